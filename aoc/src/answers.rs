@@ -0,0 +1,179 @@
+//! Known-correct answers for each day and part, kept in a data file so they
+//! can be checked by the runner.
+//!
+//! The file has one answer per line in the form `day01 part1 = 3318604`.
+//! Blank lines and lines starting with `#` are ignored. Answers depend on
+//! each person's puzzle input, so they are read at runtime from `answers.txt`
+//! in the input directory, which isn't tracked. `answers.example.txt` in the
+//! repository root has the answers for the inputs bundled with each day, and
+//! can be copied there as a starting point.
+
+use crate::input;
+use crate::Error;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The name of the answers file in the input directory.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// How an answer compares with the known answer for its day and part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Correct,
+    Incorrect { expected: String },
+    Unknown,
+}
+
+/// A set of answers keyed by day and part.
+///
+/// ```
+/// use aoc::answers::{Answers, Verification};
+///
+/// let mut answers: Answers = "day01 part1 = 1234".parse().unwrap();
+/// answers.record(1, 2, 5678);
+/// assert_eq!(answers.get(1, 2), Some("5678"));
+/// assert_eq!(answers.verify(1, 1, 1234), Verification::Correct);
+/// assert_eq!(
+///     answers.verify(1, 1, 99),
+///     Verification::Incorrect { expected: "1234".to_string() }
+/// );
+/// assert_eq!(answers.verify(2, 1, 99), Verification::Unknown);
+/// assert_eq!(answers.to_string(), "day01 part1 = 1234\nday01 part2 = 5678\n");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    entries: BTreeMap<(u32, u32), String>,
+}
+
+impl Answers {
+    pub fn new() -> Answers {
+        Answers::default()
+    }
+
+    /// The answers saved in the [input directory](../input/fn.input_dir.html).
+    pub fn from_input_dir() -> Result<Answers, Error> {
+        Answers::load(input::input_dir().join(ANSWERS_FILE))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Answers, Error> {
+        let path = path.as_ref();
+        fs::read_to_string(path)
+            .map_err(|e| Error::io(format!("Can't read '{}'", path.display()), e))?
+            .parse()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string())
+            .map_err(|e| Error::io(format!("Can't write '{}'", path.display()), e))
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        self.entries.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Store `answer` as the known answer, replacing any previous one.
+    pub fn record<T: ToString>(&mut self, day: u32, part: u32, answer: T) {
+        assert!((1..=25).contains(&day), "There is no day {}", day);
+        assert!((1..=2).contains(&part), "There is no part {}", part);
+        let answer = answer.to_string();
+        assert!(!answer.contains('\n'), "Answers must fit on a single line");
+        self.entries.insert((day, part), answer);
+    }
+
+    pub fn verify<T: ToString>(&self, day: u32, part: u32, answer: T) -> Verification {
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Verification::Correct,
+            Some(expected) => Verification::Incorrect {
+                expected: expected.to_string(),
+            },
+            None => Verification::Unknown,
+        }
+    }
+
+    /// Every answer as `(day, part, answer)`, ordered by day and part.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u32, &str)> {
+        self.entries
+            .iter()
+            .map(|(&(day, part), answer)| (day, part, answer.as_str()))
+    }
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::new();
+        for (line_number, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = || {
+                Error::Parse(format!(
+                    "Invalid answer on line {}: '{}'",
+                    line_number + 1,
+                    line
+                ))
+            };
+            let (key, answer) = match line.find('=') {
+                Some(index) => (&line[..index], line[index + 1..].trim()),
+                None => return Err(error()),
+            };
+            let mut key = key.split_whitespace();
+            let day = key.next().and_then(|d| d.strip_prefix("day"));
+            let part = key.next().and_then(|p| p.strip_prefix("part"));
+            let (day, part) = match (day, part, key.next()) {
+                (Some(day), Some(part), None) => (day, part),
+                _ => return Err(error()),
+            };
+            let day = day.parse::<u32>().map_err(|_| error())?;
+            let part = part.parse::<u32>().map_err(|_| error())?;
+            if !(1..=25).contains(&day) || !(1..=2).contains(&part) {
+                return Err(error());
+            }
+            answers.entries.insert((day, part), answer.to_string());
+        }
+        Ok(answers)
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (day, part, answer) in self.iter() {
+            writeln!(f, "day{:02} part{} = {}", day, part, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers: Answers = "# comment\n\n day02 part2 = 12 34 \nday01 part1=HCGFE\n"
+            .parse()
+            .unwrap();
+        assert_eq!(answers.get(2, 2), Some("12 34"));
+        assert_eq!(answers.get(1, 1), Some("HCGFE"));
+        assert_eq!(answers.iter().count(), 2);
+        assert_eq!(answers.to_string().parse(), Ok(answers));
+
+        assert!("day01 part1 1234".parse::<Answers>().is_err());
+        assert!("day26 part1 = 1".parse::<Answers>().is_err());
+        assert!("day01 part3 = 1".parse::<Answers>().is_err());
+        assert!("day1 = 1".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn test_example_answers() {
+        let answers: Answers = include_str!("../../answers.example.txt").parse().unwrap();
+        assert_eq!(answers.iter().count(), 49);
+        assert_eq!(answers.get(25, 2), None);
+    }
+}
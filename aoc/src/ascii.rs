@@ -0,0 +1,167 @@
+//! Comparing ASCII art, such as rendered maps and letter banners.
+
+use crate::geom::Vector2D;
+use std::fmt;
+
+/// The character-level differences between two pieces of ASCII art.
+///
+/// When displayed, lines which match are shown once, and lines which differ
+/// are shown as the expected line (`-`) and the actual line (`+`), followed
+/// by a row with `^` under each column that differs.
+///
+/// ```
+/// use aoc::ascii::diff;
+/// use aoc::geom::Vector2D;
+///
+/// let diff = diff("#..\n.#.\n", "#..\n.##\n");
+/// assert!(!diff.is_empty());
+/// assert_eq!(diff.differences(), [Vector2D::new(2, 1)]);
+/// assert_eq!(diff.to_string(), "  #..\n- .#.\n+ .##\n    ^\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsciiDiff {
+    lines: Vec<LineDiff>,
+    differences: Vec<Vector2D>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LineDiff {
+    Same(String),
+    Changed {
+        expected: Option<String>,
+        actual: Option<String>,
+        columns: Vec<usize>,
+    },
+}
+
+/// Compare `actual` against `expected` line by line and character by
+/// character. A missing character or line counts as a difference, including
+/// a missing final newline.
+pub fn diff(expected: &str, actual: &str) -> AsciiDiff {
+    let mut expected = expected.split('\n').collect::<Vec<_>>();
+    let mut actual = actual.split('\n').collect::<Vec<_>>();
+    if expected.last() == Some(&"") && actual.last() == Some(&"") {
+        expected.pop();
+        actual.pop();
+    }
+
+    let mut lines = Vec::new();
+    let mut differences = Vec::new();
+    for y in 0..expected.len().max(actual.len()) {
+        let e = expected.get(y).copied();
+        let a = actual.get(y).copied();
+        if e == a {
+            lines.push(LineDiff::Same(e.unwrap_or_default().to_string()));
+            continue;
+        }
+
+        let e_chars = e.unwrap_or_default().chars().collect::<Vec<_>>();
+        let a_chars = a.unwrap_or_default().chars().collect::<Vec<_>>();
+        let mut columns = (0..e_chars.len().max(a_chars.len()))
+            .filter(|&x| e_chars.get(x) != a_chars.get(x))
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            // an empty line on one side only, such as a missing final newline
+            columns.push(0);
+        }
+        differences.extend(columns.iter().map(|&x| Vector2D::new(x as i64, y as i64)));
+        lines.push(LineDiff::Changed {
+            expected: e.map(String::from),
+            actual: a.map(String::from),
+            columns,
+        });
+    }
+
+    AsciiDiff { lines, differences }
+}
+
+impl AsciiDiff {
+    /// True if the two pieces of art were identical.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// The position of every character which differs, in reading order.
+    pub fn differences(&self) -> &[Vector2D] {
+        &self.differences
+    }
+}
+
+impl fmt::Display for AsciiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            match line {
+                LineDiff::Same(line) => writeln!(f, "  {}", line)?,
+                LineDiff::Changed {
+                    expected,
+                    actual,
+                    columns,
+                } => {
+                    if let Some(expected) = expected {
+                        writeln!(f, "- {}", expected)?;
+                    }
+                    if let Some(actual) = actual {
+                        writeln!(f, "+ {}", actual)?;
+                    }
+                    let width = columns.last().map_or(0, |&x| x + 1);
+                    let markers = (0..width)
+                        .map(|x| if columns.contains(&x) { '^' } else { ' ' })
+                        .collect::<String>();
+                    writeln!(f, "  {}", markers)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Like `assert_eq!` for ASCII art, but on failure shows where the two
+/// differ instead of printing both in full.
+///
+/// ```should_panic
+/// aoc::ascii::assert_ascii_eq("@@\n@.\n", "@@\n..\n");
+/// ```
+#[track_caller]
+pub fn assert_ascii_eq(expected: &str, actual: &str) {
+    let diff = diff(expected, actual);
+    if !diff.is_empty() {
+        panic!(
+            "ASCII art differs at {} position(s):\n{}",
+            diff.differences().len(),
+            diff
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical() {
+        let art = "#.#\n...\n";
+        assert!(diff(art, art).is_empty());
+        assert_eq!(diff(art, art).to_string(), "  #.#\n  ...\n");
+        assert_ascii_eq(art, art);
+    }
+
+    #[test]
+    fn test_different_sizes() {
+        let diff = diff("ab\ncd\n", "abc\n");
+        assert_eq!(
+            diff.differences(),
+            [
+                Vector2D::new(2, 0),
+                Vector2D::new(0, 1),
+                Vector2D::new(1, 1)
+            ]
+        );
+        assert_eq!(diff.to_string(), "- ab\n+ abc\n    ^\n- cd\n  ^^\n");
+    }
+
+    #[test]
+    fn test_final_newline() {
+        assert_eq!(diff("ab\n", "ab").differences(), [Vector2D::new(0, 1)]);
+        assert!(diff("ab", "ab").is_empty());
+    }
+}
@@ -0,0 +1,97 @@
+//! Run an IntCode program from a file, connected to stdin and stdout.
+//!
+//! ```text
+//! intcode-run [--ascii] <program path>
+//! ```
+//!
+//! By default inputs are read from stdin as integers separated by whitespace
+//! or commas, and each output is printed on its own line. With `--ascii`,
+//! input is read a line at a time and output is printed as text, except for
+//! values outside the ASCII range which are printed as numbers.
+//!
+//! Exits with a non-zero status if the program can't be parsed, or if it
+//! faults by using an invalid opcode or parameter mode.
+
+use aoc::intcode::{Machine, Program};
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const USAGE: &str = "usage: intcode-run [--ascii] <program path>";
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let ascii = args.iter().any(|a| a == "--ascii");
+    let paths: Vec<_> = args.iter().filter(|a| !a.starts_with("--")).collect();
+    if paths.len() != 1 || args.len() != paths.len() + ascii as usize {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let source = fs::read_to_string(paths[0]).unwrap_or_else(|e| {
+        eprintln!("Failed to read {}: {}", paths[0], e);
+        process::exit(1);
+    });
+    let program = source.parse::<Program>().unwrap_or_else(|e| {
+        eprintln!("Failed to parse {}: {}", paths[0], e);
+        process::exit(1);
+    });
+    let mut machine = Machine::new(&program);
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    loop {
+        loop {
+            let value = match machine.try_run() {
+                Ok(Some(value)) => value,
+                Ok(None) => break,
+                Err(e) => {
+                    out.flush().unwrap();
+                    eprintln!("{}", e);
+                    process::exit(1);
+                }
+            };
+            if !ascii {
+                writeln!(out, "{}", value).unwrap();
+            } else if (0..128).contains(&value) {
+                write!(out, "{}", value as u8 as char).unwrap();
+            } else {
+                writeln!(out, "{}", value).unwrap();
+            }
+        }
+        out.flush().unwrap();
+
+        if machine.is_halted() {
+            break;
+        }
+
+        let line = match lines.next() {
+            Some(line) => line.unwrap(),
+            None => {
+                eprintln!("Program is awaiting input but stdin is closed");
+                process::exit(1);
+            }
+        };
+
+        if ascii {
+            machine.input_ascii(&line);
+        } else {
+            for word in line.split(|c: char| c == ',' || c.is_whitespace()) {
+                if word.is_empty() {
+                    continue;
+                }
+                match word.parse() {
+                    Ok(value) => machine.input(value),
+                    Err(_) => {
+                        eprintln!("Invalid input value '{}'", word);
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+    }
+}
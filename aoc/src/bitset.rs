@@ -0,0 +1,178 @@
+//! Compact sets of small indices.
+
+use std::fmt;
+use std::iter::FromIterator;
+
+/// A set of the indices `0..64 * WORDS`, stored as a fixed size bitset so that
+/// it is `Copy` and cheap to hash and compare.
+///
+/// ```
+/// use aoc::bitset::SmallSet;
+///
+/// let mut set: SmallSet = [3, 10, 40].iter().copied().collect();
+/// assert!(set.insert(5));
+/// assert!(!set.insert(10));
+/// assert!(set.remove(3));
+/// assert_eq!(set.iter().collect::<Vec<_>>(), [5, 10, 40]);
+///
+/// let subset: SmallSet = [10, 40].iter().copied().collect();
+/// assert!(set.contains_all(subset));
+/// assert!(!subset.contains_all(set));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SmallSet<const WORDS: usize = 1> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> Default for SmallSet<WORDS> {
+    fn default() -> Self {
+        SmallSet { words: [0; WORDS] }
+    }
+}
+
+impl<const WORDS: usize> SmallSet<WORDS> {
+    pub const CAPACITY: usize = 64 * WORDS;
+
+    pub fn new() -> SmallSet<WORDS> {
+        SmallSet::default()
+    }
+
+    fn locate(index: usize) -> (usize, u64) {
+        assert!(
+            index < Self::CAPACITY,
+            "Index {} is too large for a SmallSet with capacity {}",
+            index,
+            Self::CAPACITY
+        );
+        (index / 64, 1 << (index % 64))
+    }
+
+    /// Add `index`, returning false if it was already present.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, bit) = Self::locate(index);
+        let was_absent = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        was_absent
+    }
+
+    /// Remove `index`, returning false if it wasn't present.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, bit) = Self::locate(index);
+        let was_present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        was_present
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        index < Self::CAPACITY && {
+            let (word, bit) = Self::locate(index);
+            self.words[word] & bit != 0
+        }
+    }
+
+    /// Whether every index in `other` is also in this set.
+    pub fn contains_all(&self, other: SmallSet<WORDS>) -> bool {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .all(|(a, b)| a & b == *b)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn union(&self, other: SmallSet<WORDS>) -> SmallSet<WORDS> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: SmallSet<WORDS>) -> SmallSet<WORDS> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: SmallSet<WORDS>) -> SmallSet<WORDS> {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    fn combine<F: Fn(u64, u64) -> u64>(&self, other: SmallSet<WORDS>, f: F) -> SmallSet<WORDS> {
+        let mut result = *self;
+        for (a, b) in result.words.iter_mut().zip(other.words.iter()) {
+            *a = f(*a, *b);
+        }
+        result
+    }
+
+    /// The indices in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> {
+        let words = self.words;
+        (0..WORDS).flat_map(move |w| {
+            let mut bits = words[w];
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    None
+                } else {
+                    let bit = bits.trailing_zeros() as usize;
+                    bits &= bits - 1;
+                    Some(w * 64 + bit)
+                }
+            })
+        })
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for SmallSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = SmallSet::new();
+        for index in iter {
+            set.insert(index);
+        }
+        set
+    }
+}
+
+impl<const WORDS: usize> fmt::Debug for SmallSet<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_set_multiple_words() {
+        let mut set = SmallSet::<3>::new();
+        assert_eq!(SmallSet::<3>::CAPACITY, 192);
+        assert!(set.is_empty());
+        for &i in &[191, 0, 64, 63, 130] {
+            assert!(set.insert(i));
+        }
+        assert_eq!(set.len(), 5);
+        assert!(set.contains(64) && !set.contains(65) && !set.contains(500));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 63, 64, 130, 191]);
+        assert_eq!(format!("{:?}", set), "{0, 63, 64, 130, 191}");
+
+        let other: SmallSet<3> = [63, 64, 65].iter().copied().collect();
+        assert_eq!(set.intersection(other).iter().collect::<Vec<_>>(), [63, 64]);
+        assert_eq!(set.union(other).len(), 6);
+        assert_eq!(
+            set.difference(other).iter().collect::<Vec<_>>(),
+            [0, 130, 191]
+        );
+
+        assert!(set.remove(191));
+        assert!(!set.remove(191));
+        assert_eq!(set.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_small_set_out_of_range() {
+        SmallSet::<1>::new().insert(64);
+    }
+}
@@ -0,0 +1,127 @@
+//! Converting between numbers and their decimal digits.
+
+use crate::Error;
+
+/// The decimal digits of `n`, most significant first.
+///
+/// ```
+/// use aoc::digits::{from_digits, to_digits};
+///
+/// assert_eq!(to_digits(178_416), [1, 7, 8, 4, 1, 6]);
+/// assert_eq!(to_digits(0), [0]);
+/// assert_eq!(from_digits(&to_digits(u64::MAX)), u64::MAX);
+/// ```
+pub fn to_digits(n: u64) -> Vec<u8> {
+    let width = num_digits(n);
+    fixed_width_digits(n, width).collect()
+}
+
+/// The number with the given decimal digits, most significant first.
+pub fn from_digits(digits: &[u8]) -> u64 {
+    digits.iter().fold(0, |n, &d| {
+        assert!(d < 10, "{} is not a decimal digit", d);
+        n * 10 + u64::from(d)
+    })
+}
+
+/// How many decimal digits it takes to write `n`.
+pub fn num_digits(n: u64) -> usize {
+    std::iter::successors(Some(n), |&n| Some(n / 10).filter(|&n| n > 0)).count()
+}
+
+/// The last `width` decimal digits of `n`, most significant first and padded
+/// with leading zeros, without allocating.
+///
+/// ```
+/// use aoc::digits::fixed_width_digits;
+///
+/// assert!(fixed_width_digits(42, 4).eq([0, 0, 4, 2]));
+/// assert!(fixed_width_digits(1234, 2).eq([3, 4]));
+/// ```
+pub fn fixed_width_digits(n: u64, width: usize) -> FixedWidthDigits {
+    FixedWidthDigits { n, width }
+}
+
+/// The iterator returned by [fixed_width_digits](fn.fixed_width_digits.html).
+#[derive(Debug, Clone)]
+pub struct FixedWidthDigits {
+    n: u64,
+    width: usize,
+}
+
+impl Iterator for FixedWidthDigits {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.width == 0 {
+            return None;
+        }
+        self.width -= 1;
+        let place = 10u64.checked_pow(self.width as u32);
+        Some(place.map_or(0, |p| (self.n / p % 10) as u8))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.width, Some(self.width))
+    }
+}
+
+impl ExactSizeIterator for FixedWidthDigits {}
+
+/// Read a string of decimal digits, such as a puzzle's signal, ignoring
+/// surrounding whitespace.
+///
+/// ```
+/// use aoc::digits::{digits_to_string, parse_digits};
+///
+/// let digits = parse_digits("03036732\n").unwrap();
+/// assert_eq!(digits, [0, 3, 0, 3, 6, 7, 3, 2]);
+/// assert_eq!(digits_to_string(&digits), "03036732");
+/// assert!(parse_digits("12a4").is_err());
+/// ```
+pub fn parse_digits(s: &str) -> Result<Vec<u8>, Error> {
+    s.trim()
+        .chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| Error::Parse(format!("'{}' is not a decimal digit", c)))
+        })
+        .collect()
+}
+
+/// Write out decimal digits, most significant first.
+pub fn digits_to_string(digits: &[u8]) -> String {
+    digits
+        .iter()
+        .map(|&d| std::char::from_digit(u32::from(d), 10).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_digits() {
+        assert_eq!(num_digits(0), 1);
+        assert_eq!(num_digits(9), 1);
+        assert_eq!(num_digits(10), 2);
+        assert_eq!(num_digits(u64::MAX), 20);
+    }
+
+    #[test]
+    fn test_fixed_width_digits() {
+        assert_eq!(fixed_width_digits(7, 0).count(), 0);
+        assert_eq!(fixed_width_digits(7, 3).len(), 3);
+        let wide = fixed_width_digits(u64::MAX, 22).collect::<Vec<_>>();
+        assert_eq!(&wide[..3], [0, 0, 1]);
+        assert_eq!(from_digits(&wide), u64::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_invalid_digits() {
+        from_digits(&[1, 10]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Tracking which elements are connected as sets are merged.
+
+use std::collections::HashMap;
+
+/// A partition of the elements `0..len` into disjoint sets, which starts with
+/// each element in its own set. Uses union by rank and path compression so
+/// that operations take close to constant time.
+///
+/// ```
+/// use aoc::disjoint_set::DisjointSet;
+///
+/// let mut sets = DisjointSet::new(5);
+/// assert!(sets.union(0, 3));
+/// assert!(sets.union(3, 4));
+/// assert!(!sets.union(4, 0));
+/// assert!(sets.same_set(0, 4));
+/// assert!(!sets.same_set(0, 1));
+/// assert_eq!(sets.num_sets(), 3);
+/// assert_eq!(sets.sets(), vec![vec![0, 3, 4], vec![1], vec![2]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    num_sets: usize,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> DisjointSet {
+        DisjointSet {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            num_sets: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    pub fn num_sets(&self) -> usize {
+        self.num_sets
+    }
+
+    /// The representative element of the set containing `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut node = element;
+        while self.parents[node] != root {
+            let next = self.parents[node];
+            self.parents[node] = root;
+            node = next;
+        }
+        root
+    }
+
+    /// Merge the sets containing `a` and `b`, returning false if they were
+    /// already the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (parent, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        if self.ranks[parent] == self.ranks[child] {
+            self.ranks[parent] += 1;
+        }
+        self.parents[child] = parent;
+        self.sizes[parent] += self.sizes[child];
+        self.num_sets -= 1;
+        true
+    }
+
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// The number of elements in the set containing `element`.
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Every set with its elements in ascending order, ordered by their lowest
+    /// element.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut set_of_root = HashMap::new();
+        let mut sets: Vec<Vec<usize>> = Vec::new();
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *set_of_root.entry(root).or_insert_with(|| {
+                sets.push(Vec::new());
+                sets.len() - 1
+            });
+            sets[index].push(element);
+        }
+        sets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set_chain() {
+        let n = 1000;
+        let mut sets = DisjointSet::new(n);
+        assert_eq!(sets.len(), n);
+        for i in (0..n - 2).step_by(2) {
+            assert!(sets.union(i, i + 2));
+        }
+        assert_eq!(sets.num_sets(), n / 2 + 1);
+        assert_eq!(sets.set_size(0), n / 2);
+        assert_eq!(sets.set_size(1), 1);
+        assert!(sets.same_set(0, n - 2));
+        assert!(!sets.same_set(0, n - 1));
+
+        assert!(sets.union(1, n - 2));
+        assert_eq!(sets.set_size(3), 1);
+        assert_eq!(sets.set_size(1), n / 2 + 1);
+        assert_eq!(sets.sets().len(), n / 2);
+        assert!(DisjointSet::new(0).sets().is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+//! The error type shared by the fallible parts of the library.
+
+use std::io;
+use thiserror::Error;
+
+/// Everything that can go wrong in the library, so that solutions can
+/// propagate failures with `?`.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Text or an image that couldn't be understood.
+    #[error("{0}")]
+    Parse(String),
+
+    /// An IntCode machine that didn't behave as expected.
+    #[error("{0}")]
+    Intcode(String),
+
+    /// A search that ran out of places to look.
+    #[error("{0}")]
+    Search(String),
+
+    /// A request that is outside what the library can do, such as a GIF with
+    /// too many colours.
+    #[error("{0}")]
+    Unsupported(String),
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl Error {
+    /// An I/O error with a description of what was being attempted.
+    pub fn io(context: impl AsRef<str>, error: impl ToString) -> Error {
+        Error::Io(io::Error::other(format!(
+            "{}: {}",
+            context.as_ref(),
+            error.to_string()
+        )))
+    }
+}
+
+/// Errors are equal when they are the same kind with the same message, which
+/// for I/O errors means comparing their kind and description.
+impl PartialEq for Error {
+    fn eq(&self, other: &Error) -> bool {
+        match (self, other) {
+            (Error::Parse(a), Error::Parse(b)) => a == b,
+            (Error::Intcode(a), Error::Intcode(b)) => a == b,
+            (Error::Search(a), Error::Search(b)) => a == b,
+            (Error::Unsupported(a), Error::Unsupported(b)) => a == b,
+            (Error::Io(a), Error::Io(b)) => a.kind() == b.kind() && a.to_string() == b.to_string(),
+            _ => false,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Error::Parse("Bad input".into()).to_string(), "Bad input");
+        let missing = io::Error::new(io::ErrorKind::NotFound, "No such file");
+        assert_eq!(
+            Error::io("Can't read 'x'", missing).to_string(),
+            "Can't read 'x': No such file"
+        );
+        assert!(matches!(
+            Error::from(io::Error::from(io::ErrorKind::NotFound)),
+            Error::Io(_)
+        ));
+        assert_eq!(Error::Parse("x".into()), Error::Parse("x".into()));
+        assert_ne!(Error::Parse("x".into()), Error::Search("x".into()));
+    }
+}
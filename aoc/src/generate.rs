@@ -0,0 +1,289 @@
+//! Random puzzle inputs in the formats used by the puzzles, for stress
+//! testing solutions beyond a single personal input.
+//!
+//! Everything is driven by a small seeded [Rng](struct.Rng.html) so that a
+//! failing input can be reproduced from its seed.
+
+use std::collections::HashSet;
+
+/// A fast, seeded pseudo-random number generator (SplitMix64). Not suitable
+/// for anything that needs to be unpredictable.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Empty range");
+        // reject the top of the range so every number is equally likely
+        let limit = u64::MAX - (u64::MAX % n);
+        loop {
+            let value = self.next_u64();
+            if value < limit {
+                return value % n;
+            }
+        }
+    }
+
+    /// A number in `lo..hi`, which must not be empty.
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo < hi, "Empty range");
+        let span = (hi as i128 - lo as i128) as u64;
+        (lo as i128 + self.below(span) as i128) as i64
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// A [day 6](https://adventofcode.com/2019/day/6) orbit map of
+/// `num_objects` objects orbiting COM, which always include `YOU` and `SAN`.
+///
+/// ```
+/// use aoc::generate::{orbit_map, Rng};
+///
+/// let map = orbit_map(&mut Rng::new(6), 20);
+/// assert_eq!(map.lines().count(), 20);
+/// assert!(map.lines().any(|l| l.ends_with(")YOU")));
+/// assert!(map.lines().any(|l| l.ends_with(")SAN")));
+/// ```
+pub fn orbit_map(rng: &mut Rng, num_objects: usize) -> String {
+    assert!(num_objects >= 2, "There must be room for YOU and SAN");
+    let mut names = unique_names(rng, num_objects - 2, &["COM", "YOU", "SAN"]);
+    let mut primaries = vec!["COM".to_string()];
+    let mut lines = Vec::new();
+    for name in names.drain(..) {
+        let primary = rng.choose(&primaries).clone();
+        lines.push(format!("{}){}", primary, name));
+        primaries.push(name);
+    }
+    for &leaf in ["YOU", "SAN"].iter() {
+        let primary = rng.choose(&primaries).clone();
+        lines.push(format!("{}){}", primary, leaf));
+    }
+    shuffle(rng, &mut lines);
+    lines.join("\n")
+}
+
+/// A [day 14](https://adventofcode.com/2019/day/14) reaction list, where
+/// FUEL can be made from ORE through `num_chemicals` intermediate chemicals.
+///
+/// ```
+/// use aoc::generate::{reaction_list, Rng};
+///
+/// let reactions = reaction_list(&mut Rng::new(14), 10);
+/// assert_eq!(reactions.lines().count(), 11);
+/// assert!(reactions.lines().any(|l| l.ends_with(" FUEL")));
+/// ```
+pub fn reaction_list(rng: &mut Rng, num_chemicals: usize) -> String {
+    let names = unique_names(rng, num_chemicals, &["ORE", "FUEL"]);
+    let mut made = vec!["ORE".to_string()];
+    let mut lines = Vec::new();
+    let outputs = names.into_iter().chain(std::iter::once("FUEL".to_string()));
+    for (index, name) in outputs.enumerate() {
+        let is_fuel = index == num_chemicals;
+        let num_inputs = if is_fuel {
+            made.len().min(1 + rng.below(4) as usize)
+        } else {
+            made.len().min(1 + rng.below(3) as usize)
+        };
+        // the fuel uses every chemical nothing else has, so none are wasted
+        let mut inputs = pick_distinct(rng, &made, num_inputs);
+        if is_fuel {
+            for chemical in made.iter().skip(1) {
+                let is_used = lines.iter().any(|l: &String| {
+                    l.split("=>")
+                        .next()
+                        .unwrap()
+                        .split(',')
+                        .any(|i| i.split_whitespace().nth(1) == Some(chemical.as_str()))
+                });
+                if !is_used && !inputs.contains(chemical) {
+                    inputs.push(chemical.clone());
+                }
+            }
+        }
+        let inputs = inputs
+            .iter()
+            .map(|i| {
+                let quantity = if i == "ORE" {
+                    rng.range(1, 200)
+                } else {
+                    rng.range(1, 10)
+                };
+                format!("{} {}", quantity, i)
+            })
+            .collect::<Vec<_>>();
+        let quantity = if is_fuel { 1 } else { rng.range(1, 10) };
+        lines.push(format!("{} => {} {}", inputs.join(", "), quantity, name));
+        made.push(name);
+    }
+    shuffle(rng, &mut lines);
+    lines.join("\n")
+}
+
+/// [Day 22](https://adventofcode.com/2019/day/22) shuffle instructions which
+/// are valid for a deck of `deck_size` cards.
+///
+/// ```
+/// use aoc::generate::{shuffle_instructions, Rng};
+///
+/// let shuffles = shuffle_instructions(&mut Rng::new(22), 30, 10_007);
+/// assert_eq!(shuffles.lines().count(), 30);
+/// ```
+pub fn shuffle_instructions(rng: &mut Rng, count: usize, deck_size: u64) -> String {
+    assert!(deck_size >= 3, "Deck is too small to shuffle");
+    let size = deck_size as i64;
+    (0..count)
+        .map(|_| match rng.below(3) {
+            0 => "deal into new stack".to_string(),
+            1 => {
+                let magnitude = rng.range(1, size);
+                let n = if rng.chance(0.5) {
+                    -magnitude
+                } else {
+                    magnitude
+                };
+                format!("cut {}", n)
+            }
+            _ => loop {
+                let n = rng.range(2, size) as u64;
+                if crate::math::gcd(n, deck_size) == 1 {
+                    break format!("deal with increment {}", n);
+                }
+            },
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A [day 10](https://adventofcode.com/2019/day/10) asteroid field where each
+/// location holds an asteroid with probability `density`, with at least two
+/// asteroids.
+///
+/// ```
+/// use aoc::generate::{asteroid_field, Rng};
+///
+/// let field = asteroid_field(&mut Rng::new(10), 12, 8, 0.3);
+/// assert_eq!(field.lines().count(), 8);
+/// assert!(field.lines().all(|l| l.len() == 12));
+/// assert!(field.matches('#').count() >= 2);
+/// ```
+pub fn asteroid_field(rng: &mut Rng, width: usize, height: usize, density: f64) -> String {
+    assert!(width * height >= 2, "There must be room for two asteroids");
+    loop {
+        let field = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.chance(density) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        if field.matches('#').count() >= 2 {
+            return field;
+        }
+    }
+}
+
+fn unique_names(rng: &mut Rng, count: usize, reserved: &[&str]) -> Vec<String> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    let mut seen: HashSet<String> = reserved.iter().map(|s| s.to_string()).collect();
+    let mut names = Vec::new();
+    while names.len() < count {
+        let name = (0..3)
+            .map(|_| *rng.choose(ALPHABET) as char)
+            .collect::<String>();
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names
+}
+
+fn pick_distinct(rng: &mut Rng, items: &[String], count: usize) -> Vec<String> {
+    let mut items = items.to_vec();
+    shuffle(rng, &mut items);
+    items.truncate(count);
+    items
+}
+
+fn shuffle<T>(rng: &mut Rng, items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        let j = rng.below(i as u64 + 1) as usize;
+        items.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut rng = Rng::new(1);
+        let mut counts = [0; 5];
+        for _ in 0..5000 {
+            counts[rng.below(5) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&c| c > 900 && c < 1100));
+        assert!((0..1000).all(|_| (-3..4).contains(&rng.range(-3, 4))));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+    }
+
+    #[test]
+    fn test_orbit_map_is_a_tree() {
+        for seed in 0..20 {
+            let map = orbit_map(&mut Rng::new(seed), 50);
+            let satellites = map
+                .lines()
+                .map(|l| l.split(')').nth(1).unwrap())
+                .collect::<HashSet<_>>();
+            assert_eq!(satellites.len(), 50);
+            assert!(!satellites.contains("COM"));
+            assert!(map
+                .lines()
+                .map(|l| l.split(')').next().unwrap())
+                .all(|p| p == "COM" || satellites.contains(p)));
+        }
+    }
+
+    #[test]
+    fn test_reaction_list_uses_every_chemical() {
+        for seed in 0..20 {
+            let reactions = reaction_list(&mut Rng::new(seed), 15);
+            let outputs = reactions
+                .lines()
+                .map(|l| l.split_whitespace().last().unwrap())
+                .collect::<HashSet<_>>();
+            assert_eq!(outputs.len(), 16);
+            for output in outputs.iter().filter(|&&o| o != "FUEL") {
+                let pattern = format!(" {}", output);
+                let used = reactions
+                    .lines()
+                    .any(|l| l.split("=>").next().unwrap().contains(pattern.as_str()));
+                assert!(used, "{} is never used", output);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::geom::Vector2D;
+use std::cmp::Ordering;
+
+/// The direction of an offset from the origin, ordered clockwise starting
+/// from North (negative y, as in [cartograph](fn.cartograph.html)).
+///
+/// Angles are compared exactly using integer arithmetic, and offsets along
+/// the same line of sight have equal angles regardless of their length.
+///
+/// ```
+/// use aoc::geom::{Angle, Vector2D};
+///
+/// let north = Angle::from(Vector2D { x: 0, y: -3 });
+/// let east = Angle::from(Vector2D { x: 2, y: 0 });
+/// assert!(north < east);
+/// assert_eq!(east, Angle::from(Vector2D { x: 1, y: 0 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Angle(Vector2D);
+
+impl Angle {
+    /// The smallest offset with this angle.
+    pub fn direction(self) -> Vector2D {
+        self.0
+    }
+
+    // Which quarter turn clockwise from North the angle lies in, including
+    // its starting compass direction.
+    fn quadrant(self) -> u8 {
+        let Vector2D { x, y } = self.0;
+        if x >= 0 && y < 0 {
+            0
+        } else if x > 0 && y >= 0 {
+            1
+        } else if x <= 0 && y > 0 {
+            2
+        } else {
+            3
+        }
+    }
+}
+
+/// Panics if the offset is zero, which has no direction.
+impl From<Vector2D> for Angle {
+    fn from(offset: Vector2D) -> Angle {
+        assert_ne!(offset, Vector2D::zero(), "Zero offset has no angle");
+        Angle(offset.primitive())
+    }
+}
+
+impl Ord for Angle {
+    fn cmp(&self, other: &Angle) -> Ordering {
+        self.quadrant()
+            .cmp(&other.quadrant())
+            .then_with(|| 0.cmp(&self.0.cross(other.0)))
+    }
+}
+
+impl PartialOrd for Angle {
+    fn partial_cmp(&self, other: &Angle) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angle_ordering() {
+        let clockwise = [
+            (0, -1),
+            (1, -3),
+            (1, -1),
+            (3, -1),
+            (1, 0),
+            (2, 1),
+            (1, 1),
+            (0, 1),
+            (-1, 1),
+            (-1, 0),
+            (-5, -1),
+            (-1, -1),
+            (-1, -5),
+        ];
+        let angles = clockwise
+            .iter()
+            .copied()
+            .map(Vector2D::from)
+            .map(Angle::from)
+            .collect::<Vec<_>>();
+
+        for (i, a) in angles.iter().enumerate() {
+            for (j, b) in angles.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "{:?} vs {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn angle_equality() {
+        let a = Angle::from(Vector2D { x: 4, y: -6 });
+        assert_eq!(a, Angle::from(Vector2D { x: 2, y: -3 }));
+        assert_eq!(a.direction(), Vector2D { x: 2, y: -3 });
+        assert_ne!(a, Angle::from(Vector2D { x: -2, y: 3 }));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::geom::{Grid, Rect, Vector2D};
+
+/// A connected region of a [Grid](struct.Grid.html), as found by
+/// [Grid::components](struct.Grid.html#method.components).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    /// The number of cells in the region.
+    pub size: usize,
+    /// The smallest Rect containing every cell in the region.
+    pub bounds: Rect,
+}
+
+/// Every cell of a [Grid](struct.Grid.html) labeled with the index of the
+/// connected [Region](struct.Region.html) it belongs to.
+///
+/// Regions are numbered from 0 in row-major order of their first cell.
+///
+/// ```
+/// use aoc::geom::{cartograph_to_grid, Vector2D};
+///
+/// let grid = cartograph_to_grid("aab\nbab\n");
+/// let components = grid.components(|a, b| a == b);
+/// assert_eq!(components.len(), 3);
+/// assert_eq!(components.label(Vector2D { x: 1, y: 1 }), Some(0));
+/// assert_eq!(components.regions()[1].size, 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+impl Components {
+    pub(crate) fn new<T, F>(grid: &Grid<T>, is_same_region: F) -> Components
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        let mut labels = Grid::filled(grid.dimensions(), None);
+        let mut regions = Vec::new();
+
+        for start in grid.dimensions().iter() {
+            if labels[start].is_some() {
+                continue;
+            }
+
+            let label = regions.len();
+            let mut region = Region {
+                size: 0,
+                bounds: Rect::from_point(start),
+            };
+            let mut open = vec![start];
+            labels[start] = Some(label);
+            while let Some(pos) = open.pop() {
+                region.size += 1;
+                region.bounds.expand_to_fit(pos);
+                for n in pos.neighbours() {
+                    let is_joined = grid.contains(n)
+                        && labels[n].is_none()
+                        && is_same_region(&grid[pos], &grid[n]);
+                    if is_joined {
+                        labels[n] = Some(label);
+                        open.push(n);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+
+        Components {
+            labels: labels.map(|l| l.unwrap()),
+            regions,
+        }
+    }
+
+    /// The number of regions.
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// The index of the region containing a position, or None if the
+    /// position is outside the grid.
+    pub fn label(&self, pos: Vector2D) -> Option<usize> {
+        self.labels.get(pos).copied()
+    }
+
+    /// The region index of every cell.
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::cartograph_to_grid;
+
+    #[test]
+    fn components_labels_and_regions() {
+        let grid = cartograph_to_grid("..#..\n..#.#\n###..\n.#...\n");
+        let components = grid.components(|&a, &b| a == b);
+
+        assert_eq!(
+            components
+                .labels()
+                .render_with(|&l| (b'0' + l as u8) as char),
+            "00122\n00123\n11122\n41222\n"
+        );
+        assert_eq!(components.len(), 5);
+
+        let region = |size, (x1, y1), (x2, y2)| Region {
+            size,
+            bounds: Rect::new(Vector2D { x: x1, y: y1 }, Vector2D { x: x2, y: y2 }),
+        };
+        assert_eq!(
+            components.regions(),
+            &[
+                region(4, (0, 0), (1, 1)),
+                region(6, (0, 0), (2, 3)),
+                region(8, (2, 0), (4, 3)),
+                region(1, (4, 1), (4, 1)),
+                region(1, (0, 3), (0, 3)),
+            ]
+        );
+        assert_eq!(components.label(Vector2D { x: 5, y: 0 }), None);
+    }
+}
@@ -4,27 +4,56 @@ use std::convert::TryInto;
 use std::cmp;
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dimensions {
     pub width: usize,
     pub height: usize,
 }
 
 impl Dimensions {
-    pub fn new() -> Dimensions {
-        Dimensions::default()
+    pub const fn new() -> Dimensions {
+        Dimensions::new_const(0, 0)
     }
 
-    pub fn area(self) -> usize {
+    /// Construct Dimensions in a const context, such as for a `const` item.
+    pub const fn new_const(width: usize, height: usize) -> Dimensions {
+        Dimensions { width, height }
+    }
+
+    pub const fn area(self) -> usize {
         self.width * self.height
     }
 
     pub fn iter(self) -> DimensionsIter {
         DimensionsIter {
-            limits: Some(self),
+            limits: if self.area() > 0 { Some(self) } else { None },
             current: Vector2D::zero(),
         }
     }
 
+    /// Iterate over each row in turn from the top, yielding the positions
+    /// within that row from left to right.
+    pub fn rows(self) -> impl Iterator<Item = impl Iterator<Item = Vector2D>> {
+        let width = self.width as i64;
+        (0..self.height as i64).map(move |y| (0..width).map(move |x| Vector2D { x, y }))
+    }
+
+    /// Iterate over each column in turn from the left, yielding the
+    /// positions within that column from top to bottom.
+    pub fn columns(self) -> impl Iterator<Item = impl Iterator<Item = Vector2D>> {
+        let height = self.height as i64;
+        (0..self.width as i64).map(move |x| (0..height).map(move |y| Vector2D { x, y }))
+    }
+
+    /// Iterate over the positions on the outside edge, each once, in
+    /// row-major order.
+    pub fn border(self) -> impl Iterator<Item = Vector2D> {
+        let right = self.width as i64 - 1;
+        let bottom = self.height as i64 - 1;
+        self.iter()
+            .filter(move |p| p.x == 0 || p.y == 0 || p.x == right || p.y == bottom)
+    }
+
     pub fn expand_to_fit(&mut self, pos: Vector2D) {
         let (x, y) = (pos.x as usize, pos.y as usize);
         self.width = cmp::max(self.width, x + 1);
@@ -50,6 +79,18 @@ impl Dimensions {
         (pos.x >= 0 && pos.x < width) && (pos.y >= 0) && (pos.y < height)
     }
 
+    /// The position within these dimensions that `pos` lands on if the
+    /// area repeats infinitely in both axes.
+    ///
+    /// Panics if the area is empty.
+    pub fn wrap(self, pos: Vector2D) -> Vector2D {
+        assert!(self.area() > 0, "Can't wrap {} into empty dimensions", pos);
+        Vector2D {
+            x: pos.x.rem_euclid(self.width as i64),
+            y: pos.y.rem_euclid(self.height as i64),
+        }
+    }
+
     pub fn centre(self) -> Vector2D {
         Vector2D {
             x: (self.width / 2).try_into().unwrap(),
@@ -98,6 +139,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dimensions_new_const() {
+        const DIMS: Dimensions = Dimensions::new_const(4, 6);
+        const AREA: usize = DIMS.area();
+        assert_eq!(
+            DIMS,
+            Dimensions {
+                width: 4,
+                height: 6
+            }
+        );
+        assert_eq!(AREA, 24);
+        assert_eq!(Dimensions::new(), Dimensions::default());
+    }
+
     #[test]
     fn dimensions_iter() {
         let items = Dimensions {
@@ -113,6 +169,78 @@ mod tests {
             .map(Vector2D::from)
             .collect::<Vec<_>>();
         assert_eq!(items, expected);
+
+        assert_eq!(Dimensions::new().iter().count(), 0);
+    }
+
+    #[test]
+    fn dimensions_rows_columns() {
+        let dims = Dimensions {
+            width: 3,
+            height: 2,
+        };
+        let rows = dims
+            .rows()
+            .map(|row| row.map(|p| (p.x, p.y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [[(0, 0), (1, 0), (2, 0)], [(0, 1), (1, 1), (2, 1)]]);
+
+        let columns = dims
+            .columns()
+            .map(|column| column.map(|p| (p.x, p.y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            [[(0, 0), (0, 1)], [(1, 0), (1, 1)], [(2, 0), (2, 1)]]
+        );
+    }
+
+    #[test]
+    fn dimensions_border() {
+        let border = |width, height| {
+            Dimensions { width, height }
+                .border()
+                .map(|p| (p.x, p.y))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            border(3, 3),
+            [
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (0, 1),
+                (2, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2)
+            ]
+        );
+        assert_eq!(border(2, 1), [(0, 0), (1, 0)]);
+        assert_eq!(border(1, 2), [(0, 0), (0, 1)]);
+        assert_eq!(border(0, 0), []);
+        assert_eq!(
+            Dimensions {
+                width: 5,
+                height: 4
+            }
+            .border()
+            .count(),
+            14
+        );
+    }
+
+    #[test]
+    fn dimensions_wrap() {
+        let dims = Dimensions::new_const(3, 5);
+        let wrap = |x, y| {
+            let p = dims.wrap(Vector2D { x, y });
+            (p.x, p.y)
+        };
+        assert_eq!(wrap(1, 4), (1, 4));
+        assert_eq!(wrap(3, 5), (0, 0));
+        assert_eq!(wrap(-1, -1), (2, 4));
+        assert_eq!(wrap(-7, 12), (2, 2));
     }
 
     #[test]
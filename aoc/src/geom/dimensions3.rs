@@ -0,0 +1,92 @@
+use crate::geom::Vector3D;
+
+/// The size of a box of positions starting at the origin, the 3D
+/// counterpart of [Dimensions](struct.Dimensions.html).
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dimensions3 {
+    pub width: usize,
+    pub height: usize,
+    pub depth: usize,
+}
+
+impl Dimensions3 {
+    pub fn new() -> Dimensions3 {
+        Dimensions3::default()
+    }
+
+    pub fn volume(self) -> usize {
+        self.width * self.height * self.depth
+    }
+
+    /// Iterate over every position, with x changing fastest and z slowest.
+    pub fn iter(self) -> impl Iterator<Item = Vector3D> {
+        (0..self.volume()).map(move |index| self.node_index_to_pos(index))
+    }
+
+    pub fn pos_to_node_index(self, pos: Vector3D) -> usize {
+        let (x, y, z) = (pos.x as usize, pos.y as usize, pos.z as usize);
+        (((z * self.height) + y) * self.width) + x
+    }
+
+    pub fn node_index_to_pos(self, index: usize) -> Vector3D {
+        let x = (index % self.width) as i64;
+        let y = ((index / self.width) % self.height) as i64;
+        let z = (index / (self.width * self.height)) as i64;
+        Vector3D { x, y, z }
+    }
+
+    pub fn contains(self, pos: Vector3D) -> bool {
+        let in_range = |v: i64, limit: usize| v >= 0 && v < limit as i64;
+        in_range(pos.x, self.width) && in_range(pos.y, self.height) && in_range(pos.z, self.depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dimensions3_iter() {
+        let dims = Dimensions3 {
+            width: 2,
+            height: 2,
+            depth: 2,
+        };
+        assert_eq!(dims.volume(), 8);
+
+        let items = dims.iter().map(|p| (p.x, p.y, p.z)).collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                (0, 0, 0),
+                (1, 0, 0),
+                (0, 1, 0),
+                (1, 1, 0),
+                (0, 0, 1),
+                (1, 0, 1),
+                (0, 1, 1),
+                (1, 1, 1)
+            ]
+        );
+        for (index, pos) in dims.iter().enumerate() {
+            assert_eq!(dims.pos_to_node_index(pos), index);
+        }
+    }
+
+    #[test]
+    fn dimensions3_contains() {
+        let dims = Dimensions3 {
+            width: 3,
+            height: 4,
+            depth: 5,
+        };
+        assert!(dims.contains(Vector3D::zero()));
+        assert!(dims.contains(Vector3D { x: 2, y: 3, z: 4 }));
+
+        assert!(!dims.contains(Vector3D { x: -1, y: 0, z: 0 }));
+        assert!(!dims.contains(Vector3D { x: 0, y: 0, z: -1 }));
+        assert!(!dims.contains(Vector3D { x: 3, y: 3, z: 4 }));
+        assert!(!dims.contains(Vector3D { x: 2, y: 4, z: 4 }));
+        assert!(!dims.contains(Vector3D { x: 2, y: 3, z: 5 }));
+    }
+}
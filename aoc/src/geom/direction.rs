@@ -0,0 +1,225 @@
+use crate::geom::Vector2D;
+use crate::Error;
+use std::convert::TryFrom;
+
+/// One of the four compass directions on a grid, where North is towards
+/// negative y as in [cartograph](fn.cartograph.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from North.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn(self, turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => self.turn_left(),
+            Turn::Right => self.turn_right(),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_left().turn_left()
+    }
+
+    /// The change in position from moving one step in this direction.
+    pub const fn offset(self) -> Vector2D {
+        match self {
+            Direction::North => Vector2D::new(0, -1),
+            Direction::East => Vector2D::new(1, 0),
+            Direction::South => Vector2D::new(0, 1),
+            Direction::West => Vector2D::new(-1, 0),
+        }
+    }
+
+    /// The direction whose [offset](#method.offset) is the given vector, if any.
+    pub fn from_offset(offset: Vector2D) -> Option<Direction> {
+        Direction::ALL
+            .iter()
+            .copied()
+            .find(|d| d.offset() == offset)
+    }
+}
+
+/// Parses compass letters (`NESW`), relative letters (`URDL`) or arrows (`^>v<`).
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'N' | 'U' | '^' => Ok(Direction::North),
+            'E' | 'R' | '>' => Ok(Direction::East),
+            'S' | 'D' | 'v' => Ok(Direction::South),
+            'W' | 'L' | '<' => Ok(Direction::West),
+            _ => Err(Error::Parse(format!("Unknown direction '{}'", c))),
+        }
+    }
+}
+
+/// Parses the movement commands used by IntCode programs (north 1, south 2,
+/// west 3, east 4).
+impl TryFrom<i64> for Direction {
+    type Error = Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Direction::North),
+            2 => Ok(Direction::South),
+            3 => Ok(Direction::West),
+            4 => Ok(Direction::East),
+            _ => Err(Error::Parse(format!("Unknown direction code {}", value))),
+        }
+    }
+}
+
+impl From<Direction> for i64 {
+    fn from(direction: Direction) -> i64 {
+        match direction {
+            Direction::North => 1,
+            Direction::South => 2,
+            Direction::West => 3,
+            Direction::East => 4,
+        }
+    }
+}
+
+/// A quarter turn that changes which [Direction](enum.Direction.html) a
+/// robot is facing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Turn {
+    /// The turn that changes from facing `from` to facing `to`, if they are a
+    /// quarter turn apart.
+    pub fn between(from: Direction, to: Direction) -> Option<Turn> {
+        if from.turn_left() == to {
+            Some(Turn::Left)
+        } else if from.turn_right() == to {
+            Some(Turn::Right)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses `L` or `R`, as used in movement functions.
+impl TryFrom<char> for Turn {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(Error::Parse(format!("Unknown turn '{}'", c))),
+        }
+    }
+}
+
+impl From<Turn> for char {
+    fn from(turn: Turn) -> char {
+        match turn {
+            Turn::Left => 'L',
+            Turn::Right => 'R',
+        }
+    }
+}
+
+/// Parses the turn codes output by IntCode programs (left 0, right 1).
+impl TryFrom<i64> for Turn {
+    type Error = Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Turn::Left),
+            1 => Ok(Turn::Right),
+            _ => Err(Error::Parse(format!("Unknown turn code {}", value))),
+        }
+    }
+}
+
+impl From<Turn> for i64 {
+    fn from(turn: Turn) -> i64 {
+        match turn {
+            Turn::Left => 0,
+            Turn::Right => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn direction_turning() {
+        for &d in Direction::ALL.iter() {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.opposite().offset() + d.offset(), Vector2D::zero());
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::South.opposite(), Direction::North);
+    }
+
+    #[test]
+    fn direction_conversions() {
+        for &d in Direction::ALL.iter() {
+            assert_eq!(Direction::from_offset(d.offset()), Some(d));
+            assert_eq!(Direction::try_from(i64::from(d)), Ok(d));
+        }
+        assert_eq!(Direction::from_offset(Vector2D { x: 1, y: 1 }), None);
+
+        assert_eq!(Direction::try_from('N'), Ok(Direction::North));
+        assert_eq!(Direction::try_from('L'), Ok(Direction::West));
+        assert_eq!(Direction::try_from('v'), Ok(Direction::South));
+        assert!(Direction::try_from('x').is_err());
+        assert!(Direction::try_from(0).is_err());
+    }
+
+    #[test]
+    fn turn_conversions() {
+        for &d in Direction::ALL.iter() {
+            assert_eq!(d.turn(Turn::Left), d.turn_left());
+            assert_eq!(d.turn(Turn::Right), d.turn_right());
+            assert_eq!(Turn::between(d, d.turn_right()), Some(Turn::Right));
+            assert_eq!(Turn::between(d, d.opposite()), None);
+        }
+        for &t in [Turn::Left, Turn::Right].iter() {
+            assert_eq!(Turn::try_from(i64::from(t)), Ok(t));
+            assert_eq!(Turn::try_from(char::from(t)), Ok(t));
+        }
+        assert!(Turn::try_from(2).is_err());
+        assert!(Turn::try_from('U').is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use crate::geom::{Dimensions, Grid, Vector2D};
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+/// A [Grid](struct.Grid.html) which grows to fit any non-negative position
+/// written through `IndexMut`, filling new cells with `T::default()`.
+///
+/// ```
+/// use aoc::geom::{ExpandingGrid, Vector2D};
+///
+/// let mut grid = ExpandingGrid::new();
+/// grid[Vector2D { x: 2, y: 1 }] = '#';
+/// assert_eq!(grid.grid().render_with(|&c| if c == '#' { c } else { '.' }), "...\n..#\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExpandingGrid<T> {
+    grid: Grid<T>,
+}
+
+impl<T: Default> ExpandingGrid<T> {
+    pub fn new() -> ExpandingGrid<T> {
+        ExpandingGrid::from_grid(Grid::from_vec(Dimensions::new(), Vec::new()))
+    }
+
+    pub fn from_grid(grid: Grid<T>) -> ExpandingGrid<T> {
+        ExpandingGrid { grid }
+    }
+
+    /// Grow the grid if needed so that it contains the given position.
+    ///
+    /// Panics if either coordinate is negative.
+    pub fn expand_to_fit(&mut self, pos: Vector2D) {
+        assert!(
+            pos.x >= 0 && pos.y >= 0,
+            "ExpandingGrid can't contain negative position {}",
+            pos
+        );
+        if self.grid.contains(pos) {
+            return;
+        }
+
+        let mut dimensions = self.grid.dimensions();
+        dimensions.expand_to_fit(pos);
+        let cells = (0..dimensions.area()).map(|_| T::default()).collect();
+        let mut grid = Grid::from_vec(dimensions, cells);
+        for old_pos in self.grid.dimensions().iter() {
+            grid[old_pos] = mem::take(&mut self.grid[old_pos]);
+        }
+        self.grid = grid;
+    }
+}
+
+impl<T> ExpandingGrid<T> {
+    pub fn dimensions(&self) -> Dimensions {
+        self.grid.dimensions()
+    }
+
+    pub fn get(&self, pos: Vector2D) -> Option<&T> {
+        self.grid.get(pos)
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+}
+
+impl<T: Default> Default for ExpandingGrid<T> {
+    fn default() -> ExpandingGrid<T> {
+        ExpandingGrid::new()
+    }
+}
+
+impl<T> Index<Vector2D> for ExpandingGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector2D) -> &T {
+        &self.grid[pos]
+    }
+}
+
+impl<T: Default> IndexMut<Vector2D> for ExpandingGrid<T> {
+    fn index_mut(&mut self, pos: Vector2D) -> &mut T {
+        self.expand_to_fit(pos);
+        &mut self.grid[pos]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expanding_grid_index_mut() {
+        let mut grid = ExpandingGrid::new();
+        assert_eq!(grid.dimensions(), Dimensions::new());
+        assert_eq!(grid.get(Vector2D::zero()), None);
+
+        grid[Vector2D { x: 1, y: 0 }] = 1;
+        assert_eq!(grid.dimensions(), Dimensions::new_const(2, 1));
+        grid[Vector2D { x: 0, y: 2 }] = 2;
+        assert_eq!(grid.dimensions(), Dimensions::new_const(2, 3));
+        grid[Vector2D { x: 1, y: 1 }] += 3;
+
+        assert_eq!(grid.grid().cells(), &[0, 1, 0, 3, 2, 0]);
+        assert_eq!(grid[Vector2D { x: 1, y: 0 }], 1);
+        assert_eq!(grid.into_grid().dimensions(), Dimensions::new_const(2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn expanding_grid_negative_position() {
+        let mut grid = ExpandingGrid::new();
+        grid[Vector2D { x: -1, y: 0 }] = 'x';
+    }
+}
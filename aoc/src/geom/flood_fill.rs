@@ -0,0 +1,42 @@
+use crate::geom::Vector2D;
+use std::collections::{HashSet, VecDeque};
+
+/// Visit every position reachable from `start` by moving between
+/// [neighbours](struct.Vector2D.html#method.neighbours) for which `is_open`
+/// is true, in order of increasing distance.
+///
+/// `visit` is called once for each position with its distance from `start`,
+/// including `start` itself at distance 0 even if it isn't open.
+///
+/// ```
+/// use aoc::geom::{flood_fill, Vector2D};
+///
+/// // A 3x3 open square around the origin.
+/// let is_open = |pos: Vector2D| pos.x.abs() <= 1 && pos.y.abs() <= 1;
+/// let mut farthest = 0;
+/// let mut count = 0;
+/// flood_fill(Vector2D::zero(), is_open, |_, distance| {
+///     farthest = farthest.max(distance);
+///     count += 1;
+/// });
+/// assert_eq!((count, farthest), (9, 2));
+/// ```
+pub fn flood_fill<O, V>(start: Vector2D, is_open: O, mut visit: V)
+where
+    O: Fn(Vector2D) -> bool,
+    V: FnMut(Vector2D, usize),
+{
+    let mut seen = HashSet::new();
+    let mut open = VecDeque::new();
+    seen.insert(start);
+    open.push_back((start, 0));
+
+    while let Some((pos, distance)) = open.pop_front() {
+        visit(pos, distance);
+        for n in pos.neighbours() {
+            if is_open(n) && seen.insert(n) {
+                open.push_back((n, distance + 1));
+            }
+        }
+    }
+}
@@ -0,0 +1,405 @@
+use crate::geom::{cartograph, flood_fill, Components, Dimensions, Vector2D};
+use std::collections::VecDeque;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of values stored in row-major order, indexed by
+/// [Vector2D](struct.Vector2D.html) positions from (0, 0).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    dimensions: Dimensions,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Construct a Grid from values in row-major order, which must exactly
+    /// fill the given dimensions.
+    pub fn from_vec(dimensions: Dimensions, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), dimensions.area());
+        Grid { dimensions, cells }
+    }
+
+    /// Construct a Grid with every cell set to the given value.
+    pub fn filled(dimensions: Dimensions, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_vec(dimensions, vec![value; dimensions.area()])
+    }
+
+    pub fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    pub fn contains(&self, pos: Vector2D) -> bool {
+        self.dimensions.contains(pos)
+    }
+
+    pub fn get(&self, pos: Vector2D) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(self.dimensions.pos_to_node_index(pos))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Vector2D) -> Option<&mut T> {
+        if self.contains(pos) {
+            self.cells.get_mut(self.dimensions.pos_to_node_index(pos))
+        } else {
+            None
+        }
+    }
+
+    /// The value at a position in an infinite plane tiled with copies of the
+    /// grid, so that coordinates wrap around in both axes.
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_wrapped(&self, pos: Vector2D) -> &T {
+        &self[self.dimensions.wrap(pos)]
+    }
+
+    /// All values in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Iterate over each row of values in turn from the top.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.dimensions.width.max(1))
+    }
+
+    /// Iterate over each column in turn from the left, yielding its values
+    /// from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        self.dimensions
+            .columns()
+            .map(move |column| column.map(move |pos| &self[pos]))
+    }
+
+    /// Iterate over every position and its value in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2D, &T)> {
+        self.dimensions.iter().zip(self.cells.iter())
+    }
+
+    /// [Flood fill](fn.flood_fill.html) within the grid from `start`, through
+    /// cells whose values are open.
+    pub fn flood_fill<O, V>(&self, start: Vector2D, is_open: O, visit: V)
+    where
+        O: Fn(&T) -> bool,
+        V: FnMut(Vector2D, usize),
+    {
+        let is_open = |pos| self.get(pos).is_some_and(&is_open);
+        flood_fill(start, is_open, visit);
+    }
+
+    /// The number of steps from the nearest seed to every cell, moving only
+    /// through passable cells, or None for cells which can't be reached.
+    ///
+    /// Seeds are at distance 0 even if they aren't passable themselves.
+    pub fn distance_field<S, P>(&self, seeds: S, is_passable: P) -> Grid<Option<usize>>
+    where
+        S: IntoIterator<Item = Vector2D>,
+        P: Fn(&T) -> bool,
+    {
+        let mut distances = Grid::filled(self.dimensions, None);
+        let mut open = VecDeque::new();
+        for seed in seeds {
+            if let Some(d @ None) = distances.get_mut(seed) {
+                *d = Some(0);
+                open.push_back((seed, 0));
+            }
+        }
+
+        while let Some((pos, distance)) = open.pop_front() {
+            for n in pos.neighbours() {
+                if !self.get(n).is_some_and(&is_passable) {
+                    continue;
+                }
+                if let Some(d @ None) = distances.get_mut(n) {
+                    *d = Some(distance + 1);
+                    open.push_back((n, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Label the connected regions of the grid, where neighbouring cells are
+    /// in the same region if `is_same_region` is true for their values.
+    pub fn components<F>(&self, is_same_region: F) -> Components
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        Components::new(self, is_same_region)
+    }
+
+    /// A view of part of the grid, without copying, in which `top_left`
+    /// appears at (0, 0). The view must lie entirely within the grid.
+    pub fn view(&self, top_left: Vector2D, dimensions: Dimensions) -> GridView<'_, T> {
+        if dimensions.area() > 0 {
+            let bottom_right = Vector2D {
+                x: dimensions.width as i64 - 1,
+                y: dimensions.height as i64 - 1,
+            };
+            assert!(
+                self.contains(top_left) && self.contains(top_left + bottom_right),
+                "View at {} of {:?} is outside the grid",
+                top_left,
+                dimensions
+            );
+        }
+
+        GridView {
+            grid: self,
+            top_left,
+            dimensions,
+        }
+    }
+
+    /// Draw each value as a character, one line per row from the top with
+    /// each line ending in a newline.
+    pub fn render_with<F: Fn(&T) -> char>(&self, func: F) -> String {
+        let mut canvas = String::with_capacity(self.cells.len() + self.dimensions.height);
+        for row in self.rows() {
+            canvas.extend(row.iter().map(&func));
+            canvas.push('\n');
+        }
+        canvas
+    }
+
+    /// Construct a new Grid of the same dimensions by applying a function to
+    /// every value.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, func: F) -> Grid<U> {
+        Grid::from_vec(self.dimensions, self.cells.iter().map(func).collect())
+    }
+}
+
+impl<T> Index<Vector2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector2D) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Vector2D> for Grid<T> {
+    fn index_mut(&mut self, pos: Vector2D) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {} is outside the grid", pos))
+    }
+}
+
+/// A borrowed rectangular part of a [Grid](struct.Grid.html), with its own
+/// positions starting from (0, 0). See [Grid::view](struct.Grid.html#method.view).
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    top_left: Vector2D,
+    dimensions: Dimensions,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    pub fn contains(&self, pos: Vector2D) -> bool {
+        self.dimensions.contains(pos)
+    }
+
+    pub fn get(&self, pos: Vector2D) -> Option<&'a T> {
+        if self.contains(pos) {
+            self.grid.get(self.top_left + pos)
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over every position in the view and its value in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2D, &'a T)> {
+        let (grid, top_left) = (self.grid, self.top_left);
+        self.dimensions
+            .iter()
+            .map(move |pos| (pos, &grid[top_left + pos]))
+    }
+
+    /// Copy the viewed values into a new Grid.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_vec(
+            self.dimensions,
+            self.iter().map(|(_, value)| value.clone()).collect(),
+        )
+    }
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<T> Index<Vector2D> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector2D) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {} is outside the view", pos))
+    }
+}
+
+/// Read a map of characters into a [Grid](struct.Grid.html), positioned as
+/// by [cartograph](fn.cartograph.html).
+///
+/// The grid is as wide as the longest line, with shorter lines padded by spaces.
+pub fn cartograph_to_grid(input: &str) -> Grid<char> {
+    let mut dimensions = Dimensions::new();
+    let chars: Vec<_> = cartograph(input)
+        .inspect(|&(pos, _)| dimensions.expand_to_fit(pos))
+        .collect();
+
+    let mut grid = Grid::filled(dimensions, ' ');
+    for (pos, c) in chars {
+        grid[pos] = c;
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ascii::assert_ascii_eq;
+
+    #[test]
+    fn grid_get() {
+        let mut grid = Grid::from_vec(
+            Dimensions {
+                width: 3,
+                height: 2,
+            },
+            (0..6).collect(),
+        );
+        assert_eq!(grid.get(Vector2D { x: 2, y: 0 }), Some(&2));
+        assert_eq!(grid[Vector2D { x: 0, y: 1 }], 3);
+        assert_eq!(grid.get(Vector2D { x: 3, y: 0 }), None);
+        assert_eq!(grid.get(Vector2D { x: 0, y: -1 }), None);
+
+        grid[Vector2D { x: 1, y: 1 }] = 10;
+        assert_eq!(grid.cells(), &[0, 1, 2, 3, 10, 5]);
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[0, 1, 2], [3, 10, 5]]);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [[0, 3], [1, 10], [2, 5]]
+        );
+        assert_eq!(grid.map(|v| v * 2).cells(), &[0, 2, 4, 6, 20, 10]);
+    }
+
+    #[test]
+    fn grid_distance_field() {
+        let grid = cartograph_to_grid("..#..\n#.#.#\n.....\n#####\n.....\n");
+        let seeds = vec![Vector2D::zero(), Vector2D { x: 4, y: 0 }];
+        let distances = grid.distance_field(seeds, |&c| c == '.');
+
+        let rendered = distances.render_with(|d| d.map_or('#', |d| (b'0' + d as u8) as char));
+        assert_ascii_eq("01#10\n#2#2#\n43434\n#####\n#####\n", &rendered);
+    }
+
+    #[test]
+    fn grid_render_with() {
+        let grid = cartograph_to_grid("#.#\n..#\n");
+        assert_eq!(grid.render_with(|&c| c), "#.#\n..#\n");
+        assert_eq!(
+            grid.map(|&c| c == '#')
+                .render_with(|&w| if w { 'X' } else { ' ' }),
+            "X X\n  X\n"
+        );
+        assert_eq!(
+            Grid::<char>::from_vec(Default::default(), vec![]).render_with(|&c| c),
+            ""
+        );
+    }
+
+    #[test]
+    fn grid_get_wrapped() {
+        let grid = cartograph_to_grid("ab\ncd\nef\n");
+        assert_eq!(grid.get_wrapped(Vector2D { x: 1, y: 1 }), &'d');
+        assert_eq!(grid.get_wrapped(Vector2D { x: 2, y: 3 }), &'a');
+        assert_eq!(grid.get_wrapped(Vector2D { x: -1, y: -1 }), &'f');
+        assert_eq!(grid.get_wrapped(Vector2D { x: 5, y: -5 }), &'d');
+    }
+
+    #[test]
+    fn grid_view() {
+        let grid = cartograph_to_grid("abcd\nefgh\nijkl\n");
+        let view = grid.view(
+            Vector2D { x: 1, y: 1 },
+            Dimensions {
+                width: 3,
+                height: 2,
+            },
+        );
+        assert_eq!(view[Vector2D::zero()], 'f');
+        assert_eq!(view.get(Vector2D { x: 2, y: 1 }), Some(&'l'));
+        assert_eq!(view.get(Vector2D { x: 3, y: 0 }), None);
+        assert_eq!(view.get(Vector2D { x: -1, y: 0 }), None);
+        assert_eq!(view.iter().map(|(_, &c)| c).collect::<String>(), "fghjkl");
+        assert_eq!(view.to_grid().cells(), &['f', 'g', 'h', 'j', 'k', 'l']);
+    }
+
+    #[test]
+    #[should_panic]
+    fn grid_view_out_of_bounds() {
+        let grid = cartograph_to_grid("ab\ncd\n");
+        grid.view(
+            Vector2D { x: 1, y: 0 },
+            Dimensions {
+                width: 2,
+                height: 1,
+            },
+        );
+    }
+
+    #[test]
+    fn grid_flood_fill() {
+        let grid = cartograph_to_grid("..#..\n#.#.#\n.....\n");
+        let mut distances = Grid::filled(grid.dimensions(), None);
+        grid.flood_fill(
+            Vector2D::zero(),
+            |&c| c == '.',
+            |pos, distance| {
+                distances[pos] = Some(distance);
+            },
+        );
+
+        let rendered: String = distances
+            .cells()
+            .iter()
+            .map(|d| d.map_or('#', |d| (b'0' + d as u8) as char))
+            .collect();
+        assert_eq!(rendered, "01#78#2#6#43456");
+    }
+
+    #[test]
+    fn test_cartograph_to_grid() {
+        let grid = cartograph_to_grid("#.#\r\n.\n##.#\n");
+        assert_eq!(
+            grid.dimensions(),
+            Dimensions {
+                width: 4,
+                height: 3
+            }
+        );
+        assert_eq!(grid.cells().iter().collect::<String>(), "#.# .   ##.#");
+        assert_eq!(
+            grid.iter().find(|&(_, &c)| c == '.').map(|(pos, _)| pos),
+            Some(Vector2D { x: 1, y: 0 })
+        );
+    }
+}
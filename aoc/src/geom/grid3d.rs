@@ -0,0 +1,129 @@
+use crate::geom::{Dimensions3, Vector3D};
+use std::ops::{Index, IndexMut};
+
+/// A box of values indexed by [Vector3D](struct.Vector3D.html) positions
+/// from (0, 0, 0), the 3D counterpart of [Grid](struct.Grid.html).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid3D<T> {
+    dimensions: Dimensions3,
+    cells: Vec<T>,
+}
+
+impl<T> Grid3D<T> {
+    /// Construct a Grid3D from values ordered as in
+    /// [Dimensions3::iter](struct.Dimensions3.html#method.iter), which must
+    /// exactly fill the given dimensions.
+    pub fn from_vec(dimensions: Dimensions3, cells: Vec<T>) -> Grid3D<T> {
+        assert_eq!(cells.len(), dimensions.volume());
+        Grid3D { dimensions, cells }
+    }
+
+    /// Construct a Grid3D with every cell set to the given value.
+    pub fn filled(dimensions: Dimensions3, value: T) -> Grid3D<T>
+    where
+        T: Clone,
+    {
+        Grid3D::from_vec(dimensions, vec![value; dimensions.volume()])
+    }
+
+    pub fn dimensions(&self) -> Dimensions3 {
+        self.dimensions
+    }
+
+    pub fn contains(&self, pos: Vector3D) -> bool {
+        self.dimensions.contains(pos)
+    }
+
+    pub fn get(&self, pos: Vector3D) -> Option<&T> {
+        if self.contains(pos) {
+            self.cells.get(self.dimensions.pos_to_node_index(pos))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Vector3D) -> Option<&mut T> {
+        if self.contains(pos) {
+            self.cells.get_mut(self.dimensions.pos_to_node_index(pos))
+        } else {
+            None
+        }
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Iterate over every position and its value.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector3D, &T)> {
+        self.dimensions.iter().zip(self.cells.iter())
+    }
+
+    /// The face-adjacent neighbours of a position which lie within the grid.
+    pub fn neighbours(&self, pos: Vector3D) -> impl Iterator<Item = Vector3D> + '_ {
+        pos.neighbours().filter(move |&n| self.contains(n))
+    }
+
+    /// Every surrounding position, including diagonals, which lies within
+    /// the grid.
+    pub fn neighbours_with_diagonals(&self, pos: Vector3D) -> impl Iterator<Item = Vector3D> + '_ {
+        pos.neighbours_with_diagonals()
+            .filter(move |&n| self.contains(n))
+    }
+}
+
+impl<T> Index<Vector3D> for Grid3D<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector3D) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Vector3D> for Grid3D<T> {
+    fn index_mut(&mut self, pos: Vector3D) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {} is outside the grid", pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid3d_get() {
+        let dims = Dimensions3 {
+            width: 2,
+            height: 3,
+            depth: 2,
+        };
+        let mut grid = Grid3D::from_vec(dims, (0..12).collect());
+        assert_eq!(grid[Vector3D { x: 1, y: 0, z: 0 }], 1);
+        assert_eq!(grid[Vector3D { x: 0, y: 2, z: 0 }], 4);
+        assert_eq!(grid[Vector3D { x: 1, y: 2, z: 1 }], 11);
+        assert_eq!(grid.get(Vector3D { x: 0, y: 0, z: 2 }), None);
+
+        grid[Vector3D { x: 0, y: 1, z: 1 }] = 100;
+        assert_eq!(grid.cells()[8], 100);
+        assert!(grid.iter().all(|(pos, &v)| grid[pos] == v));
+    }
+
+    #[test]
+    fn grid3d_neighbours() {
+        let dims = Dimensions3 {
+            width: 3,
+            height: 3,
+            depth: 3,
+        };
+        let grid = Grid3D::filled(dims, ());
+        let centre = Vector3D { x: 1, y: 1, z: 1 };
+        assert_eq!(grid.neighbours(centre).count(), 6);
+        assert_eq!(grid.neighbours_with_diagonals(centre).count(), 26);
+
+        let corner = Vector3D::zero();
+        assert_eq!(grid.neighbours(corner).count(), 3);
+        assert_eq!(grid.neighbours_with_diagonals(corner).count(), 7);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::geom::Vector2D;
+
+/// A straight line between two grid positions, including both ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineSegment {
+    pub start: Vector2D,
+    pub end: Vector2D,
+}
+
+impl LineSegment {
+    pub fn new(start: Vector2D, end: Vector2D) -> LineSegment {
+        LineSegment { start, end }
+    }
+
+    /// True if the segment is horizontal or vertical (or a single point).
+    pub fn is_axis_aligned(self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    pub fn manhattan_length(self) -> usize {
+        (self.end - self.start).manhattan_length()
+    }
+
+    /// True if the given position lies exactly on the segment.
+    pub fn contains(self, pos: Vector2D) -> bool {
+        let min = self.start.min_components(self.end);
+        let max = self.start.max_components(self.end);
+        (pos - self.start).cross(self.end - self.start) == 0
+            && (pos.x >= min.x && pos.x <= max.x)
+            && (pos.y >= min.y && pos.y <= max.y)
+    }
+
+    /// True if the segments share at least one point, which need not be a
+    /// grid position.
+    pub fn intersects(self, other: LineSegment) -> bool {
+        match self.crossing(other) {
+            Some(_) => true,
+            None => self.overlap(other).is_some(),
+        }
+    }
+
+    /// The grid position at which the segments meet, if any.
+    ///
+    /// Where the segments are collinear and overlap, this is the shared point
+    /// closest to the start of `self`. Segments which cross between grid
+    /// positions have no intersection point.
+    pub fn intersection_point(self, other: LineSegment) -> Option<Vector2D> {
+        match self.crossing(other) {
+            Some((t, denom)) => {
+                let offset = (self.end - self.start) * t;
+                if offset.x % denom == 0 && offset.y % denom == 0 {
+                    Some(self.start + offset / denom)
+                } else {
+                    None
+                }
+            }
+            None => self.overlap(other),
+        }
+    }
+
+    // For segments that aren't parallel and do cross, the position along self
+    // at which they cross as the fraction t / denom.
+    fn crossing(self, other: LineSegment) -> Option<(i64, i64)> {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let denom = d1.cross(d2);
+        if denom == 0 {
+            return None;
+        }
+
+        let between = other.start - self.start;
+        let (mut t, mut u, mut denom) = (between.cross(d2), between.cross(d1), denom);
+        if denom < 0 {
+            t = -t;
+            u = -u;
+            denom = -denom;
+        }
+
+        if (0..=denom).contains(&t) && (0..=denom).contains(&u) {
+            Some((t, denom))
+        } else {
+            None
+        }
+    }
+
+    // For parallel segments, the shared point closest to the start of self.
+    fn overlap(self, other: LineSegment) -> Option<Vector2D> {
+        [self.start, self.end, other.start, other.end]
+            .iter()
+            .copied()
+            .filter(|&p| self.contains(p) && other.contains(p))
+            .min_by_key(|&p| (p - self.start).manhattan_length())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> LineSegment {
+        LineSegment::new(Vector2D { x: x1, y: y1 }, Vector2D { x: x2, y: y2 })
+    }
+
+    #[test]
+    fn line_segment_crossing() {
+        let a = segment(0, 2, 6, 2);
+        let b = segment(3, 0, 3, 5);
+        assert!(a.intersects(b));
+        assert_eq!(a.intersection_point(b), Some(Vector2D { x: 3, y: 2 }));
+        assert_eq!(b.intersection_point(a), Some(Vector2D { x: 3, y: 2 }));
+
+        // Touching at an end point.
+        let c = segment(6, 2, 6, 8);
+        assert_eq!(a.intersection_point(c), Some(Vector2D { x: 6, y: 2 }));
+
+        // Diagonals crossing between grid positions.
+        let d = segment(0, 0, 1, 1);
+        let e = segment(0, 1, 1, 0);
+        assert!(d.intersects(e));
+        assert_eq!(d.intersection_point(e), None);
+
+        let f = segment(7, 0, 7, 5);
+        assert!(!a.intersects(f));
+        assert_eq!(a.intersection_point(f), None);
+    }
+
+    #[test]
+    fn line_segment_collinear() {
+        let a = segment(0, 0, 5, 0);
+        assert_eq!(
+            a.intersection_point(segment(8, 0, 3, 0)),
+            Some(Vector2D { x: 3, y: 0 })
+        );
+        assert_eq!(
+            segment(5, 0, 0, 0).intersection_point(segment(-2, 0, 3, 0)),
+            Some(Vector2D { x: 3, y: 0 })
+        );
+        assert!(!a.intersects(segment(6, 0, 9, 0)));
+        assert!(!a.intersects(segment(0, 1, 5, 1)));
+        assert!(a.intersects(segment(2, 0, 2, 0)));
+    }
+}
@@ -1,5 +1,47 @@
+mod angle;
+pub use angle::Angle;
+
+mod components;
+pub use components::{Components, Region};
+
 mod dimensions;
 pub use dimensions::{Dimensions, DimensionsIter};
 
+mod dimensions3;
+pub use dimensions3::Dimensions3;
+
+mod direction;
+pub use direction::{Direction, Turn};
+
+mod expanding_grid;
+pub use expanding_grid::ExpandingGrid;
+
+mod flood_fill;
+pub use flood_fill::flood_fill;
+
+mod grid;
+pub use grid::{cartograph_to_grid, Grid, GridView};
+
+mod grid3d;
+pub use grid3d::Grid3D;
+
+mod line_segment;
+pub use line_segment::LineSegment;
+
+mod offset_grid;
+pub use offset_grid::OffsetGrid;
+
+mod polyline;
+pub use polyline::Polyline;
+
+mod rect;
+pub use rect::Rect;
+
+mod sparse_grid;
+pub use sparse_grid::SparseGrid;
+
 mod vector2d;
 pub use vector2d::{cartograph, Vector2D};
+
+mod vector3d;
+pub use vector3d::Vector3D;
@@ -0,0 +1,151 @@
+use crate::geom::{Grid, Rect, Vector2D};
+use std::ops::{Index, IndexMut};
+
+/// A dense [Grid](struct.Grid.html) covering a [Rect](struct.Rect.html) of
+/// positions which may be negative, indexed directly by those positions.
+///
+/// The grid can be grown to fit new positions, so that maps discovered by
+/// exploring outwards from an origin never need to convert coordinates.
+///
+/// ```
+/// use aoc::geom::{OffsetGrid, Vector2D};
+///
+/// let mut grid = OffsetGrid::new(Vector2D::zero(), '.');
+/// grid.expand_to_fit(Vector2D { x: -2, y: 1 }, '.');
+/// grid[Vector2D { x: -2, y: 1 }] = '#';
+/// assert_eq!(grid.grid().cells().iter().collect::<String>(), "...#..");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetGrid<T> {
+    bounds: Rect,
+    cells: Grid<T>,
+}
+
+impl<T: Clone> OffsetGrid<T> {
+    /// Construct a grid containing only the given position.
+    pub fn new(pos: Vector2D, value: T) -> OffsetGrid<T> {
+        OffsetGrid::filled(Rect::from_point(pos), value)
+    }
+
+    /// Construct a grid covering the given bounds with every cell set to the
+    /// given value.
+    pub fn filled(bounds: Rect, value: T) -> OffsetGrid<T> {
+        OffsetGrid {
+            bounds,
+            cells: Grid::filled(bounds.dimensions(), value),
+        }
+    }
+
+    /// Grow the grid if necessary to contain the given position, filling
+    /// any new cells with `fill`.
+    pub fn expand_to_fit(&mut self, pos: Vector2D, fill: T) {
+        if self.contains(pos) {
+            return;
+        }
+
+        let mut bounds = self.bounds;
+        bounds.expand_to_fit(pos);
+        let mut cells = Grid::filled(bounds.dimensions(), fill);
+        for (old_pos, value) in self.iter() {
+            cells[old_pos - bounds.top_left] = value.clone();
+        }
+
+        self.bounds = bounds;
+        self.cells = cells;
+    }
+}
+
+impl<T> OffsetGrid<T> {
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// The underlying Grid, where (0, 0) is the top left of the bounds.
+    pub fn grid(&self) -> &Grid<T> {
+        &self.cells
+    }
+
+    pub fn contains(&self, pos: Vector2D) -> bool {
+        self.bounds.contains(pos)
+    }
+
+    pub fn get(&self, pos: Vector2D) -> Option<&T> {
+        self.cells.get(pos - self.bounds.top_left)
+    }
+
+    pub fn get_mut(&mut self, pos: Vector2D) -> Option<&mut T> {
+        self.cells.get_mut(pos - self.bounds.top_left)
+    }
+
+    /// Iterate over every position and its value in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2D, &T)> {
+        let top_left = self.bounds.top_left;
+        self.cells
+            .iter()
+            .map(move |(pos, value)| (pos + top_left, value))
+    }
+
+    /// The number of cells in the grid.
+    pub fn area(&self) -> usize {
+        self.bounds.area()
+    }
+
+    /// A unique index for each position in the grid, from 0 to the area.
+    pub fn pos_to_node_index(&self, pos: Vector2D) -> usize {
+        assert!(self.contains(pos), "Position {} is outside the grid", pos);
+        self.bounds.pos_to_node_index(pos)
+    }
+
+    pub fn node_index_to_pos(&self, index: usize) -> Vector2D {
+        assert!(
+            index < self.area(),
+            "Node index {} is outside the grid",
+            index
+        );
+        self.bounds.node_index_to_pos(index)
+    }
+}
+
+impl<T> Index<Vector2D> for OffsetGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vector2D) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Vector2D> for OffsetGrid<T> {
+    fn index_mut(&mut self, pos: Vector2D) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {} is outside the grid", pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_grid_expand() {
+        let mut grid = OffsetGrid::new(Vector2D { x: 1, y: 1 }, 0);
+        grid[Vector2D { x: 1, y: 1 }] = 1;
+
+        grid.expand_to_fit(Vector2D { x: -1, y: 2 }, 0);
+        grid[Vector2D { x: -1, y: 2 }] = 2;
+        grid.expand_to_fit(Vector2D { x: 0, y: 1 }, 9);
+
+        assert_eq!(
+            grid.bounds(),
+            Rect::new(Vector2D { x: -1, y: 1 }, Vector2D { x: 1, y: 2 })
+        );
+        assert_eq!(grid.grid().cells(), &[0, 0, 1, 2, 0, 0]);
+        assert_eq!(grid.get(Vector2D { x: 2, y: 1 }), None);
+        assert_eq!(grid.get(Vector2D { x: -2, y: 1 }), None);
+
+        for (index, (pos, _)) in grid.iter().enumerate() {
+            assert_eq!(grid.pos_to_node_index(pos), index);
+            assert_eq!(grid.node_index_to_pos(index), pos);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::geom::{Direction, LineSegment, Vector2D};
+use crate::Error;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// A path made of straight moves in compass directions, such as a wire.
+///
+/// Can be parsed from comma separated moves, each a
+/// [Direction](enum.Direction.html) character followed by a length, in which
+/// case the path starts at the origin.
+///
+/// ```
+/// use aoc::geom::{Polyline, Vector2D};
+///
+/// let path: Polyline = "R2,D1".parse().unwrap();
+/// assert_eq!(path.length(), 3);
+/// assert_eq!(path.end(), Vector2D { x: 2, y: 1 });
+/// assert_eq!(path.walk().count(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polyline {
+    start: Vector2D,
+    moves: Vec<(Direction, usize)>,
+}
+
+impl Polyline {
+    /// An empty path at the given start position.
+    pub fn new(start: Vector2D) -> Polyline {
+        Polyline {
+            start,
+            moves: Vec::new(),
+        }
+    }
+
+    /// Extend the path by moving `length` steps in the given direction.
+    pub fn push(&mut self, direction: Direction, length: usize) {
+        self.moves.push((direction, length));
+    }
+
+    pub fn start(&self) -> Vector2D {
+        self.start
+    }
+
+    pub fn end(&self) -> Vector2D {
+        self.segments().last().map_or(self.start, |s| s.end)
+    }
+
+    pub fn moves(&self) -> &[(Direction, usize)] {
+        &self.moves
+    }
+
+    /// The total number of steps along the path.
+    pub fn length(&self) -> usize {
+        self.moves.iter().map(|(_, length)| length).sum()
+    }
+
+    /// Every position visited after the start, one step at a time.
+    pub fn walk(&self) -> impl Iterator<Item = Vector2D> + '_ {
+        let steps = self
+            .moves
+            .iter()
+            .flat_map(|&(direction, length)| (0..length).map(move |_| direction.offset()));
+        steps.scan(self.start, |pos, step| {
+            *pos += step;
+            Some(*pos)
+        })
+    }
+
+    /// Each straight move as a line segment, in order.
+    pub fn segments(&self) -> impl Iterator<Item = LineSegment> + '_ {
+        self.moves
+            .iter()
+            .scan(self.start, |pos, &(direction, length)| {
+                let start = *pos;
+                *pos += direction.offset() * length as i64;
+                Some(LineSegment::new(start, *pos))
+            })
+    }
+}
+
+impl FromStr for Polyline {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut path = Polyline::new(Vector2D::zero());
+        for step in input.trim().split(',') {
+            let step = step.trim();
+            let mut chars = step.chars();
+            let direction = chars
+                .next()
+                .ok_or_else(|| Error::Parse("Empty move".into()))
+                .and_then(Direction::try_from)?;
+            let length = chars
+                .as_str()
+                .parse()
+                .map_err(|e| Error::Parse(format!("Invalid length in '{}': {}", step, e)))?;
+            path.push(direction, length);
+        }
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polyline_parse() {
+        let path = "R8,U5,L5,D3".parse::<Polyline>().unwrap();
+        assert_eq!(
+            path.moves(),
+            &[
+                (Direction::East, 8),
+                (Direction::North, 5),
+                (Direction::West, 5),
+                (Direction::South, 3)
+            ]
+        );
+        assert_eq!(path.length(), 21);
+
+        assert!("R8,X5".parse::<Polyline>().is_err());
+        assert!("R8,U".parse::<Polyline>().is_err());
+        assert!("R8,,U1".parse::<Polyline>().is_err());
+    }
+
+    #[test]
+    fn polyline_walk_and_segments() {
+        let path = "R2,U1,L3".parse::<Polyline>().unwrap();
+        let walk = path.walk().map(|p| (p.x, p.y)).collect::<Vec<_>>();
+        assert_eq!(walk, [(1, 0), (2, 0), (2, -1), (1, -1), (0, -1), (-1, -1)]);
+
+        let segments = path.segments().collect::<Vec<_>>();
+        assert_eq!(
+            segments,
+            [
+                LineSegment::new((0, 0).into(), (2, 0).into()),
+                LineSegment::new((2, 0).into(), (2, -1).into()),
+                LineSegment::new((2, -1).into(), (-1, -1).into()),
+            ]
+        );
+        assert_eq!(path.end(), Vector2D { x: -1, y: -1 });
+        assert_eq!(
+            Polyline::new(Vector2D { x: 4, y: 4 }).end(),
+            Vector2D { x: 4, y: 4 }
+        );
+    }
+}
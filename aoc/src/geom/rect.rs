@@ -0,0 +1,138 @@
+use crate::geom::{Dimensions, Vector2D};
+use std::convert::TryInto;
+
+/// An axis-aligned rectangle of grid positions, which unlike
+/// [Dimensions](struct.Dimensions.html) may include negative coordinates.
+///
+/// Both corners are inclusive, so a Rect always contains at least one position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub top_left: Vector2D,
+    pub bottom_right: Vector2D,
+}
+
+impl Rect {
+    /// The smallest Rect containing both of the given corners.
+    pub fn new(a: Vector2D, b: Vector2D) -> Rect {
+        Rect {
+            top_left: a.min_components(b),
+            bottom_right: a.max_components(b),
+        }
+    }
+
+    /// A Rect containing only the given position.
+    pub fn from_point(pos: Vector2D) -> Rect {
+        Rect::new(pos, pos)
+    }
+
+    /// The smallest Rect containing all of the given positions, or None if
+    /// there are none.
+    pub fn bounding<I: IntoIterator<Item = Vector2D>>(positions: I) -> Option<Rect> {
+        let mut positions = positions.into_iter();
+        let mut rect = Rect::from_point(positions.next()?);
+        for pos in positions {
+            rect.expand_to_fit(pos);
+        }
+        Some(rect)
+    }
+
+    pub fn width(self) -> usize {
+        (self.bottom_right.x - self.top_left.x + 1)
+            .try_into()
+            .unwrap()
+    }
+
+    pub fn height(self) -> usize {
+        (self.bottom_right.y - self.top_left.y + 1)
+            .try_into()
+            .unwrap()
+    }
+
+    pub fn area(self) -> usize {
+        self.width() * self.height()
+    }
+
+    pub fn dimensions(self) -> Dimensions {
+        Dimensions {
+            width: self.width(),
+            height: self.height(),
+        }
+    }
+
+    pub fn expand_to_fit(&mut self, pos: Vector2D) {
+        self.top_left = self.top_left.min_components(pos);
+        self.bottom_right = self.bottom_right.max_components(pos);
+    }
+
+    pub fn contains(self, pos: Vector2D) -> bool {
+        (pos.x >= self.top_left.x && pos.x <= self.bottom_right.x)
+            && (pos.y >= self.top_left.y && pos.y <= self.bottom_right.y)
+    }
+
+    /// Iterate over every position in row-major order, starting at the top left.
+    pub fn iter(self) -> impl Iterator<Item = Vector2D> {
+        self.dimensions().iter().map(move |pos| pos + self.top_left)
+    }
+
+    pub fn pos_to_node_index(self, pos: Vector2D) -> usize {
+        self.dimensions().pos_to_node_index(pos - self.top_left)
+    }
+
+    pub fn node_index_to_pos(self, index: usize) -> Vector2D {
+        self.dimensions().node_index_to_pos(index) + self.top_left
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_bounding() {
+        let positions = [(2, -1), (-3, 4), (0, 0)]
+            .iter()
+            .copied()
+            .map(Vector2D::from);
+        let rect = Rect::bounding(positions).unwrap();
+        assert_eq!(rect.top_left, Vector2D { x: -3, y: -1 });
+        assert_eq!(rect.bottom_right, Vector2D { x: 2, y: 4 });
+        assert_eq!(rect.width(), 6);
+        assert_eq!(rect.height(), 6);
+        assert_eq!(rect.area(), 36);
+
+        assert!(Rect::bounding(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn rect_contains() {
+        let mut rect = Rect::from_point(Vector2D { x: -1, y: -1 });
+        rect.expand_to_fit(Vector2D { x: 1, y: 0 });
+
+        assert!(rect.contains(Vector2D { x: -1, y: -1 }));
+        assert!(rect.contains(Vector2D { x: 1, y: 0 }));
+        assert!(rect.contains(Vector2D { x: 0, y: -1 }));
+
+        assert!(!rect.contains(Vector2D { x: -2, y: -1 }));
+        assert!(!rect.contains(Vector2D { x: 2, y: 0 }));
+        assert!(!rect.contains(Vector2D { x: 0, y: 1 }));
+        assert!(!rect.contains(Vector2D { x: 0, y: -2 }));
+    }
+
+    #[test]
+    fn rect_iter() {
+        let rect = Rect::new(Vector2D { x: 0, y: -1 }, Vector2D { x: -1, y: 0 });
+        let items = rect.iter().collect::<Vec<_>>();
+
+        let expected = [(-1, -1), (0, -1), (-1, 0), (0, 0)]
+            .iter()
+            .copied()
+            .map(Vector2D::from)
+            .collect::<Vec<_>>();
+        assert_eq!(items, expected);
+
+        for (index, &pos) in expected.iter().enumerate() {
+            assert_eq!(rect.pos_to_node_index(pos), index);
+            assert_eq!(rect.node_index_to_pos(index), pos);
+        }
+    }
+}
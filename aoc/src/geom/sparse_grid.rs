@@ -0,0 +1,167 @@
+use crate::geom::{Grid, Rect, Vector2D};
+use std::collections::HashMap;
+
+/// Values at arbitrary positions, including negative coordinates, which
+/// tracks the bounding [Rect](struct.Rect.html) of every position set.
+///
+/// ```
+/// use aoc::geom::{SparseGrid, Vector2D};
+///
+/// let mut grid = SparseGrid::new();
+/// grid.insert(Vector2D { x: -1, y: 0 }, '#');
+/// grid.insert(Vector2D { x: 1, y: 1 }, '#');
+/// let rendered = grid.render(|_, c| *c.unwrap_or(&'.'));
+/// assert_eq!(rendered, "#..\n..#\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vector2D, T>,
+    bounds: Option<Rect>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Set the value at a position, returning the previous value if any.
+    pub fn insert(&mut self, pos: Vector2D, value: T) -> Option<T> {
+        self.expand_bounds(pos);
+        self.cells.insert(pos, value)
+    }
+
+    /// The value at a position, setting it to `default` first if unset.
+    pub fn get_or_insert(&mut self, pos: Vector2D, default: T) -> &mut T {
+        self.expand_bounds(pos);
+        self.cells.entry(pos).or_insert(default)
+    }
+
+    pub fn get(&self, pos: Vector2D) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Vector2D) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Vector2D) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// The number of positions which have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The smallest Rect containing every position set, or None if empty.
+    pub fn bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    /// Iterate over every position set and its value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vector2D, &T)> {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    /// Draw every position within the bounds as a character, one line per
+    /// row from the top with each line ending in a newline.
+    pub fn render<F: Fn(Vector2D, Option<&T>) -> char>(&self, func: F) -> String {
+        let mut canvas = String::new();
+        if let Some(bounds) = self.bounds {
+            for pos in bounds.iter() {
+                canvas.push(func(pos, self.get(pos)));
+                if pos.x == bounds.bottom_right.x {
+                    canvas.push('\n');
+                }
+            }
+        }
+        canvas
+    }
+
+    /// Draw every value within the bounds as a character, with unset
+    /// positions drawn as `empty`, in the same layout as
+    /// [render](#method.render).
+    pub fn render_with<F: Fn(&T) -> char>(&self, empty: char, func: F) -> String {
+        self.render(|_, value| value.map_or(empty, &func))
+    }
+
+    /// Copy into a dense [Grid](struct.Grid.html) covering the bounds, with
+    /// unset positions filled with `empty`. Position (0, 0) in the Grid is
+    /// the top left of the bounds.
+    pub fn to_grid(&self, empty: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let bounds = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Grid::from_vec(Default::default(), Vec::new()),
+        };
+        let mut grid = Grid::filled(bounds.dimensions(), empty);
+        for (&pos, value) in self.cells.iter() {
+            grid[pos - bounds.top_left] = value.clone();
+        }
+        grid
+    }
+
+    fn expand_bounds(&mut self, pos: Vector2D) {
+        match self.bounds.as_mut() {
+            Some(bounds) => bounds.expand_to_fit(pos),
+            None => self.bounds = Some(Rect::from_point(pos)),
+        }
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_grid_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.render(|_, _| '?'), "");
+
+        grid.insert(Vector2D { x: 2, y: -3 }, 1);
+        assert_eq!(grid.insert(Vector2D { x: -1, y: 0 }, 2), None);
+        assert_eq!(grid.insert(Vector2D { x: -1, y: 0 }, 3), Some(2));
+        *grid.get_or_insert(Vector2D { x: 0, y: -1 }, 0) += 5;
+        assert_eq!(grid.len(), 3);
+        assert_eq!(
+            grid.bounds(),
+            Some(Rect::new(
+                Vector2D { x: -1, y: -3 },
+                Vector2D { x: 2, y: 0 }
+            ))
+        );
+
+        let dense = grid.to_grid(0);
+        assert_eq!(
+            dense.cells(),
+            &[0, 0, 0, 1, 0, 0, 0, 0, 0, 5, 0, 0, 3, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn sparse_grid_render_with() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Vector2D { x: 3, y: 5 }, true);
+        grid.insert(Vector2D { x: 1, y: 4 }, false);
+        assert_eq!(
+            grid.render_with('.', |&b| if b { '#' } else { 'o' }),
+            "o..\n..#\n"
+        );
+    }
+}
@@ -1,7 +1,12 @@
+use crate::math::gcd;
+use crate::Error;
+use std::convert::TryFrom;
 use std::fmt;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector2D {
     pub x: i64,
     pub y: i64,
@@ -20,12 +25,6 @@ where
     }
 }
 
-impl Default for Vector2D {
-    fn default() -> Vector2D {
-        Vector2D { x: 0, y: 0 }
-    }
-}
-
 impl Add for Vector2D {
     type Output = Vector2D;
 
@@ -62,9 +61,62 @@ impl SubAssign for Vector2D {
     }
 }
 
+impl Mul<i64> for Vector2D {
+    type Output = Vector2D;
+
+    fn mul(self, rhs: i64) -> Vector2D {
+        Vector2D {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl MulAssign<i64> for Vector2D {
+    fn mul_assign(&mut self, rhs: i64) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl Div<i64> for Vector2D {
+    type Output = Vector2D;
+
+    fn div(self, rhs: i64) -> Vector2D {
+        Vector2D {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl Neg for Vector2D {
+    type Output = Vector2D;
+
+    fn neg(self) -> Vector2D {
+        Vector2D {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
 impl Vector2D {
-    pub fn zero() -> Vector2D {
-        Vector2D::default()
+    /// The four unit vectors pointing west, east, north and south, in the
+    /// order [neighbours](#method.neighbours) visits them.
+    pub const CARDINAL_DIRECTIONS: [Vector2D; 4] = [
+        Vector2D::new(-1, 0),
+        Vector2D::new(1, 0),
+        Vector2D::new(0, -1),
+        Vector2D::new(0, 1),
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Vector2D {
+        Vector2D { x, y }
+    }
+
+    pub const fn zero() -> Vector2D {
+        Vector2D::new(0, 0)
     }
 
     pub fn manhattan_length(self) -> usize {
@@ -85,9 +137,97 @@ impl Vector2D {
         }
     }
 
+    /// The sign of each component, as -1, 0 or 1.
+    pub fn signum(self) -> Vector2D {
+        Vector2D {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Clamp each component to lie within the matching components of `min`
+    /// and `max`, inclusive.
+    pub fn clamp_components(self, min: Vector2D, max: Vector2D) -> Vector2D {
+        self.max_components(min).min_components(max)
+    }
+
     pub fn neighbours(self) -> Neighbours {
         Neighbours::new(self)
     }
+
+    pub fn dot(self, other: Vector2D) -> i64 {
+        (self.x * other.x) + (self.y * other.y)
+    }
+
+    /// A vector of the same length at right angles to this one, such that
+    /// `v.cross(v.perp())` is positive. With y pointing down this is a
+    /// quarter turn clockwise.
+    pub fn perp(self) -> Vector2D {
+        Vector2D {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// The z component of the cross product of the two vectors extended
+    /// into 3D, which is positive if `other` is clockwise from this one
+    /// with y pointing down.
+    pub fn cross(self, other: Vector2D) -> i64 {
+        (self.x * other.y) - (self.y * other.x)
+    }
+
+    /// This vector divided by the greatest common divisor of its components,
+    /// giving the smallest step in the same direction. All positions along a
+    /// line of sight from the origin share the same primitive vector.
+    pub fn primitive(self) -> Vector2D {
+        match i64::try_from(gcd(self.x.unsigned_abs(), self.y.unsigned_abs())) {
+            Ok(0) => self,
+            Ok(divisor) => self / divisor,
+            // Only a gcd of 2^63 is too large, where every component is zero
+            // or i64::MIN.
+            Err(_) => Vector2D::new(self.x.signum(), self.y.signum()),
+        }
+    }
+
+    /// All positions at exactly the given manhattan distance from this one.
+    pub fn manhattan_ring(self, radius: usize) -> impl Iterator<Item = Vector2D> {
+        let r = radius as i64;
+        let centre_only = if r == 0 { Some(self) } else { None };
+        let ring = (0..r).flat_map(move |i| {
+            let quarter = Vector2D { x: r - i, y: i };
+            (0..4).map(move |n| self + quarter.rotate(n))
+        });
+        centre_only.into_iter().chain(ring)
+    }
+
+    /// All positions within the given manhattan distance of this one, in
+    /// order of increasing distance.
+    pub fn manhattan_disk(self, radius: usize) -> impl Iterator<Item = Vector2D> {
+        (0..=radius).flat_map(move |r| self.manhattan_ring(r))
+    }
+
+    /// Rotate a quarter turn clockwise about the origin, with y pointing down
+    /// as in [cartograph](fn.cartograph.html).
+    pub fn rotate_cw(self) -> Vector2D {
+        self.perp()
+    }
+
+    /// Rotate a quarter turn counter-clockwise about the origin, with y
+    /// pointing down as in [cartograph](fn.cartograph.html).
+    pub fn rotate_ccw(self) -> Vector2D {
+        -self.perp()
+    }
+
+    /// Rotate by the given number of quarter turns clockwise, or
+    /// counter-clockwise if negative.
+    pub fn rotate(self, n_quarters: i64) -> Vector2D {
+        match n_quarters.rem_euclid(4) {
+            0 => self,
+            1 => self.rotate_cw(),
+            2 => -self,
+            _ => self.rotate_ccw(),
+        }
+    }
 }
 
 impl fmt::Display for Vector2D {
@@ -96,12 +236,25 @@ impl fmt::Display for Vector2D {
     }
 }
 
-const CARDINAL_DIRECTIONS: [Vector2D; 4] = [
-    Vector2D { x: -1, y: 0 },
-    Vector2D { x: 1, y: 0 },
-    Vector2D { x: 0, y: -1 },
-    Vector2D { x: 0, y: 1 },
-];
+/// Parses the `x,y` format, allowing whitespace around the values.
+impl FromStr for Vector2D {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| Error::Parse(format!("Vector2D '{}' must be in the form x,y", s)))?;
+        let parse = |v: &str| {
+            v.trim()
+                .parse()
+                .map_err(|e| Error::Parse(format!("Invalid Vector2D '{}': {}", s, e)))
+        };
+        Ok(Vector2D {
+            x: parse(x)?,
+            y: parse(y)?,
+        })
+    }
+}
 
 pub struct Neighbours {
     centre: Vector2D,
@@ -118,8 +271,8 @@ impl Iterator for Neighbours {
     type Item = Vector2D;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < CARDINAL_DIRECTIONS.len() {
-            let v = self.centre + CARDINAL_DIRECTIONS[self.index];
+        if self.index < Vector2D::CARDINAL_DIRECTIONS.len() {
+            let v = self.centre + Vector2D::CARDINAL_DIRECTIONS[self.index];
             self.index += 1;
             Some(v)
         } else {
@@ -172,11 +325,38 @@ mod tests {
         assert_eq!(v, Vector2D { x: -7, y: -5 });
     }
 
+    #[test]
+    fn vector2d_scalar_ops() {
+        let v = Vector2D { x: 3, y: -4 };
+        assert_eq!(v * 3, Vector2D { x: 9, y: -12 });
+        assert_eq!(v * -1, -v);
+        assert_eq!(-v, Vector2D { x: -3, y: 4 });
+        assert_eq!((v * 5) / 5, v);
+        assert_eq!(Vector2D { x: 7, y: -7 } / 2, Vector2D { x: 3, y: -3 });
+
+        let mut w = v;
+        w *= -2;
+        assert_eq!(w, Vector2D { x: -6, y: 8 });
+    }
+
     #[test]
     fn vector2d_zero() {
         assert_eq!(Vector2D::zero(), Vector2D { x: 0, y: 0 });
     }
 
+    #[test]
+    fn vector2d_const_new() {
+        const V: Vector2D = Vector2D::new(3, -4);
+        assert_eq!(V, Vector2D { x: 3, y: -4 });
+        assert_eq!(
+            Vector2D::CARDINAL_DIRECTIONS
+                .iter()
+                .map(|&d| Vector2D::zero() + d)
+                .collect::<Vec<_>>(),
+            Vector2D::zero().neighbours().collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn vector2d_manhattan_length() {
         assert_eq!(Vector2D::zero().manhattan_length(), 0);
@@ -249,6 +429,130 @@ mod tests {
         assert!(neighbours.contains(&Vector2D { x: 5, y: -3 }));
     }
 
+    #[test]
+    fn vector2d_signum() {
+        assert_eq!(Vector2D { x: 7, y: -3 }.signum(), Vector2D { x: 1, y: -1 });
+        assert_eq!(Vector2D { x: 0, y: 12 }.signum(), Vector2D { x: 0, y: 1 });
+        assert_eq!(Vector2D::zero().signum(), Vector2D::zero());
+    }
+
+    #[test]
+    fn vector2d_clamp_components() {
+        let min = Vector2D { x: -2, y: 0 };
+        let max = Vector2D { x: 2, y: 5 };
+        let clamp = |x, y| Vector2D { x, y }.clamp_components(min, max);
+        assert_eq!(clamp(1, 3), Vector2D { x: 1, y: 3 });
+        assert_eq!(clamp(-5, 3), Vector2D { x: -2, y: 3 });
+        assert_eq!(clamp(10, -1), Vector2D { x: 2, y: 0 });
+        assert_eq!(clamp(2, 6), Vector2D { x: 2, y: 5 });
+    }
+
+    #[test]
+    fn vector2d_dot() {
+        assert_eq!(Vector2D { x: 2, y: 3 }.dot(Vector2D { x: 4, y: -1 }), 5);
+        assert_eq!(Vector2D { x: 1, y: 0 }.dot(Vector2D { x: 0, y: 7 }), 0);
+        assert_eq!(Vector2D { x: -3, y: 2 }.dot(Vector2D { x: -3, y: 2 }), 13);
+    }
+
+    #[test]
+    fn vector2d_perp() {
+        let v = Vector2D { x: 3, y: -2 };
+        assert_eq!(v.perp(), Vector2D { x: 2, y: 3 });
+        assert_eq!(v.perp(), v.rotate_cw());
+        assert_eq!(v.dot(v.perp()), 0);
+        assert!(v.cross(v.perp()) > 0);
+    }
+
+    #[test]
+    fn vector2d_cross() {
+        let north = Vector2D { x: 0, y: -1 };
+        let east = Vector2D { x: 1, y: 0 };
+        assert_eq!(north.cross(east), 1);
+        assert_eq!(east.cross(north), -1);
+        assert_eq!(north.cross(north * 3), 0);
+        assert_eq!(Vector2D { x: 2, y: 3 }.cross(Vector2D { x: 4, y: -1 }), -14);
+    }
+
+    #[test]
+    fn vector2d_primitive() {
+        assert_eq!(Vector2D::zero().primitive(), Vector2D::zero());
+        assert_eq!(
+            Vector2D { x: 6, y: -4 }.primitive(),
+            Vector2D { x: 3, y: -2 }
+        );
+        assert_eq!(
+            Vector2D { x: 0, y: -7 }.primitive(),
+            Vector2D { x: 0, y: -1 }
+        );
+        assert_eq!(
+            Vector2D { x: -5, y: 0 }.primitive(),
+            Vector2D { x: -1, y: 0 }
+        );
+        assert_eq!(Vector2D { x: 3, y: 5 }.primitive(), Vector2D { x: 3, y: 5 });
+        assert_eq!(
+            Vector2D::new(i64::MIN, 0).primitive(),
+            Vector2D { x: -1, y: 0 }
+        );
+    }
+
+    #[test]
+    fn vector2d_manhattan_ring() {
+        use std::collections::HashSet;
+
+        let centre = Vector2D { x: 2, y: -3 };
+        assert_eq!(centre.manhattan_ring(0).collect::<Vec<_>>(), [centre]);
+
+        for radius in 1..5 {
+            let ring = centre.manhattan_ring(radius).collect::<Vec<_>>();
+            assert_eq!(ring.len(), radius * 4);
+            assert_eq!(ring.iter().collect::<HashSet<_>>().len(), ring.len());
+            assert!(ring
+                .iter()
+                .all(|&p| (p - centre).manhattan_length() == radius));
+        }
+    }
+
+    #[test]
+    fn vector2d_manhattan_disk() {
+        let centre = Vector2D { x: -1, y: 1 };
+        let disk = centre.manhattan_disk(3).collect::<Vec<_>>();
+        assert_eq!(disk.len(), 1 + 4 + 8 + 12);
+
+        let distances = disk
+            .iter()
+            .map(|&p| (p - centre).manhattan_length())
+            .collect::<Vec<_>>();
+        assert!(distances.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(distances.last(), Some(&3));
+    }
+
+    #[test]
+    fn vector2d_rotate() {
+        use crate::geom::Direction;
+
+        let v = Vector2D { x: 3, y: -1 };
+        assert_eq!(v.rotate_cw(), Vector2D { x: 1, y: 3 });
+        assert_eq!(v.rotate_ccw(), Vector2D { x: -1, y: -3 });
+        assert_eq!(v.rotate(2), Vector2D { x: -3, y: 1 });
+        assert_eq!(v.rotate(-1), v.rotate_ccw());
+        assert_eq!(v.rotate(7), v.rotate_ccw());
+        assert_eq!(v.rotate(4), v);
+
+        for &d in Direction::ALL.iter() {
+            assert_eq!(d.offset().rotate_cw(), d.turn_right().offset());
+            assert_eq!(d.offset().rotate_ccw(), d.turn_left().offset());
+        }
+    }
+
+    #[test]
+    fn vector2d_from_str() {
+        assert_eq!("3,-4".parse::<Vector2D>(), Ok(Vector2D { x: 3, y: -4 }));
+        assert_eq!(" 10 , 0 ".parse::<Vector2D>(), Ok(Vector2D { x: 10, y: 0 }));
+        assert!("3".parse::<Vector2D>().is_err());
+        assert!("3,4,5".parse::<Vector2D>().is_err());
+        assert!("a,4".parse::<Vector2D>().is_err());
+    }
+
     #[test]
     fn test_cartograph() {
         let map = cartograph("123\r\n45\n6789\n").collect::<Vec<_>>();
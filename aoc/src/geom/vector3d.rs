@@ -0,0 +1,197 @@
+use crate::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3D {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Add for Vector3D {
+    type Output = Vector3D;
+
+    fn add(self, rhs: Vector3D) -> Vector3D {
+        Vector3D {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl AddAssign for Vector3D {
+    fn add_assign(&mut self, rhs: Vector3D) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl Sub for Vector3D {
+    type Output = Vector3D;
+
+    fn sub(self, rhs: Vector3D) -> Vector3D {
+        Vector3D {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl SubAssign for Vector3D {
+    fn sub_assign(&mut self, rhs: Vector3D) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// Access the components by axis, in the order x, y, z.
+impl Index<usize> for Vector3D {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Invalid axis {} for Vector3D", axis),
+        }
+    }
+}
+
+impl Vector3D {
+    pub fn zero() -> Vector3D {
+        Vector3D::default()
+    }
+
+    pub fn manhattan_length(self) -> usize {
+        (self.x.abs() + self.y.abs() + self.z.abs()) as usize
+    }
+
+    /// The six positions sharing a face with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Vector3D> {
+        self.neighbours_with_diagonals()
+            .filter(move |&n| (n - self).manhattan_length() == 1)
+    }
+
+    /// The 26 positions surrounding this one, including diagonals.
+    pub fn neighbours_with_diagonals(self) -> impl Iterator<Item = Vector3D> {
+        (0..27).filter(|&n| n != 13).map(move |n| {
+            self + Vector3D {
+                x: (n % 3) - 1,
+                y: ((n / 3) % 3) - 1,
+                z: (n / 9) - 1,
+            }
+        })
+    }
+}
+
+impl fmt::Display for Vector3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<x={}, y={}, z={}>", self.x, self.y, self.z)
+    }
+}
+
+/// Parses the `<x=1, y=-2, z=3>` format, allowing whitespace around the
+/// values.
+impl FromStr for Vector3D {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s
+            .trim()
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .ok_or_else(|| Error::Parse(format!("Vector3D '{}' must be enclosed in <>", s)))?;
+
+        let mut components = [0; 3];
+        let mut parts = inner.split(',');
+        for (name, value) in ["x", "y", "z"].iter().zip(components.iter_mut()) {
+            let part = parts
+                .next()
+                .ok_or_else(|| Error::Parse(format!("Vector3D '{}' is missing {}", s, name)))?;
+            *value = match part.split_once('=') {
+                Some((n, v)) if n.trim() == *name => v.trim().parse().map_err(|e| {
+                    Error::Parse(format!("Invalid {} in Vector3D '{}': {}", name, s, e))
+                })?,
+                _ => {
+                    return Err(Error::Parse(format!(
+                        "Expected {}= in Vector3D '{}'",
+                        name, s
+                    )))
+                }
+            };
+        }
+        if parts.next().is_some() {
+            return Err(Error::Parse(format!(
+                "Too many components in Vector3D '{}'",
+                s
+            )));
+        }
+
+        let [x, y, z] = components;
+        Ok(Vector3D { x, y, z })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector3d_ops() {
+        let mut v = Vector3D { x: 1, y: 2, z: 3 };
+        let w = Vector3D { x: -4, y: 0, z: 7 };
+        assert_eq!(v + w, Vector3D { x: -3, y: 2, z: 10 });
+        assert_eq!(v - w, Vector3D { x: 5, y: 2, z: -4 });
+        v += w;
+        assert_eq!(v, Vector3D { x: -3, y: 2, z: 10 });
+        v -= w;
+        assert_eq!(v, Vector3D { x: 1, y: 2, z: 3 });
+        assert_eq!((v[0], v[1], v[2]), (1, 2, 3));
+        assert_eq!(w.manhattan_length(), 11);
+    }
+
+    #[test]
+    fn vector3d_neighbours() {
+        let v = Vector3D { x: 5, y: -2, z: 0 };
+        let neighbours = v.neighbours().collect::<Vec<_>>();
+        assert_eq!(neighbours.len(), 6);
+        assert!(neighbours.iter().all(|&n| (n - v).manhattan_length() == 1));
+
+        let surrounding = v.neighbours_with_diagonals().collect::<Vec<_>>();
+        assert_eq!(surrounding.len(), 26);
+        assert!(!surrounding.contains(&v));
+        assert!(surrounding.contains(&Vector3D { x: 4, y: -1, z: 1 }));
+    }
+
+    #[test]
+    fn vector3d_from_str() {
+        assert_eq!(
+            "<x=-1, y=0, z=2>".parse::<Vector3D>(),
+            Ok(Vector3D { x: -1, y: 0, z: 2 })
+        );
+        assert_eq!(
+            " <x= 2, y=-10, z=-7> ".parse::<Vector3D>(),
+            Ok(Vector3D {
+                x: 2,
+                y: -10,
+                z: -7
+            })
+        );
+        let v = Vector3D { x: 4, y: -8, z: 8 };
+        assert_eq!(v.to_string().parse::<Vector3D>(), Ok(v));
+
+        assert!("x=1, y=2, z=3".parse::<Vector3D>().is_err());
+        assert!("<x=1, y=2>".parse::<Vector3D>().is_err());
+        assert!("<x=1, z=2, y=3>".parse::<Vector3D>().is_err());
+        assert!("<x=1, y=2, z=3, w=4>".parse::<Vector3D>().is_err());
+        assert!("<x=1, y=a, z=3>".parse::<Vector3D>().is_err());
+    }
+}
@@ -1,4 +1,14 @@
-pub trait Graph {
+use crate::disjoint_set::DisjointSet;
+use rayon::prelude::*;
+use std::cmp::Reverse;
+use std::collections::hash_map::RandomState;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::{BuildHasher, Hash};
+
+/// A graph of nodes identified by index. Graphs must be `Sync` so that
+/// independent searches can be run across threads, as in
+/// [shortest_path_searches](#method.shortest_path_searches).
+pub trait Graph: Sync {
     fn num_nodes(&self) -> usize;
     fn node_edges(&self, node_index: usize) -> Vec<Edge>;
 
@@ -7,6 +17,40 @@ pub trait Graph {
         start_index: usize,
         dest_index: Option<usize>,
     ) -> PathSearchResult {
+        self.multi_source_search(&[start_index], dest_index)
+    }
+
+    /// Find the cheapest paths from `start_index`, stopping at the first node
+    /// reached for which `is_goal` is true, such as any node containing a key.
+    /// The node found is the result's destination.
+    fn shortest_path_search_until<G>(&self, start_index: usize, is_goal: G) -> PathSearchResult
+    where
+        G: FnMut(usize) -> bool,
+    {
+        self.multi_source_search_until(&[start_index], is_goal)
+    }
+
+    /// Find the cheapest path to each node from whichever of `start_indices`
+    /// is nearest to it, stopping early once `dest_index` is reached.
+    fn multi_source_search(
+        &self,
+        start_indices: &[usize],
+        dest_index: Option<usize>,
+    ) -> PathSearchResult {
+        self.multi_source_search_until(start_indices, |node| Some(node) == dest_index)
+    }
+
+    /// Find the cheapest path to each node from whichever of `start_indices`
+    /// is nearest to it, stopping at the first node for which `is_goal` is
+    /// true.
+    fn multi_source_search_until<G>(
+        &self,
+        start_indices: &[usize],
+        mut is_goal: G,
+    ) -> PathSearchResult
+    where
+        G: FnMut(usize) -> bool,
+    {
         let num_nodes = self.num_nodes();
 
         let mut previous_node = Vec::new();
@@ -15,38 +59,67 @@ pub trait Graph {
         let mut costs = Vec::new();
         costs.resize(num_nodes, None);
 
-        let mut open = Vec::new();
-        open.push((None, start_index, 0));
+        let mut open: BinaryHeap<_> = start_indices
+            .iter()
+            .map(|&start| Reverse((0, start, None)))
+            .collect();
 
         let mut num_found = 0;
+        let mut dest_index = None;
 
-        while let Some((prev, node, cost)) = open.pop() {
+        while let Some(Reverse((cost, node, prev))) = open.pop() {
+            // A node may be queued more than once, but only the cheapest counts.
+            if costs[node].is_some() {
+                continue;
+            }
             previous_node[node] = prev;
             costs[node] = Some(cost);
             num_found += 1;
 
-            // If we filled the whole graph or reached our destination, we're done.
-            if num_found == num_nodes || Some(node) == dest_index {
+            // If we reached a goal or filled the whole graph, we're done.
+            if is_goal(node) {
+                dest_index = Some(node);
+                break;
+            }
+            if num_found == num_nodes {
                 break;
             }
 
             for e in self.node_edges(node).into_iter() {
                 let next = e.dest_index;
                 if costs[next].is_none() {
-                    open.push((Some(node), next, cost + e.cost));
+                    open.push(Reverse((cost + e.cost, next, Some(node))));
                 }
             }
-            open.sort_by(|a, b| a.2.cmp(&b.2).reverse());
         }
 
         PathSearchResult {
-            start_index,
+            start_indices: start_indices.to_vec(),
             dest_index,
             previous_node,
             costs,
         }
     }
 
+    /// Run a separate [shortest_path_search](#method.shortest_path_search)
+    /// from each of `start_indices` in parallel, returning the results in the
+    /// same order.
+    fn shortest_path_searches(&self, start_indices: &[usize]) -> Vec<PathSearchResult> {
+        start_indices
+            .par_iter()
+            .map(|&start| self.shortest_path_search(start, None))
+            .collect()
+    }
+
+    /// The cost of the cheapest path for each `(start, dest)` pair, finding
+    /// them in parallel. Each is None if the destination can't be reached.
+    fn shortest_path_costs(&self, queries: &[(usize, usize)]) -> Vec<Option<usize>> {
+        queries
+            .par_iter()
+            .map(|&(start, dest)| self.shortest_path_search(start, Some(dest)).cost_to(dest))
+            .collect()
+    }
+
     fn find_shortest_path_indices(
         &self,
         start_index: usize,
@@ -56,9 +129,1350 @@ pub trait Graph {
             .make_path()
     }
 
+    /// The shortest path between two nodes found by
+    /// [astar_search](fn.astar_search.html), where `heuristic` estimates the
+    /// remaining cost from a node to `dest_index` without overestimating it.
+    fn astar<H>(&self, start_index: usize, dest_index: usize, heuristic: H) -> Option<Vec<usize>>
+    where
+        H: Fn(usize) -> usize,
+    {
+        let edges = |&node: &usize| {
+            self.node_edges(node)
+                .into_iter()
+                .map(|e| (e.dest_index, e.cost))
+        };
+        astar_search(start_index, edges, |&n| n == dest_index, |&n| heuristic(n))
+            .map(|(_, path)| path)
+    }
+
+    /// The cost of the cheapest path between every pair of nodes, indexed as
+    /// `[from][to]`, or None where there is no path. Uses the Floyd–Warshall
+    /// algorithm, so takes time cubic in the number of nodes.
+    fn all_pairs_distances(&self) -> Vec<Vec<Option<usize>>> {
+        let num_nodes = self.num_nodes();
+        let mut distances = vec![vec![None; num_nodes]; num_nodes];
+        for (from, row) in distances.iter_mut().enumerate() {
+            row[from] = Some(0);
+            for e in self.node_edges(from) {
+                let d = &mut row[e.dest_index];
+                *d = Some(d.map_or(e.cost, |d| d.min(e.cost)));
+            }
+        }
+
+        for via in 0..num_nodes {
+            let from_via = distances[via].clone();
+            for row in distances.iter_mut() {
+                let to_via = match row[via] {
+                    Some(d) => d,
+                    None => continue,
+                };
+                for (d, from_via) in row.iter_mut().zip(from_via.iter()) {
+                    if let Some(from_via) = from_via {
+                        let through = to_via + from_via;
+                        if d.is_none_or(|d| through < d) {
+                            *d = Some(through);
+                        }
+                    }
+                }
+            }
+        }
+        distances
+    }
+
+    /// Partition the nodes into strongly connected components, in which every
+    /// node can reach every other, using Tarjan's algorithm. Components are
+    /// returned in reverse topological order, so no component has an edge to
+    /// one after it.
+    fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        let num_nodes = self.num_nodes();
+        let mut indices = vec![None; num_nodes];
+        let mut lowlinks = vec![0; num_nodes];
+        let mut on_stack = vec![false; num_nodes];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut next_index = 0;
+
+        for root in 0..num_nodes {
+            if indices[root].is_some() {
+                continue;
+            }
+
+            // Each entry is a node being visited, its edges, and the position
+            // of the next edge to follow.
+            let mut visiting = Vec::new();
+            let mut start_visit = Some(root);
+            loop {
+                if let Some(node) = start_visit.take() {
+                    indices[node] = Some(next_index);
+                    lowlinks[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                    visiting.push((node, self.node_edges(node), 0));
+                }
+
+                let (node, next) = match visiting.last_mut() {
+                    Some((node, edges, pos)) => {
+                        *pos += 1;
+                        (*node, edges.get(*pos - 1).map(|e| e.dest_index))
+                    }
+                    None => break,
+                };
+
+                if let Some(next) = next {
+                    match indices[next] {
+                        None => start_visit = Some(next),
+                        Some(index) if on_stack[next] => lowlinks[node] = lowlinks[node].min(index),
+                        Some(_) => (),
+                    }
+                    continue;
+                }
+
+                // Finished with this node.
+                visiting.pop();
+                if let Some(&(parent, _, _)) = visiting.last() {
+                    lowlinks[parent] = lowlinks[parent].min(lowlinks[node]);
+                }
+                if Some(lowlinks[node]) == indices[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    /// Whether any path leads from a node back to itself.
+    fn has_cycle(&self) -> bool {
+        let has_self_edge =
+            (0..self.num_nodes()).any(|n| self.node_edges(n).iter().any(|e| e.dest_index == n));
+        has_self_edge
+            || self
+                .strongly_connected_components()
+                .iter()
+                .any(|c| c.len() > 1)
+    }
+
+    /// Partition the nodes into connected components, ignoring the direction
+    /// of edges. Each component lists its nodes in ascending order, and the
+    /// components are ordered by their lowest node.
+    fn components(&self) -> Vec<Vec<usize>> {
+        let mut sets = DisjointSet::new(self.num_nodes());
+        for node in 0..self.num_nodes() {
+            for edge in self.node_edges(node) {
+                sets.union(node, edge.dest_index);
+            }
+        }
+        sets.sets()
+    }
+
+    /// Whether following edges from node `a` can lead to node `b`.
+    fn is_reachable(&self, a: usize, b: usize) -> bool {
+        let mut seen = vec![false; self.num_nodes()];
+        let mut to_visit = vec![a];
+        seen[a] = true;
+        while let Some(node) = to_visit.pop() {
+            if node == b {
+                return true;
+            }
+            for edge in self.node_edges(node) {
+                if !seen[edge.dest_index] {
+                    seen[edge.dest_index] = true;
+                    to_visit.push(edge.dest_index);
+                }
+            }
+        }
+        false
+    }
+
+    /// Order the nodes so that every edge leads from an earlier node to a
+    /// later one, or None if the graph has a cycle.
+    fn topological_order(&self) -> Option<Vec<usize>> {
+        let num_nodes = self.num_nodes();
+        let mut num_incoming = vec![0; num_nodes];
+        for node in 0..num_nodes {
+            for e in self.node_edges(node) {
+                num_incoming[e.dest_index] += 1;
+            }
+        }
+
+        let mut ready: Vec<usize> = (0..num_nodes).filter(|&n| num_incoming[n] == 0).collect();
+        ready.reverse();
+        let mut order = Vec::with_capacity(num_nodes);
+        while let Some(node) = ready.pop() {
+            order.push(node);
+            for e in self.node_edges(node) {
+                num_incoming[e.dest_index] -= 1;
+                if num_incoming[e.dest_index] == 0 {
+                    ready.push(e.dest_index);
+                }
+            }
+        }
+
+        if order.len() == num_nodes {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// The most expensive path between two nodes, with its total cost. Only
+    /// defined for acyclic graphs, so returns None if the graph has a cycle
+    /// or `dest_index` can't be reached.
+    fn longest_path(&self, start_index: usize, dest_index: usize) -> Option<(usize, Vec<usize>)> {
+        let order = self.topological_order()?;
+        let mut costs = vec![None; self.num_nodes()];
+        let mut previous_node = vec![None; self.num_nodes()];
+        costs[start_index] = Some(0);
+
+        // Every path to a node is complete by the time it is reached in
+        // topological order.
+        for node in order {
+            let cost = match costs[node] {
+                Some(cost) => cost,
+                None => continue,
+            };
+            for e in self.node_edges(node) {
+                let next_cost = cost + e.cost;
+                if costs[e.dest_index].is_none_or(|c| c < next_cost) {
+                    costs[e.dest_index] = Some(next_cost);
+                    previous_node[e.dest_index] = Some(node);
+                }
+            }
+        }
+
+        let cost = costs[dest_index]?;
+        let mut path = vec![dest_index];
+        while let Some(prev) = previous_node[*path.last().unwrap()] {
+            path.push(prev);
+        }
+        path.reverse();
+        Some((cost, path))
+    }
+
     fn farthest_distance_from(&self, start_index: usize) -> usize {
         self.shortest_path_search(start_index, None).highest_cost()
     }
+
+    /// Collapse corridors into single weighted edges, so that long passages
+    /// in a maze don't need to be searched one step at a time.
+    ///
+    /// A corridor node is one with edges to and from exactly two other nodes,
+    /// and for which `keep` is false. Every other node is kept, and is joined
+    /// to the kept nodes at the far end of each corridor leading from it by
+    /// an edge with the total cost of the corridor. Loops made only of
+    /// corridor nodes are dropped, as are corridors which lead back to the
+    /// node they started from, since they can never be part of a cheapest
+    /// path.
+    fn contract_corridors<K>(&self, mut keep: K) -> ContractedGraph
+    where
+        K: FnMut(usize) -> bool,
+    {
+        let num_nodes = self.num_nodes();
+        let out_edges: Vec<_> = (0..num_nodes).map(|n| self.node_edges(n)).collect();
+        let mut in_nodes = vec![Vec::new(); num_nodes];
+        for (node, edges) in out_edges.iter().enumerate() {
+            for e in edges {
+                in_nodes[e.dest_index].push(node);
+            }
+        }
+
+        let is_corridor: Vec<bool> = (0..num_nodes)
+            .map(|node| {
+                let mut outs: Vec<_> = out_edges[node].iter().map(|e| e.dest_index).collect();
+                let mut ins = in_nodes[node].clone();
+                outs.sort_unstable();
+                ins.sort_unstable();
+                let is_passage =
+                    outs.len() == 2 && outs[0] != outs[1] && !outs.contains(&node) && outs == ins;
+                is_passage && !keep(node)
+            })
+            .collect();
+
+        let original_indices: Vec<usize> = (0..num_nodes).filter(|&n| !is_corridor[n]).collect();
+        let mut new_indices = vec![None; num_nodes];
+        for (new_index, &node) in original_indices.iter().enumerate() {
+            new_indices[node] = Some(new_index);
+        }
+
+        let edges = original_indices
+            .iter()
+            .map(|&node| {
+                let mut edges: Vec<Edge> = Vec::new();
+                for first in &out_edges[node] {
+                    // Follow the corridor until it reaches a kept node.
+                    let (mut prev, mut current, mut cost) = (node, first.dest_index, first.cost);
+                    while is_corridor[current] && current != node {
+                        let next = out_edges[current]
+                            .iter()
+                            .find(|e| e.dest_index != prev)
+                            .unwrap();
+                        prev = current;
+                        current = next.dest_index;
+                        cost += next.cost;
+                    }
+                    if current == node {
+                        continue;
+                    }
+                    let dest_index = match new_indices[current] {
+                        Some(index) => index,
+                        None => continue,
+                    };
+                    match edges.iter_mut().find(|e| e.dest_index == dest_index) {
+                        Some(edge) => edge.cost = edge.cost.min(cost),
+                        None => edges.push(Edge { dest_index, cost }),
+                    }
+                }
+                edges
+            })
+            .collect();
+
+        ContractedGraph {
+            original_indices,
+            new_indices,
+            edges,
+        }
+    }
+}
+
+/// A cost which can be summed along a path and compared, as used by the
+/// search functions such as [dijkstra](fn.dijkstra.html).
+///
+/// Implemented for the unsigned integer types, and for pairs of weights which
+/// compare lexicographically, so a search with `(levels, steps)` costs
+/// minimises levels first and then steps. Signed types are deliberately not
+/// weights: the searches settle a node the first time it is reached, which
+/// is only correct when no step has a negative cost. The graphs with dense
+/// indices, such as [Graph](trait.Graph.html), always use `usize` costs.
+pub trait Weight: Copy + Ord {
+    fn zero() -> Self;
+    fn plus(self, other: Self) -> Self;
+}
+
+macro_rules! impl_weight_for_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Weight for $t {
+                fn zero() -> $t {
+                    0
+                }
+
+                fn plus(self, other: $t) -> $t {
+                    self + other
+                }
+            }
+        )*
+    };
+}
+
+impl_weight_for_unsigned!(u8, u16, u32, u64, usize);
+
+impl<A: Weight, B: Weight> Weight for (A, B) {
+    fn zero() -> (A, B) {
+        (A::zero(), B::zero())
+    }
+
+    fn plus(self, other: (A, B)) -> (A, B) {
+        (self.0.plus(other.0), self.1.plus(other.1))
+    }
+}
+
+/// A graph whose nodes are values of any hashable type, such as positions,
+/// rather than the dense indices required by [Graph](trait.Graph.html).
+pub trait NodeGraph {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes reachable in one step from `node`, with the cost of each step.
+    fn node_neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, usize)>;
+
+    /// The cheapest path between two nodes, including both ends.
+    fn shortest_path(&self, start: &Self::Node, dest: &Self::Node) -> Option<Vec<Self::Node>> {
+        self.astar_path(start, dest, |_| 0)
+    }
+
+    /// As [shortest_path](#method.shortest_path), guided by a heuristic which
+    /// never overestimates the remaining cost from a node to `dest`.
+    fn astar_path<H>(
+        &self,
+        start: &Self::Node,
+        dest: &Self::Node,
+        heuristic: H,
+    ) -> Option<Vec<Self::Node>>
+    where
+        H: Fn(&Self::Node) -> usize,
+    {
+        let edges = |node: &Self::Node| self.node_neighbours(node);
+        astar_search(start.clone(), edges, |n| n == dest, heuristic).map(|(_, path)| path)
+    }
+}
+
+/// Find the cheapest path from `start` to any node for which `is_goal` is
+/// true, returning its total cost and every node along it, including both
+/// ends.
+///
+/// Nodes can be any state, and `successors` gives the neighbours of a node
+/// with the cost of moving to each. `heuristic` must never overestimate the cost
+/// from a node to the nearest goal; returning 0 everywhere is equivalent to
+/// Dijkstra's algorithm.
+///
+/// ```
+/// use aoc::graph::astar_search;
+///
+/// // Reach 10 from 0 by steps of +1 costing 1 or +3 costing 2.
+/// let successors = |&n: &i32| vec![(n + 1, 1), (n + 3, 2)];
+/// let heuristic = |&n: &i32| ((10 - n).max(0) / 3) as usize;
+/// let (cost, path) = astar_search(0, successors, |&n| n == 10, heuristic).unwrap();
+/// assert_eq!(cost, 7);
+/// assert_eq!(path.len(), 5);
+/// ```
+pub fn astar_search<N, W, E, I, G, H>(
+    start: N,
+    successors: E,
+    is_goal: G,
+    heuristic: H,
+) -> Option<(W, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    W: Weight,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> W,
+{
+    astar_search_observed(start, successors, is_goal, heuristic, &mut ())
+}
+
+/// Receives the progress of a search, such as to animate its frontier. Every
+/// method does nothing by default, and `()` can be used to observe nothing.
+pub trait SearchObserver<N, W> {
+    /// A node has been added to the frontier with the cost of reaching it. A
+    /// node may be queued again if a cheaper path to it is found.
+    fn queued(&mut self, _node: &N, _cost: W) {}
+
+    /// The cheapest path to a node has been found and it is about to be
+    /// expanded.
+    fn settled(&mut self, _node: &N, _cost: W) {}
+}
+
+impl<N, W> SearchObserver<N, W> for () {}
+
+/// [astar_search](fn.astar_search.html), reporting each node queued and
+/// settled to `observer`.
+///
+/// ```
+/// use aoc::graph::{astar_search_observed, SearchObserver};
+///
+/// struct Order(Vec<i32>);
+///
+/// impl SearchObserver<i32, usize> for Order {
+///     fn settled(&mut self, &node: &i32, _cost: usize) {
+///         self.0.push(node);
+///     }
+/// }
+///
+/// let mut order = Order(Vec::new());
+/// let successors = |&n: &i32| vec![(n + 1, 1)];
+/// astar_search_observed(0, successors, |&n| n == 3, |_| 0, &mut order);
+/// assert_eq!(order.0, [0, 1, 2, 3]);
+/// ```
+pub fn astar_search_observed<N, W, E, I, G, H, O>(
+    start: N,
+    mut successors: E,
+    mut is_goal: G,
+    mut heuristic: H,
+    observer: &mut O,
+) -> Option<(W, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    W: Weight,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> W,
+    O: SearchObserver<N, W>,
+{
+    // Every node queued along with the index of the node it was reached from,
+    // so that heap entries can refer to nodes by index.
+    let mut visited: Vec<(N, Option<usize>)> = vec![(start.clone(), None)];
+    let mut best_costs = HashMap::new();
+    best_costs.insert(start.clone(), W::zero());
+
+    let mut open = BinaryHeap::new();
+    open.push(Reverse((heuristic(&start), W::zero(), 0)));
+    observer.queued(&start, W::zero());
+
+    while let Some(Reverse((_, cost, index))) = open.pop() {
+        let node = visited[index].0.clone();
+        if best_costs[&node] < cost {
+            continue;
+        }
+        observer.settled(&node, cost);
+
+        if is_goal(&node) {
+            let mut path = vec![node];
+            let mut previous = visited[index].1;
+            while let Some(i) = previous {
+                path.push(visited[i].0.clone());
+                previous = visited[i].1;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, edge_cost) in successors(&node) {
+            let next_cost = cost.plus(edge_cost);
+            if best_costs.get(&next).is_some_and(|&c| c <= next_cost) {
+                continue;
+            }
+            best_costs.insert(next.clone(), next_cost);
+            observer.queued(&next, next_cost);
+            open.push(Reverse((
+                next_cost.plus(heuristic(&next)),
+                next_cost,
+                visited.len(),
+            )));
+            visited.push((next, Some(index)));
+        }
+    }
+
+    None
+}
+
+/// Find the cheapest path from `start` to any node for which `is_goal` is
+/// true, where `successors` gives the neighbours of a node with the cost of
+/// moving to each. Returns the total cost and every node along the path.
+///
+/// ```
+/// use aoc::graph::dijkstra;
+///
+/// // Halving an even number costs 1, and subtracting one costs 3.
+/// let successors = |&n: &u32| {
+///     let mut next = vec![(n - 1, 3u32)];
+///     if n % 2 == 0 {
+///         next.push((n / 2, 1));
+///     }
+///     next
+/// };
+/// let (cost, path) = dijkstra(10, successors, |&n| n == 1).unwrap();
+/// assert_eq!(cost, 6);
+/// assert_eq!(path, [10, 5, 4, 2, 1]);
+/// ```
+pub fn dijkstra<N, W, E, I, G>(start: N, successors: E, is_goal: G) -> Option<(W, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    W: Weight,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+{
+    dijkstra_observed(start, successors, is_goal, &mut ())
+}
+
+/// [dijkstra](fn.dijkstra.html), reporting each node queued and settled to
+/// `observer`.
+pub fn dijkstra_observed<N, W, E, I, G, O>(
+    start: N,
+    successors: E,
+    is_goal: G,
+    observer: &mut O,
+) -> Option<(W, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    W: Weight,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+    O: SearchObserver<N, W>,
+{
+    astar_search_observed(start, successors, is_goal, |_| W::zero(), observer)
+}
+
+/// Find the cheapest path through states made of a base node, such as a
+/// position, and some extra state, such as the keys collected so far.
+///
+/// `edges` gives the base nodes reachable from a base node, each with a cost
+/// and a label describing the edge. `transition` decides whether an edge can
+/// be followed given the current extra state, returning the extra state
+/// after following it, or None if the edge is blocked. Returns the total cost
+/// and every state along the path.
+///
+/// ```
+/// use aoc::graph::augmented_search;
+///
+/// // A corridor 0..=4 with a door at 3, and the key to it at 1.
+/// let edges = |&pos: &i32| {
+///     vec![(pos - 1, 1u32, pos - 1), (pos + 1, 1, pos + 1)]
+///         .into_iter()
+///         .filter(|&(p, _, _)| (0..=4).contains(&p))
+///         .collect::<Vec<_>>()
+/// };
+/// let transition = |&has_key: &bool, to: i32| match to {
+///     1 => Some(true),
+///     3 if !has_key => None,
+///     _ => Some(has_key),
+/// };
+/// let is_goal = |&pos: &i32, _: &bool| pos == 4;
+///
+/// let (cost, path) = augmented_search(2, false, edges, transition, is_goal).unwrap();
+/// assert_eq!(cost, 4);
+/// assert_eq!(path, [(2, false), (1, true), (2, true), (3, true), (4, true)]);
+/// ```
+pub fn augmented_search<B, S, W, L, E, I, T, G>(
+    start: B,
+    start_state: S,
+    edges: E,
+    transition: T,
+    is_goal: G,
+) -> Option<(W, Vec<(B, S)>)>
+where
+    B: Clone + Eq + Hash,
+    S: Clone + Eq + Hash,
+    W: Weight,
+    E: FnMut(&B) -> I,
+    I: IntoIterator<Item = (B, W, L)>,
+    T: FnMut(&S, L) -> Option<S>,
+    G: FnMut(&B, &S) -> bool,
+{
+    let heuristic = |_: &B, _: &S| W::zero();
+    augmented_astar_search(start, start_state, edges, transition, is_goal, heuristic)
+}
+
+/// As [augmented_search](fn.augmented_search.html), guided by a heuristic
+/// which never overestimates the cost from a base node and extra state to
+/// the nearest goal, as for [astar_search](fn.astar_search.html).
+pub fn augmented_astar_search<B, S, W, L, E, I, T, G, H>(
+    start: B,
+    start_state: S,
+    mut edges: E,
+    mut transition: T,
+    mut is_goal: G,
+    mut heuristic: H,
+) -> Option<(W, Vec<(B, S)>)>
+where
+    B: Clone + Eq + Hash,
+    S: Clone + Eq + Hash,
+    W: Weight,
+    E: FnMut(&B) -> I,
+    I: IntoIterator<Item = (B, W, L)>,
+    T: FnMut(&S, L) -> Option<S>,
+    G: FnMut(&B, &S) -> bool,
+    H: FnMut(&B, &S) -> W,
+{
+    let successors = |(node, state): &(B, S)| {
+        edges(node)
+            .into_iter()
+            .filter_map(|(next, cost, label)| {
+                transition(state, label).map(|next_state| ((next, next_state), cost))
+            })
+            .collect::<Vec<_>>()
+    };
+    astar_search(
+        (start, start_state),
+        successors,
+        |(node, state)| is_goal(node, state),
+        |(node, state)| heuristic(node, state),
+    )
+}
+
+/// Find the path with the fewest steps from `start` to any node for which
+/// `is_goal` is true, where `successors` gives the neighbours of a node.
+/// Returns every node along the path, including both ends.
+///
+/// ```
+/// use aoc::graph::bfs;
+///
+/// let successors = |&n: &i32| vec![n + 1, n * 2];
+/// let path = bfs(1, successors, |&n| n == 10).unwrap();
+/// assert_eq!(path, [1, 2, 4, 5, 10]);
+/// ```
+pub fn bfs<N, E, I, G>(start: N, mut successors: E, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    // Each node seen maps to the node it was first reached from.
+    let mut previous = HashMap::new();
+    previous.insert(start.clone(), None);
+
+    let mut open = VecDeque::new();
+    open.push_back(start);
+
+    while let Some(node) = open.pop_front() {
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(prev) = previous[path.last().unwrap()].clone() {
+                path.push(prev);
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for next in successors(&node) {
+            if !previous.contains_key(&next) {
+                previous.insert(next.clone(), Some(node.clone()));
+                open.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Find the path with the fewest steps from `start` to any node for which
+/// `is_goal` is true, and no more than `max_depth` steps long, by repeated
+/// depth-first searches of increasing depth.
+///
+/// Unlike [bfs](fn.bfs.html) only the current path is kept in memory, at the
+/// cost of revisiting nodes, so this suits state spaces too large to keep a
+/// full set of the nodes seen.
+///
+/// ```
+/// use aoc::graph::iddfs;
+///
+/// let successors = |&n: &i32| vec![n + 1, n * 2];
+/// assert_eq!(iddfs(1, successors, |&n| n == 10, 10).unwrap(), [1, 2, 4, 5, 10]);
+/// assert_eq!(iddfs(1, successors, |&n| n == 10, 3), None);
+/// ```
+pub fn iddfs<N, E, I, G>(
+    start: N,
+    mut successors: E,
+    mut is_goal: G,
+    max_depth: usize,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut successors = |n: &N| successors(n).into_iter().map(|next| (next, 1)).collect();
+    for depth in 0..=max_depth {
+        let search = bounded_search(
+            &start,
+            &mut successors,
+            &mut is_goal,
+            &mut |_| 0,
+            &mut |_, _| false,
+            depth,
+            false,
+        );
+        match search {
+            BoundedSearch::Found(_, path) => return Some(path),
+            BoundedSearch::Exceeded(Some(_)) => (),
+            BoundedSearch::Exceeded(None) => return None,
+        }
+    }
+    None
+}
+
+/// Find the cheapest path from `start` to any node for which `is_goal` is
+/// true using IDA*, which takes the same arguments as
+/// [astar_search](fn.astar_search.html) but only keeps the current path in
+/// memory, repeating depth-first searches with an increasing limit on the
+/// estimated total cost.
+///
+/// Nodes reachable by many different paths are searched again for each of
+/// them, which [ida_star_with_table](fn.ida_star_with_table.html) avoids.
+/// Doesn't terminate if no goal is reachable and there are infinitely many
+/// paths from the start.
+///
+/// ```
+/// use aoc::graph::ida_star;
+///
+/// let successors = |&n: &i32| vec![(n + 1, 1), (n + 3, 2)];
+/// let heuristic = |&n: &i32| ((10 - n).max(0) / 3) as usize;
+/// let (cost, path) = ida_star(0, successors, |&n| n == 10, heuristic).unwrap();
+/// assert_eq!(cost, 7);
+/// assert_eq!(path.len(), 5);
+/// ```
+pub fn ida_star<N, W, E, I, G, H>(
+    start: N,
+    mut successors: E,
+    mut is_goal: G,
+    mut heuristic: H,
+) -> Option<(W, Vec<N>)>
+where
+    N: Clone + Eq,
+    W: Weight,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> W,
+{
+    let mut successors = |n: &N| successors(n).into_iter().collect();
+    let mut bound = heuristic(&start);
+    loop {
+        let search = bounded_search(
+            &start,
+            &mut successors,
+            &mut is_goal,
+            &mut heuristic,
+            &mut |_, _| false,
+            bound,
+            false,
+        );
+        match search {
+            BoundedSearch::Found(cost, path) => return Some((cost, path)),
+            BoundedSearch::Exceeded(next_bound) => bound = next_bound?,
+        }
+    }
+}
+
+/// As [ida_star](fn.ida_star.html), but remembering the cheapest cost each
+/// node has been reached with during the current depth-first search in a
+/// table of `table_size` entries, so that a node reached again by a path
+/// which is no cheaper isn't searched again.
+///
+/// Nodes which share an entry replace each other, so memory use stays fixed
+/// however many nodes there are, while most repeated searches are avoided.
+///
+/// ```
+/// use aoc::graph::ida_star_with_table;
+///
+/// // Every path through the grid is searched only once per cell.
+/// let successors = |&(x, y): &(u32, u32)| vec![((x + 1, y), 1), ((x, y + 1), 1)];
+/// let heuristic = |&(x, y): &(u32, u32)| (40 - x.min(40) + 40 - y.min(40)) as usize;
+/// let is_goal = |&pos: &(u32, u32)| pos == (40, 40);
+/// let (cost, _) = ida_star_with_table((0, 0), successors, is_goal, heuristic, 256).unwrap();
+/// assert_eq!(cost, 80);
+/// ```
+pub fn ida_star_with_table<N, W, E, I, G, H>(
+    start: N,
+    mut successors: E,
+    mut is_goal: G,
+    mut heuristic: H,
+    table_size: usize,
+) -> Option<(W, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    W: Weight,
+    E: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, W)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> W,
+{
+    assert!(table_size > 0, "The table must have at least one entry");
+    let mut successors = |n: &N| successors(n).into_iter().collect();
+
+    // Each entry is the search it was made in, a node and its cheapest cost,
+    // so that the table doesn't need clearing between searches.
+    let mut table: Vec<Option<(usize, N, W)>> = vec![None; table_size];
+    let hasher = RandomState::new();
+    let mut bound = heuristic(&start);
+    let mut search_number = 0;
+    loop {
+        search_number += 1;
+        let mut seen_cheaper = |node: &N, cost: W| {
+            let entry = &mut table[(hasher.hash_one(node) % table_size as u64) as usize];
+            match entry {
+                Some((n, seen, seen_cost)) if *n == search_number && seen == node => {
+                    if *seen_cost <= cost {
+                        return true;
+                    }
+                    *seen_cost = cost;
+                }
+                _ => *entry = Some((search_number, node.clone(), cost)),
+            }
+            false
+        };
+        let search = bounded_search(
+            &start,
+            &mut successors,
+            &mut is_goal,
+            &mut heuristic,
+            &mut seen_cheaper,
+            bound,
+            true,
+        );
+        match search {
+            BoundedSearch::Found(cost, path) => return Some((cost, path)),
+            BoundedSearch::Exceeded(next_bound) => bound = next_bound?.max(bound.plus(bound)),
+        }
+    }
+}
+
+enum BoundedSearch<N, W> {
+    Found(W, Vec<N>),
+    /// No goal was found within the bound. Holds the lowest estimated cost
+    /// beyond the bound, or None if nothing was cut off.
+    Exceeded(Option<W>),
+}
+
+/// Depth-first search for a goal, following simple paths whose estimated
+/// total cost is within `bound`, trying the successors with the lowest
+/// estimates first. Nodes for which `skip` returns true, given the cost of
+/// reaching them, are not searched any further.
+///
+/// If `find_cheapest` is false this stops at the first goal found, otherwise
+/// it carries on looking for cheaper ones and returns the cheapest within the
+/// bound.
+fn bounded_search<N, W, E, G, H, S>(
+    start: &N,
+    successors: &mut E,
+    is_goal: &mut G,
+    heuristic: &mut H,
+    skip: &mut S,
+    bound: W,
+    find_cheapest: bool,
+) -> BoundedSearch<N, W>
+where
+    N: Clone + Eq,
+    W: Weight,
+    E: FnMut(&N) -> Vec<(N, W)>,
+    G: FnMut(&N) -> bool,
+    H: FnMut(&N) -> W,
+    S: FnMut(&N, W) -> bool,
+{
+    if is_goal(start) {
+        return BoundedSearch::Found(W::zero(), vec![start.clone()]);
+    }
+
+    let mut expand = |node: &N, cost: W| {
+        let mut options: Vec<_> = successors(node)
+            .into_iter()
+            .map(|(next, edge_cost)| {
+                let next_cost = cost.plus(edge_cost);
+                let estimate = next_cost.plus(heuristic(&next));
+                (next, next_cost, estimate)
+            })
+            .collect();
+        options.sort_by_key(|&(_, _, estimate)| estimate);
+        options.into_iter()
+    };
+
+    let mut path = vec![start.clone()];
+    let mut unexplored = vec![expand(start, W::zero())];
+    let mut next_bound: Option<W> = None;
+    let mut cheapest: Option<(W, Vec<N>)> = None;
+
+    while let Some(options) = unexplored.last_mut() {
+        let (next, cost, estimate) = match options.next() {
+            Some(option) => option,
+            None => {
+                unexplored.pop();
+                path.pop();
+                continue;
+            }
+        };
+        if path.contains(&next) {
+            continue;
+        }
+
+        if estimate > bound {
+            next_bound = Some(next_bound.map_or(estimate, |b| b.min(estimate)));
+            continue;
+        }
+        if cheapest.as_ref().is_some_and(|&(c, _)| estimate >= c) {
+            continue;
+        }
+
+        if is_goal(&next) {
+            let mut goal_path = path.clone();
+            goal_path.push(next);
+            if !find_cheapest {
+                return BoundedSearch::Found(cost, goal_path);
+            }
+            cheapest = Some((cost, goal_path));
+            continue;
+        }
+        if skip(&next, cost) {
+            continue;
+        }
+        unexplored.push(expand(&next, cost));
+        path.push(next);
+    }
+
+    match cheapest {
+        Some((cost, path)) => BoundedSearch::Found(cost, path),
+        None => BoundedSearch::Exceeded(next_bound),
+    }
+}
+
+/// Find the cheapest path from `start` to `goal` by searching forwards from
+/// the start and backwards from the goal at the same time, which usually
+/// explores far fewer nodes than [dijkstra](fn.dijkstra.html) on large graphs.
+///
+/// `successors` gives the nodes reachable from a node with the cost of each
+/// step, and `predecessors` the nodes which can reach it. For an undirected
+/// graph these are the same. Returns the total cost and every node along the
+/// path, including both ends.
+///
+/// ```
+/// use aoc::graph::bidirectional_dijkstra;
+///
+/// let neighbours = |&n: &i32| vec![(n - 1, 1u32), (n + 1, 1)];
+/// let (cost, path) = bidirectional_dijkstra(-3, 4, neighbours, neighbours).unwrap();
+/// assert_eq!(cost, 7);
+/// assert_eq!(path, (-3..=4).collect::<Vec<_>>());
+/// ```
+pub fn bidirectional_dijkstra<N, W, S, SI, P, PI>(
+    start: N,
+    goal: N,
+    mut successors: S,
+    mut predecessors: P,
+) -> Option<(W, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    W: Weight,
+    S: FnMut(&N) -> SI,
+    SI: IntoIterator<Item = (N, W)>,
+    P: FnMut(&N) -> PI,
+    PI: IntoIterator<Item = (N, W)>,
+{
+    let mut forward = HalfSearch::<N, W>::new(start);
+    let mut backward = HalfSearch::new(goal.clone());
+
+    // The cheapest complete path found so far, and the node where the two
+    // searches met on it.
+    let mut best: Option<(W, N)> = None;
+    if forward.costs.contains_key(&goal) {
+        best = Some((W::zero(), goal));
+    }
+
+    while let (Some(f), Some(b)) = (forward.peek_cost(), backward.peek_cost()) {
+        // Every path not yet found costs at least this much.
+        if best.as_ref().is_some_and(|&(cost, _)| f.plus(b) >= cost) {
+            break;
+        }
+
+        let (search, other, edges) = if f <= b {
+            let next = forward.pop().map(|(cost, node)| {
+                let edges = successors(&node).into_iter().collect::<Vec<_>>();
+                (cost, node, edges)
+            });
+            (&mut forward, &backward, next)
+        } else {
+            let next = backward.pop().map(|(cost, node)| {
+                let edges = predecessors(&node).into_iter().collect::<Vec<_>>();
+                (cost, node, edges)
+            });
+            (&mut backward, &forward, next)
+        };
+
+        if let Some((cost, node, edges)) = edges {
+            for (next, edge_cost) in edges {
+                let next_cost = cost.plus(edge_cost);
+                if !search.relax(&node, &next, next_cost) {
+                    continue;
+                }
+                if let Some(&other_cost) = other.costs.get(&next) {
+                    let total = next_cost.plus(other_cost);
+                    if best.as_ref().is_none_or(|&(cost, _)| total < cost) {
+                        best = Some((total, next));
+                    }
+                }
+            }
+        }
+    }
+
+    let (cost, meeting) = best?;
+    let mut path = forward.path_to(&meeting);
+    path.reverse();
+    path.extend(backward.path_to(&meeting).into_iter().skip(1));
+    Some((cost, path))
+}
+
+/// One direction of a [bidirectional_dijkstra](fn.bidirectional_dijkstra.html).
+struct HalfSearch<N, W> {
+    costs: HashMap<N, W>,
+    previous: HashMap<N, N>,
+    nodes: Vec<N>,
+    open: BinaryHeap<Reverse<(W, usize)>>,
+}
+
+impl<N: Clone + Eq + Hash, W: Weight> HalfSearch<N, W> {
+    fn new(start: N) -> HalfSearch<N, W> {
+        let mut costs = HashMap::new();
+        costs.insert(start.clone(), W::zero());
+        let mut open = BinaryHeap::new();
+        open.push(Reverse((W::zero(), 0)));
+        HalfSearch {
+            costs,
+            previous: HashMap::new(),
+            nodes: vec![start],
+            open,
+        }
+    }
+
+    fn peek_cost(&self) -> Option<W> {
+        self.open.peek().map(|Reverse((cost, _))| *cost)
+    }
+
+    /// The cheapest node not yet expanded, skipping any stale entries.
+    fn pop(&mut self) -> Option<(W, N)> {
+        let Reverse((cost, index)) = self.open.pop()?;
+        let node = self.nodes[index].clone();
+        if self.costs[&node] < cost {
+            None
+        } else {
+            Some((cost, node))
+        }
+    }
+
+    /// Record reaching `next` from `node` at the given cost, returning
+    /// whether that was cheaper than any way found before.
+    fn relax(&mut self, node: &N, next: &N, cost: W) -> bool {
+        if self.costs.get(next).is_some_and(|&c| c <= cost) {
+            return false;
+        }
+        self.costs.insert(next.clone(), cost);
+        self.previous.insert(next.clone(), node.clone());
+        self.open.push(Reverse((cost, self.nodes.len())));
+        self.nodes.push(next.clone());
+        true
+    }
+
+    /// The nodes from `node` back to where this search started.
+    fn path_to(&self, node: &N) -> Vec<N> {
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.previous.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path
+    }
+}
+
+/// Describe a graph in the Graphviz DOT language, for visual inspection with
+/// a tool such as `dot -Tsvg`.
+///
+/// Each node is drawn with the text from `labels`, and edges are labelled
+/// with their costs if `weights` is true.
+///
+/// ```
+/// use aoc::graph::{to_dot, Edge, Graph};
+///
+/// struct Line;
+///
+/// impl Graph for Line {
+///     fn num_nodes(&self) -> usize {
+///         2
+///     }
+///
+///     fn node_edges(&self, node_index: usize) -> Vec<Edge> {
+///         match node_index {
+///             0 => vec![Edge { dest_index: 1, cost: 5 }],
+///             _ => vec![],
+///         }
+///     }
+/// }
+///
+/// let dot = to_dot(&Line, |n| ["start", "end"][n].to_string(), true);
+/// assert_eq!(
+///     dot,
+///     "digraph {\n    \
+///        0 [label=\"start\"];\n    \
+///        1 [label=\"end\"];\n    \
+///        0 -> 1 [label=\"5\"];\n\
+///      }\n"
+/// );
+/// ```
+pub fn to_dot<G, L>(graph: &G, labels: L, weights: bool) -> String
+where
+    G: Graph + ?Sized,
+    L: Fn(usize) -> String,
+{
+    let escape = |s: String| s.replace('\\', "\\\\").replace('"', "\\\"");
+
+    let mut dot = String::from("digraph {\n");
+    for node in 0..graph.num_nodes() {
+        dot += &format!("    {} [label=\"{}\"];\n", node, escape(labels(node)));
+    }
+    for node in 0..graph.num_nodes() {
+        for e in graph.node_edges(node) {
+            dot += &format!("    {} -> {}", node, e.dest_index);
+            if weights {
+                dot += &format!(" [label=\"{}\"]", e.cost);
+            }
+            dot += ";\n";
+        }
+    }
+    dot += "}\n";
+    dot
+}
+
+/// A directed network of edges with limited capacities, for finding the
+/// maximum flow from a source node to a sink node with Dinic's algorithm.
+///
+/// ```
+/// use aoc::graph::FlowNetwork;
+///
+/// let mut network = FlowNetwork::new(4);
+/// network.add_edge(0, 1, 3);
+/// network.add_edge(0, 2, 2);
+/// let middle = network.add_edge(1, 2, 5);
+/// network.add_edge(1, 3, 2);
+/// network.add_edge(2, 3, 3);
+/// assert_eq!(network.max_flow(0, 3), 5);
+/// assert_eq!(network.flow(middle), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FlowNetwork {
+    // Each edge is stored next to its reverse edge, so the reverse of edge
+    // `i` is always `i ^ 1`. The spare capacity of the reverse edge is the
+    // flow along the forward one.
+    edges: Vec<FlowEdge>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FlowEdge {
+    dest: usize,
+    residual: usize,
+}
+
+impl FlowNetwork {
+    pub fn new(num_nodes: usize) -> FlowNetwork {
+        FlowNetwork {
+            edges: Vec::new(),
+            adjacency: vec![Vec::new(); num_nodes],
+        }
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// Add an edge which can carry up to `capacity` units of flow, returning
+    /// an id which can be passed to [flow](#method.flow).
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: usize) -> usize {
+        let id = self.edges.len();
+        self.edges.push(FlowEdge {
+            dest: to,
+            residual: capacity,
+        });
+        self.edges.push(FlowEdge {
+            dest: from,
+            residual: 0,
+        });
+        self.adjacency[from].push(id);
+        self.adjacency[to].push(id + 1);
+        id
+    }
+
+    /// The flow along an edge after the last call to
+    /// [max_flow](#method.max_flow).
+    pub fn flow(&self, edge_id: usize) -> usize {
+        self.edges[edge_id ^ 1].residual
+    }
+
+    /// Push as much flow as possible from `source` to `sink`, returning the
+    /// total. Flow found by earlier calls is kept, so only the additional
+    /// flow is returned.
+    pub fn max_flow(&mut self, source: usize, sink: usize) -> usize {
+        assert_ne!(source, sink);
+        let mut total = 0;
+        while let Some(levels) = self.levels(source, sink) {
+            let mut next_edge = vec![0; self.num_nodes()];
+            loop {
+                let pushed = self.augment(source, sink, usize::MAX, &levels, &mut next_edge);
+                if pushed == 0 {
+                    break;
+                }
+                total += pushed;
+            }
+        }
+        total
+    }
+
+    /// Distance of each node from `source` along edges with spare capacity,
+    /// or None if `sink` can't be reached.
+    fn levels(&self, source: usize, sink: usize) -> Option<Vec<Option<usize>>> {
+        let mut levels = vec![None; self.num_nodes()];
+        levels[source] = Some(0);
+        let mut to_visit = VecDeque::from(vec![source]);
+        while let Some(node) = to_visit.pop_front() {
+            for &id in &self.adjacency[node] {
+                let edge = &self.edges[id];
+                if edge.residual > 0 && levels[edge.dest].is_none() {
+                    levels[edge.dest] = levels[node].map(|l| l + 1);
+                    to_visit.push_back(edge.dest);
+                }
+            }
+        }
+        levels[sink].map(|_| levels)
+    }
+
+    /// Push up to `limit` units of flow from `node` to `sink` along edges
+    /// which lead one level further from the source, returning the amount
+    /// pushed.
+    fn augment(
+        &mut self,
+        node: usize,
+        sink: usize,
+        limit: usize,
+        levels: &[Option<usize>],
+        next_edge: &mut [usize],
+    ) -> usize {
+        if node == sink {
+            return limit;
+        }
+        while next_edge[node] < self.adjacency[node].len() {
+            let id = self.adjacency[node][next_edge[node]];
+            let edge = self.edges[id];
+            let is_forward = levels[edge.dest] == levels[node].map(|l| l + 1);
+            if is_forward && edge.residual > 0 {
+                let limit = limit.min(edge.residual);
+                let pushed = self.augment(edge.dest, sink, limit, levels, next_edge);
+                if pushed > 0 {
+                    self.push_flow(id, pushed);
+                    return pushed;
+                }
+            }
+            next_edge[node] += 1;
+        }
+        0
+    }
+
+    fn push_flow(&mut self, id: usize, amount: usize) {
+        self.edges[id].residual -= amount;
+        self.edges[id ^ 1].residual += amount;
+    }
+}
+
+/// Find a largest set of pairs between `num_left` items on the left and
+/// `num_right` items on the right such that no item is in more than one pair,
+/// choosing only from the allowed `pairs` of `(left, right)` indices.
+///
+/// ```
+/// use aoc::graph::bipartite_matching;
+///
+/// let allowed = vec![(0, 0), (0, 1), (1, 0), (2, 1)];
+/// assert_eq!(bipartite_matching(3, 2, allowed).len(), 2);
+/// ```
+pub fn bipartite_matching<I>(num_left: usize, num_right: usize, pairs: I) -> Vec<(usize, usize)>
+where
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let source = num_left + num_right;
+    let sink = source + 1;
+    let mut network = FlowNetwork::new(num_left + num_right + 2);
+    for left in 0..num_left {
+        network.add_edge(source, left, 1);
+    }
+    for right in 0..num_right {
+        network.add_edge(num_left + right, sink, 1);
+    }
+    let pair_edges: Vec<_> = pairs
+        .into_iter()
+        .map(|(left, right)| ((left, right), network.add_edge(left, num_left + right, 1)))
+        .collect();
+
+    network.max_flow(source, sink);
+    pair_edges
+        .into_iter()
+        .filter(|&(_, id)| network.flow(id) > 0)
+        .map(|(pair, _)| pair)
+        .collect()
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -67,17 +1481,68 @@ pub struct Edge {
     pub cost: usize,
 }
 
+/// A graph produced by
+/// [Graph::contract_corridors](trait.Graph.html#method.contract_corridors),
+/// which remembers how its nodes relate to those of the original graph.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ContractedGraph {
+    original_indices: Vec<usize>,
+    new_indices: Vec<Option<usize>>,
+    edges: Vec<Vec<Edge>>,
+}
+
+impl ContractedGraph {
+    /// The index in the original graph of a node in this one.
+    pub fn original_index(&self, node_index: usize) -> usize {
+        self.original_indices[node_index]
+    }
+
+    /// The index in this graph of a node in the original graph, or None if it
+    /// was part of a corridor.
+    pub fn index_of(&self, original_index: usize) -> Option<usize> {
+        self.new_indices[original_index]
+    }
+}
+
+impl Graph for ContractedGraph {
+    fn num_nodes(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn node_edges(&self, node_index: usize) -> Vec<Edge> {
+        self.edges[node_index].clone()
+    }
+}
+
+/// The cheapest paths from one or more start nodes found by
+/// [Graph::shortest_path_search](trait.Graph.html#method.shortest_path_search)
+/// or [Graph::multi_source_search](trait.Graph.html#method.multi_source_search).
+/// If the search was given a destination, it may have stopped before reaching
+/// every node.
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct PathSearchResult {
-    pub start_index: usize,
-    pub dest_index: Option<usize>,
-    pub previous_node: Vec<Option<usize>>,
-    pub costs: Vec<Option<usize>>,
+    start_indices: Vec<usize>,
+    dest_index: Option<usize>,
+    previous_node: Vec<Option<usize>>,
+    costs: Vec<Option<usize>>,
 }
 
 impl PathSearchResult {
-    pub fn make_path(&self) -> Option<Vec<usize>> {
-        let mut index = self.dest_index?;
+    pub fn start_indices(&self) -> &[usize] {
+        &self.start_indices
+    }
+
+    /// The cost of the cheapest path to a node from the nearest start, or
+    /// None if it wasn't reached.
+    pub fn cost_to(&self, node_index: usize) -> Option<usize> {
+        self.costs[node_index]
+    }
+
+    /// Every node from the nearest start to `node_index` along the cheapest
+    /// path, or None if it wasn't reached.
+    pub fn path_to(&self, node_index: usize) -> Option<Vec<usize>> {
+        self.costs[node_index]?;
+        let mut index = node_index;
         let mut path = vec![index];
         while let Some(next) = self.previous_node[index] {
             path.push(next);
@@ -87,8 +1552,27 @@ impl PathSearchResult {
         Some(path)
     }
 
+    /// The destination the search stopped at, or None if it didn't reach one.
+    pub fn dest_index(&self) -> Option<usize> {
+        self.dest_index
+    }
+
+    /// The path to the destination the search stopped at, if any.
+    pub fn make_path(&self) -> Option<Vec<usize>> {
+        self.path_to(self.dest_index?)
+    }
+
+    /// Each node reached along with the cost of the cheapest path to it.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.costs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, cost)| cost.map(|c| (index, c)))
+    }
+
+    /// The cost of reaching the farthest node, ignoring any unreached nodes.
     pub fn highest_cost(&self) -> usize {
-        self.costs.iter().max().unwrap().unwrap()
+        self.iter().map(|(_, cost)| cost).max().unwrap_or(0)
     }
 }
 
@@ -170,13 +1654,14 @@ mod test {
         let graph = make_graph();
 
         let info = graph.shortest_path_search(0, None);
+        assert_eq!(info.start_indices(), [0]);
+        assert_eq!(info.path_to(0), Some(vec![0]));
+        assert_eq!(info.path_to(2), Some(vec![0, 3, 2]));
+        assert_eq!(info.path_to(4), Some(vec![0, 3, 4]));
+        assert_eq!(info.cost_to(1), Some(1));
         assert_eq!(
-            info.previous_node,
-            vec![None, Some(0), Some(3), Some(0), Some(3)]
-        );
-        assert_eq!(
-            info.costs,
-            vec![Some(0), Some(1), Some(2), Some(1), Some(2)]
+            info.iter().collect::<Vec<_>>(),
+            [(0, 0), (1, 1), (2, 2), (3, 1), (4, 2)]
         );
 
         let path = graph.find_shortest_path_indices(4, 1).unwrap();
@@ -185,4 +1670,577 @@ mod test {
         let dist = graph.farthest_distance_from(4);
         assert_eq!(dist, 2);
     }
+
+    #[test]
+    fn test_contract_corridors() {
+        /*
+            0--1--2--3--4
+                     |
+                     5--6
+        */
+        let graph = WeightedGraph(vec![
+            vec![(1, 1)],
+            vec![(0, 1), (2, 2)],
+            vec![(1, 2), (3, 3)],
+            vec![(2, 3), (4, 1), (5, 1)],
+            vec![(3, 1)],
+            vec![(3, 1), (6, 1)],
+            vec![(5, 1)],
+        ]);
+
+        let contracted = graph.contract_corridors(|_| false);
+        assert_eq!(contracted.num_nodes(), 4);
+        let original: Vec<_> = (0..4).map(|n| contracted.original_index(n)).collect();
+        assert_eq!(original, [0, 3, 4, 6]);
+        assert_eq!(contracted.index_of(2), None);
+        assert_eq!(
+            contracted.node_edges(1),
+            [
+                Edge {
+                    dest_index: 0,
+                    cost: 6
+                },
+                Edge {
+                    dest_index: 2,
+                    cost: 1
+                },
+                Edge {
+                    dest_index: 3,
+                    cost: 2
+                },
+            ]
+        );
+        assert_eq!(
+            contracted.shortest_path_search(0, None).cost_to(3),
+            graph.shortest_path_search(0, None).cost_to(6)
+        );
+
+        // Kept nodes split a corridor.
+        let contracted = graph.contract_corridors(|n| n == 2);
+        assert_eq!(contracted.num_nodes(), 5);
+        let two = contracted.index_of(2).unwrap();
+        assert_eq!(
+            contracted.node_edges(two)[0],
+            Edge {
+                dest_index: 0,
+                cost: 3
+            }
+        );
+
+        /*
+            0--1--2
+               |  |
+               4--3
+        */
+        let looped = WeightedGraph(vec![
+            vec![(1, 1)],
+            vec![(0, 1), (2, 1), (4, 1)],
+            vec![(1, 1), (3, 1)],
+            vec![(2, 1), (4, 1)],
+            vec![(3, 1), (1, 1)],
+        ]);
+        let contracted = looped.contract_corridors(|_| false);
+        assert_eq!(contracted.num_nodes(), 2);
+        let one = contracted.index_of(1).unwrap();
+        assert_eq!(
+            contracted.node_edges(one),
+            [Edge {
+                dest_index: contracted.index_of(0).unwrap(),
+                cost: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let dag = WeightedGraph(vec![
+            vec![(1, 1), (2, 4)],
+            vec![(2, 1), (3, 6)],
+            vec![(3, 2)],
+            vec![],
+            vec![(0, 1)],
+        ]);
+        assert_eq!(dag.topological_order(), Some(vec![4, 0, 1, 2, 3]));
+        assert_eq!(dag.longest_path(0, 3), Some((7, vec![0, 1, 3])));
+        assert_eq!(dag.longest_path(0, 2), Some((4, vec![0, 2])));
+        assert_eq!(dag.longest_path(3, 0), None);
+
+        assert_eq!(make_graph().topological_order(), None);
+        assert_eq!(make_graph().longest_path(0, 1), None);
+    }
+
+    #[test]
+    fn test_search_until() {
+        let graph = make_graph();
+        let info = graph.shortest_path_search_until(1, |n| n % 2 == 0);
+        assert_eq!(info.dest_index(), Some(0));
+        assert_eq!(info.make_path(), Some(vec![1, 0]));
+
+        let info = graph.shortest_path_search_until(1, |n| n > 3);
+        assert_eq!(info.dest_index(), Some(4));
+        assert_eq!(info.make_path(), Some(vec![1, 3, 4]));
+
+        let info = graph.multi_source_search_until(&[0, 1], |_| false);
+        assert_eq!(info.dest_index(), None);
+        assert_eq!(info.make_path(), None);
+        assert_eq!(info.highest_cost(), 2);
+    }
+
+    #[test]
+    fn test_search_observer() {
+        #[derive(Default)]
+        struct Recorder {
+            queued: Vec<(usize, usize)>,
+            settled: Vec<(usize, usize)>,
+        }
+
+        impl SearchObserver<usize, usize> for Recorder {
+            fn queued(&mut self, &node: &usize, cost: usize) {
+                self.queued.push((node, cost));
+            }
+
+            fn settled(&mut self, &node: &usize, cost: usize) {
+                self.settled.push((node, cost));
+            }
+        }
+
+        // Node 2 is first queued at cost 5, then again at cost 2 via node 1.
+        let graph = WeightedGraph(vec![vec![(1, 1), (2, 5)], vec![(2, 1)], vec![]]);
+        let edges = |&n: &usize| {
+            graph
+                .node_edges(n)
+                .into_iter()
+                .map(|e| (e.dest_index, e.cost))
+        };
+        let mut recorder = Recorder::default();
+        let result = dijkstra_observed(0, edges, |&n| n == 2, &mut recorder);
+        assert_eq!(result, Some((2, vec![0, 1, 2])));
+        assert_eq!(recorder.queued, [(0, 0), (1, 1), (2, 5), (2, 2)]);
+        assert_eq!(recorder.settled, [(0, 0), (1, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn test_parallel_searches() {
+        let graph = make_graph();
+        let searches = graph.shortest_path_searches(&[4, 1]);
+        assert_eq!(searches.len(), 2);
+        assert_eq!(searches[0], graph.shortest_path_search(4, None));
+        assert_eq!(searches[1], graph.shortest_path_search(1, None));
+
+        let costs = graph.shortest_path_costs(&[(0, 4), (2, 2), (1, 2)]);
+        assert_eq!(costs, [Some(2), Some(0), Some(2)]);
+    }
+
+    #[test]
+    fn test_multi_source_search() {
+        let graph = make_graph();
+        let info = graph.multi_source_search(&[1, 4], None);
+        assert_eq!(info.start_indices(), [1, 4]);
+        let costs: Vec<_> = (0..5).map(|n| info.cost_to(n)).collect();
+        assert_eq!(costs, [Some(1), Some(0), Some(1), Some(1), Some(0)]);
+        assert_eq!(info.path_to(2), Some(vec![4, 2]));
+        assert_eq!(info.highest_cost(), 1);
+
+        let info = graph.multi_source_search(&[1, 4], Some(0));
+        assert_eq!(info.make_path(), Some(vec![1, 0]));
+    }
+
+    #[test]
+    fn test_shortest_path_with_revisited_nodes() {
+        /*
+            0 -> 1 -> 3 -> 4
+             \-> 2 -/
+        */
+        let nodes = (0..5).collect();
+        let mut edges = HashMap::new();
+        edges.insert(0, vec![1, 2]);
+        edges.insert(1, vec![3]);
+        edges.insert(2, vec![3]);
+        edges.insert(3, vec![4]);
+        edges.insert(4, vec![]);
+        let graph = TestGraph { nodes, edges };
+
+        let path = graph.find_shortest_path_indices(0, 4).unwrap();
+        assert_eq!(path.len(), 4);
+        assert_eq!(path[3], 4);
+    }
+
+    struct WeightedGraph(Vec<Vec<(usize, usize)>>);
+
+    impl Graph for WeightedGraph {
+        fn num_nodes(&self) -> usize {
+            self.0.len()
+        }
+
+        fn node_edges(&self, node_index: usize) -> Vec<Edge> {
+            self.0[node_index]
+                .iter()
+                .map(|&(dest_index, cost)| Edge { dest_index, cost })
+                .collect()
+        }
+    }
+
+    // The direct edge 0 -> 3 is more expensive than going around through
+    // 1 and 2, and 4 can only be reached from 2.
+    fn weighted_graph() -> WeightedGraph {
+        WeightedGraph(vec![
+            vec![(1, 2), (3, 10)],
+            vec![(2, 2)],
+            vec![(3, 2), (4, 1)],
+            vec![],
+            vec![(3, 4)],
+        ])
+    }
+
+    #[test]
+    fn test_shortest_path_weighted() {
+        let graph = weighted_graph();
+
+        let info = graph.shortest_path_search(0, None);
+        let costs: Vec<_> = (0..5).map(|n| info.cost_to(n)).collect();
+        assert_eq!(costs, [Some(0), Some(2), Some(4), Some(6), Some(5)]);
+        assert_eq!(
+            graph.find_shortest_path_indices(0, 3),
+            Some(vec![0, 1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_all_pairs_distances() {
+        let graph = make_graph();
+        let distances = graph.all_pairs_distances();
+        for (from, row) in distances.iter().enumerate() {
+            let info = graph.shortest_path_search(from, None);
+            let costs: Vec<_> = (0..row.len()).map(|n| info.cost_to(n)).collect();
+            assert_eq!(row, &costs);
+        }
+
+        let weighted = weighted_graph();
+        let distances = weighted.all_pairs_distances();
+        assert_eq!(distances[0], [Some(0), Some(2), Some(4), Some(6), Some(5)]);
+        assert_eq!(distances[1], [None, Some(0), Some(2), Some(4), Some(3)]);
+        assert_eq!(distances[3], [None, None, None, Some(0), None]);
+        assert_eq!(distances[4][3], Some(4));
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        // 0 -> 1 -> 2 -> 0, 2 -> 3 <-> 4, 5 -> 4
+        let graph = WeightedGraph(vec![
+            vec![(1, 1)],
+            vec![(2, 1)],
+            vec![(0, 1), (3, 1)],
+            vec![(4, 1)],
+            vec![(3, 1)],
+            vec![(4, 1)],
+        ]);
+        let mut components = graph.strongly_connected_components();
+        assert_eq!(components.len(), 3);
+        // Reverse topological order means {3, 4} comes before both others.
+        assert!(components[0].contains(&3));
+
+        for c in components.iter_mut() {
+            c.sort();
+        }
+        components.sort();
+        assert_eq!(components, [vec![0, 1, 2], vec![3, 4], vec![5]]);
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn test_has_cycle() {
+        let dag = WeightedGraph(vec![vec![(1, 1), (2, 1)], vec![(2, 1)], vec![]]);
+        assert!(!dag.has_cycle());
+        assert_eq!(dag.strongly_connected_components().len(), 3);
+
+        let self_loop = WeightedGraph(vec![vec![(1, 1)], vec![(1, 1)]]);
+        assert!(self_loop.has_cycle());
+
+        // Undirected graphs always have cycles between neighbours.
+        assert!(make_graph().has_cycle());
+    }
+
+    #[test]
+    fn test_components_and_reachability() {
+        let graph = WeightedGraph(vec![
+            vec![(2, 1)],
+            vec![(3, 1)],
+            vec![],
+            vec![],
+            vec![(0, 1)],
+        ]);
+        assert_eq!(graph.components(), [vec![0, 2, 4], vec![1, 3]]);
+
+        assert!(graph.is_reachable(4, 2));
+        assert!(graph.is_reachable(1, 1));
+        assert!(!graph.is_reachable(2, 0));
+        assert!(!graph.is_reachable(0, 3));
+
+        assert_eq!(make_graph().components().len(), 1);
+    }
+
+    #[test]
+    fn test_max_flow() {
+        // The classic example from CLRS, with a maximum flow of 23.
+        let mut network = FlowNetwork::new(6);
+        let capacities = [
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 3, 12),
+            (2, 1, 4),
+            (2, 4, 14),
+            (3, 2, 9),
+            (3, 5, 20),
+            (4, 3, 7),
+            (4, 5, 4),
+        ];
+        let ids: Vec<_> = capacities
+            .iter()
+            .map(|&(from, to, capacity)| network.add_edge(from, to, capacity))
+            .collect();
+        assert_eq!(network.max_flow(0, 5), 23);
+
+        // Flow is conserved at every node other than the source and sink.
+        for node in 1..5 {
+            let flow_through = |is_in: bool| -> usize {
+                capacities
+                    .iter()
+                    .zip(&ids)
+                    .filter(|&(&(from, to, _), _)| if is_in { to == node } else { from == node })
+                    .map(|(_, &id)| network.flow(id))
+                    .sum()
+            };
+            assert_eq!(flow_through(true), flow_through(false));
+        }
+
+        // There's nothing left to push on a second run.
+        assert_eq!(network.max_flow(0, 5), 0);
+    }
+
+    #[test]
+    fn test_bipartite_matching() {
+        // Only a perfect matching if 0 takes 1, so 1 and 2 can share 0 and 2.
+        let pairs = vec![(0, 0), (0, 1), (1, 0), (2, 0), (2, 2)];
+        let mut matching = bipartite_matching(3, 3, pairs);
+        matching.sort_unstable();
+        assert_eq!(matching, [(0, 1), (1, 0), (2, 2)]);
+
+        assert!(bipartite_matching(2, 2, vec![]).is_empty());
+    }
+
+    #[test]
+    fn test_iddfs_and_ida_star() {
+        let graph = make_graph();
+        let successors = |&n: &usize| graph.node_edges(n).into_iter().map(|e| e.dest_index);
+        assert_eq!(iddfs(1, successors, |&n| n == 2, 10).unwrap(), [1, 3, 2]);
+        assert_eq!(iddfs(1, successors, |&n| n == 2, 1), None);
+        assert_eq!(iddfs(0, successors, |&n| n == 99, 100), None);
+
+        let weighted = WeightedGraph(vec![
+            vec![(1, 1), (2, 5)],
+            vec![(2, 1), (3, 7)],
+            vec![(3, 1)],
+            vec![],
+        ]);
+        let successors = |&n: &usize| {
+            weighted
+                .node_edges(n)
+                .into_iter()
+                .map(|e| (e.dest_index, e.cost))
+        };
+        let (cost, path) = ida_star(0, successors, |&n| n == 3, |&n| 3 - n).unwrap();
+        assert_eq!(cost, 3);
+        assert_eq!(path, [0, 1, 2, 3]);
+        assert_eq!(ida_star(3, successors, |&n| n == 0, |_| 0), None);
+
+        for &table_size in &[1, 2, 64] {
+            let search = ida_star_with_table(0, successors, |&n| n == 3, |&n| 3 - n, table_size);
+            assert_eq!(search, Some((3, vec![0, 1, 2, 3])));
+            let search = ida_star_with_table(3, successors, |&n| n == 0, |_| 0, table_size);
+            assert_eq!(search, None);
+        }
+    }
+
+    #[test]
+    fn test_ida_star_with_table_skips_repeated_nodes() {
+        // Every node of a 12x12 grid can be reached by many different paths.
+        let mut expanded = 0;
+        let successors = |&(x, y): &(u32, u32)| {
+            expanded += 1;
+            vec![((x + 1, y), 1), ((x, y + 1), 1)]
+        };
+        let heuristic = |&(x, y): &(u32, u32)| (12 - x.min(12) + 12 - y.min(12)) as usize;
+        let search = ida_star_with_table((0, 0), successors, |&p| p == (12, 12), heuristic, 4096);
+        assert_eq!(
+            search.map(|(cost, path)| (cost, path.len())),
+            Some((24, 25))
+        );
+        assert!(expanded <= 13 * 13);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let graph = WeightedGraph(vec![vec![(1, 3), (2, 4)], vec![], vec![(0, 1)]]);
+        let labels = |n: usize| ["a", "say \"hi\"", "c\\d"][n].to_string();
+        assert_eq!(
+            to_dot(&graph, labels, false),
+            "digraph {\n\
+             \x20   0 [label=\"a\"];\n\
+             \x20   1 [label=\"say \\\"hi\\\"\"];\n\
+             \x20   2 [label=\"c\\\\d\"];\n\
+             \x20   0 -> 1;\n\
+             \x20   0 -> 2;\n\
+             \x20   2 -> 0;\n\
+             }\n"
+        );
+        assert!(to_dot(&graph, labels, true).contains("    0 -> 2 [label=\"4\"];\n"));
+    }
+
+    #[test]
+    fn test_compound_weights() {
+        // From 0, either take one big step to 10 costing (1, 0), or ten small
+        // steps costing (0, 1) each.
+        let successors = |&n: &u32| {
+            let mut next = vec![(n + 1, (0u32, 1u32))];
+            if n == 0 {
+                next.push((10, (1, 0)));
+            }
+            next
+        };
+        let (cost, path) = dijkstra(0, successors, |&n| n == 10).unwrap();
+        assert_eq!(cost, (0, 10));
+        assert_eq!(path.len(), 11);
+
+        let swapped = |n: &u32| {
+            successors(n)
+                .into_iter()
+                .map(|(n, (a, b))| (n, (b, a)))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            dijkstra(0, swapped, |&n| n == 10),
+            Some(((0, 1), vec![0, 10]))
+        );
+        assert_eq!(
+            bidirectional_dijkstra(0, 10, swapped, |&n: &u32| match n {
+                10 => vec![(9, (1, 0)), (0, (0, 1))],
+                0 => vec![],
+                n => vec![(n - 1, (1, 0))],
+            }),
+            Some(((0, 1), vec![0, 10]))
+        );
+        assert_eq!(Weight::plus((1u64, 2u8), (3, 4)), (4, 6));
+    }
+
+    #[test]
+    fn test_astar() {
+        let graph = make_graph();
+        assert_eq!(graph.astar(4, 1, |_| 0), Some(vec![4, 3, 1]));
+        assert_eq!(graph.astar(2, 2, |_| 0), Some(vec![2]));
+
+        let weighted = weighted_graph();
+        // Remaining hops to node 3, which never overestimates the cost.
+        let hops = |n: usize| [2, 2, 1, 0, 1][n];
+        assert_eq!(weighted.astar(0, 3, hops), Some(vec![0, 1, 2, 3]));
+        assert_eq!(weighted.astar(3, 0, hops), None);
+    }
+
+    #[test]
+    fn test_astar_search_states() {
+        // Find the fewest moves to make a grid position, where moving east
+        // costs 1 and north costs 2, guided by the Manhattan distance.
+        let goal = (3, 2);
+        let successors = |&(x, y): &(i32, i32)| vec![((x + 1, y), 1), ((x, y + 1), 2)];
+        let heuristic = |&(x, y): &(i32, i32)| ((goal.0 - x).abs() + (goal.1 - y).abs()) as usize;
+        let (cost, path) = astar_search((0, 0), successors, |&p| p == goal, heuristic).unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&goal));
+        assert_eq!(path.len(), 6);
+    }
+
+    struct Ring(i32);
+
+    impl NodeGraph for Ring {
+        type Node = char;
+
+        fn node_neighbours(&self, &node: &char) -> Vec<(char, usize)> {
+            let index = node as i32 - 'a' as i32;
+            [-1, 1]
+                .iter()
+                .map(|d| (index + d).rem_euclid(self.0))
+                .map(|i| ((b'a' + i as u8) as char, 1))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_node_graph() {
+        let ring = Ring(6);
+        assert_eq!(
+            ring.shortest_path(&'b', &'e'),
+            Some(vec!['b', 'a', 'f', 'e'])
+        );
+        assert_eq!(ring.shortest_path(&'a', &'c'), Some(vec!['a', 'b', 'c']));
+        assert_eq!(ring.shortest_path(&'a', &'z'), None);
+        let path = ring.astar_path(&'a', &'d', |&n| {
+            ((n as i32 - 'd' as i32).abs() % 3) as usize
+        });
+        assert_eq!(path.map(|p| p.len()), Some(4));
+    }
+
+    #[test]
+    fn test_dijkstra_and_bfs() {
+        let graph = weighted_graph();
+        let weighted = |&n: &usize| graph.0[n].clone();
+        assert_eq!(
+            dijkstra(0, weighted, |&n| n == 3),
+            Some((6, vec![0, 1, 2, 3]))
+        );
+        assert_eq!(dijkstra(0, weighted, |&n| n == 0), Some((0, vec![0])));
+        assert_eq!(dijkstra(3, weighted, |&n| n == 0), None);
+
+        // Ignoring costs, the direct edge is the fewest steps.
+        let unweighted = |n: &usize| weighted(n).into_iter().map(|(dest, _)| dest);
+        assert_eq!(bfs(0, unweighted, |&n| n == 3), Some(vec![0, 3]));
+        assert_eq!(bfs(0, unweighted, |&n| n == 4), Some(vec![0, 1, 2, 4]));
+        assert_eq!(bfs(4, unweighted, |&n| n == 0), None);
+    }
+
+    #[test]
+    fn test_bidirectional_dijkstra() {
+        let edges = weighted_graph().0;
+        let successors = |&n: &usize| edges[n].clone();
+        let predecessors = |&n: &usize| {
+            (0..edges.len())
+                .flat_map(|from| edges[from].iter().map(move |&(to, cost)| (from, to, cost)))
+                .filter(|&(_, to, _)| to == n)
+                .map(|(from, _, cost)| (from, cost))
+                .collect::<Vec<_>>()
+        };
+
+        for goal in 0..edges.len() {
+            assert_eq!(
+                bidirectional_dijkstra(0, goal, successors, predecessors),
+                dijkstra(0, successors, |&n| n == goal)
+            );
+        }
+        assert_eq!(bidirectional_dijkstra(3, 0, successors, predecessors), None);
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        let nodes = (0..2).collect();
+        let mut edges = HashMap::new();
+        edges.insert(0, vec![]);
+        edges.insert(1, vec![]);
+        let graph = TestGraph { nodes, edges };
+
+        assert_eq!(graph.find_shortest_path_indices(0, 1), None);
+
+        let info = graph.shortest_path_search(0, None);
+        assert_eq!(info.cost_to(1), None);
+        assert_eq!(info.path_to(1), None);
+        assert_eq!(info.highest_cost(), 0);
+    }
 }
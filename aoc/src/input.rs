@@ -0,0 +1,303 @@
+//! Loading puzzle inputs at runtime, so that solutions can be run against
+//! anyone's input rather than only the ones bundled with `include_str!`.
+
+use crate::Error;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The directory inputs are read from and cached in, as `dayNN_input.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The session cookie used to download inputs from adventofcode.com, with the
+/// `download` feature.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Load the input for `day` as given on this program's command line, looking
+/// in order at:
+///
+/// 1. a path given as `--input <path>`, or stdin with `--input -`,
+/// 2. `dayNN_input.txt` in the directory named by `AOC_INPUT_DIR` (or
+///    `inputs` if it isn't set),
+/// 3. with the `download` feature, adventofcode.com, using the session cookie
+///    in `AOC_SESSION`. The download is saved to the input directory so it is
+///    only fetched once,
+/// 4. the `bundled` input.
+///
+/// Any other command line arguments are an error.
+pub fn load(day: u32, bundled: &str) -> Result<String, Error> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let loader = InputLoader::from_args(&mut args)?;
+    if let Some(arg) = args.first() {
+        return Err(Error::Parse(format!(
+            "Unexpected argument '{}', the only option is --input <path|->",
+            arg
+        )));
+    }
+    loader.with_bundled(bundled).load(day)
+}
+
+/// The directory named by `AOC_INPUT_DIR`, or `inputs` if it isn't set.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// The places to look for puzzle inputs, as used by [load](fn.load.html).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputLoader {
+    source: Option<Source>,
+    input_dir: Option<PathBuf>,
+    #[cfg(feature = "download")]
+    session: Option<String>,
+    bundled: Option<String>,
+}
+
+// An input named explicitly, rather than found by looking in the usual places.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputLoader {
+    /// A loader which has nowhere to look.
+    pub fn new() -> InputLoader {
+        InputLoader::default()
+    }
+
+    /// A loader configured from the environment.
+    pub fn from_env() -> InputLoader {
+        InputLoader {
+            input_dir: Some(input_dir()),
+            #[cfg(feature = "download")]
+            session: env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty()),
+            ..InputLoader::default()
+        }
+    }
+
+    /// A loader configured from the environment, which also reads from the
+    /// path given as `--input <path>` in `args`, or from stdin for
+    /// `--input -`. The option is removed from `args`, leaving any others for
+    /// the caller.
+    ///
+    /// ```
+    /// use aoc::input::InputLoader;
+    ///
+    /// let mut args = vec!["run".to_string(), "--input".to_string(), "-".to_string()];
+    /// let loader = InputLoader::from_args(&mut args).unwrap();
+    /// assert_eq!(loader, InputLoader::from_env().with_stdin());
+    /// assert_eq!(args, ["run"]);
+    /// ```
+    pub fn from_args(args: &mut Vec<String>) -> Result<InputLoader, Error> {
+        let loader = InputLoader::from_env();
+        let index = match args.iter().position(|a| a == "--input") {
+            Some(index) => index,
+            None => return Ok(loader),
+        };
+        if index + 1 >= args.len() {
+            return Err(Error::Parse("--input needs a path, or - for stdin".into()));
+        }
+        let value = args.remove(index + 1);
+        args.remove(index);
+        Ok(match value.as_str() {
+            "-" => loader.with_stdin(),
+            _ => loader.with_path(value),
+        })
+    }
+
+    pub fn with_path<P: AsRef<Path>>(self, path: P) -> InputLoader {
+        InputLoader {
+            source: Some(Source::Path(path.as_ref().to_path_buf())),
+            ..self
+        }
+    }
+
+    pub fn with_stdin(self) -> InputLoader {
+        InputLoader {
+            source: Some(Source::Stdin),
+            ..self
+        }
+    }
+
+    pub fn with_input_dir<P: AsRef<Path>>(self, input_dir: P) -> InputLoader {
+        InputLoader {
+            input_dir: Some(input_dir.as_ref().to_path_buf()),
+            ..self
+        }
+    }
+
+    #[cfg(feature = "download")]
+    pub fn with_session(self, session: &str) -> InputLoader {
+        InputLoader {
+            session: Some(session.trim().to_string()),
+            ..self
+        }
+    }
+
+    /// The input to fall back on when there is none anywhere else.
+    pub fn with_bundled(self, bundled: &str) -> InputLoader {
+        InputLoader {
+            bundled: Some(bundled.to_string()),
+            ..self
+        }
+    }
+
+    /// Whether an input path or stdin was given explicitly, rather than it
+    /// being looked for in the usual places.
+    pub fn is_explicit(&self) -> bool {
+        self.source.is_some()
+    }
+
+    /// Where the input for `day` is read from and cached in, if there is an
+    /// input directory.
+    pub fn cached_path(&self, day: u32) -> Option<PathBuf> {
+        let dir = self.input_dir.as_ref()?;
+        Some(dir.join(format!("day{:02}_input.txt", day)))
+    }
+
+    pub fn load(&self, day: u32) -> Result<String, Error> {
+        if !(1..=25).contains(&day) {
+            return Err(Error::Parse(format!("There is no day {}", day)));
+        }
+
+        match &self.source {
+            Some(Source::Path(path)) => return read_input(path),
+            Some(Source::Stdin) => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::io("Can't read input from stdin", e))?;
+                return Ok(input);
+            }
+            None => (),
+        }
+
+        let cached_path = self.cached_path(day);
+        if let Some(path) = cached_path.as_ref().filter(|p| p.is_file()) {
+            return read_input(path);
+        }
+
+        #[cfg(feature = "download")]
+        if let Some(session) = &self.session {
+            return download_and_cache(day, session, cached_path);
+        }
+
+        self.bundled.clone().ok_or_else(|| {
+            let message = format!(
+                "No input found for day {}: pass a path or put it in ${}{}",
+                day,
+                INPUT_DIR_VAR,
+                if cfg!(feature = "download") {
+                    format!(" or set ${}", SESSION_VAR)
+                } else {
+                    String::new()
+                }
+            );
+            Error::Io(io::Error::new(io::ErrorKind::NotFound, message))
+        })
+    }
+}
+
+fn read_input(path: &Path) -> Result<String, Error> {
+    fs::read_to_string(path).map_err(|e| Error::io(format!("Can't read '{}'", path.display()), e))
+}
+
+#[cfg(feature = "download")]
+fn download_and_cache(
+    day: u32,
+    session: &str,
+    cached_path: Option<PathBuf>,
+) -> Result<String, Error> {
+    let input = download(day, session)?;
+    if let Some(path) = cached_path {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| Error::io(format!("Can't create '{}'", dir.display()), e))?;
+        }
+        fs::write(&path, &input)
+            .map_err(|e| Error::io(format!("Can't cache input in '{}'", path.display()), e))?;
+    }
+    Ok(input)
+}
+
+#[cfg(feature = "download")]
+fn download(day: u32, session: &str) -> Result<String, Error> {
+    let url = format!("https://adventofcode.com/2019/day/{}/input", day);
+    ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set(
+            "User-Agent",
+            "github.com/jtempest/advent_of_code_2019-rs input loader",
+        )
+        .call()
+        .map_err(|e| Error::io(format!("Can't download input for day {}", day), e))?
+        .into_string()
+        .map_err(|e| Error::io(format!("Can't read input for day {}", day), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_resolution_order() {
+        let dir = env::temp_dir().join(format!("aoc_input_test_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let explicit = dir.join("explicit.txt");
+        fs::write(&explicit, "explicit").unwrap();
+        fs::write(dir.join("day03_input.txt"), "cached").unwrap();
+
+        let loader = InputLoader::new().with_input_dir(&dir);
+        assert_eq!(loader.cached_path(3), Some(dir.join("day03_input.txt")));
+        assert_eq!(loader.load(3), Ok("cached".to_string()));
+        assert_eq!(
+            loader.clone().with_path(&explicit).load(3).unwrap(),
+            "explicit"
+        );
+
+        // Nowhere to find it, and no session to download it with.
+        match loader.load(4) {
+            Err(Error::Io(e)) => {
+                assert_eq!(e.kind(), io::ErrorKind::NotFound);
+                assert!(e.to_string().contains(INPUT_DIR_VAR));
+            }
+            other => panic!("Expected a missing input, got {:?}", other),
+        }
+        assert!(loader.load(26).is_err());
+        assert!(InputLoader::new()
+            .with_path(dir.join("missing"))
+            .load(3)
+            .is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_input_args() {
+        let args = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let mut none = args(&["15"]);
+        let loader = InputLoader::from_args(&mut none).unwrap();
+        assert!(!loader.is_explicit());
+        assert_eq!(none, ["15"]);
+
+        let mut path = args(&["--input", "mine.txt", "15"]);
+        let loader = InputLoader::from_args(&mut path).unwrap();
+        assert_eq!(loader, InputLoader::from_env().with_path("mine.txt"));
+        assert_eq!(path, ["15"]);
+
+        assert!(InputLoader::from_args(&mut args(&["15", "--input"])).is_err());
+    }
+
+    #[test]
+    fn test_bundled_fallback() {
+        let loader = InputLoader::new().with_bundled("bundled");
+        assert_eq!(loader.load(5), Ok("bundled".to_string()));
+        assert!(loader.with_path("/no/such/input").load(5).is_err());
+    }
+}
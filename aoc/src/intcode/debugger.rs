@@ -0,0 +1,190 @@
+use super::{Executor, Interpreter, Machine, MachineState, Step};
+
+/// Wraps a [Machine](struct.Machine.html) to run it one instruction at a
+/// time, recording enough history to step backwards again.
+///
+/// A snapshot of the machine is taken every `checkpoint_interval` steps. To
+/// rewind, the latest checkpoint before the target step is restored and the
+/// remaining instructions are executed again. Inputs are kept when rewinding,
+/// so any consumed after the target step are buffered once more.
+///
+/// Instructions are executed by the machine's [Executor](trait.Executor.html),
+/// so outputs are recorded in its transcript just as when it is run, though
+/// any state held by the executor itself is not rewound.
+///
+/// ```
+/// use aoc::intcode::{Debugger, Machine};
+///
+/// let mut debugger = Debugger::new(Machine::from_source("104,1,104,2,99"), 10);
+/// assert_eq!(debugger.run(), Some(1));
+/// assert_eq!(debugger.run(), Some(2));
+/// assert_eq!(debugger.step_back(1), 1);
+/// assert_eq!(debugger.run(), Some(2));
+/// ```
+#[derive(Debug)]
+pub struct Debugger<E = Interpreter> {
+    machine: Machine<E>,
+    checkpoint_interval: usize,
+    checkpoints: Vec<MachineState>,
+    history: Vec<usize>,
+    inputs: Vec<(usize, i64)>,
+}
+
+impl<E: Executor> Debugger<E> {
+    pub fn new(machine: Machine<E>, checkpoint_interval: usize) -> Debugger<E> {
+        assert!(checkpoint_interval > 0);
+        let checkpoints = vec![machine.snapshot()];
+        Debugger {
+            machine,
+            checkpoint_interval,
+            checkpoints,
+            history: Vec::new(),
+            inputs: Vec::new(),
+        }
+    }
+
+    pub fn machine(&self) -> &Machine<E> {
+        &self.machine
+    }
+
+    /// The number of instructions executed so far.
+    pub fn steps_taken(&self) -> usize {
+        self.history.len()
+    }
+
+    /// The address of every instruction executed so far, in order.
+    pub fn history(&self) -> &[usize] {
+        &self.history
+    }
+
+    /// Buffer the given input value, see [Machine::input](struct.Machine.html#method.input).
+    pub fn input(&mut self, value: i64) {
+        self.inputs.push((self.steps_taken(), value));
+        self.machine.input(value);
+    }
+
+    /// Input the given ASCII string and then input an additional '\n'.
+    pub fn input_ascii(&mut self, ascii_line: &str) {
+        if let Some(teletype) = self.machine.teletype.as_mut() {
+            teletype.input(ascii_line);
+        }
+        for c in ascii_line.chars() {
+            self.input(c as i64);
+        }
+        self.input('\n' as i64);
+    }
+
+    /// Execute a single instruction. Step::Halt means no instruction was
+    /// executed, as the machine is halted or awaiting input.
+    pub fn step(&mut self) -> Step {
+        let ip = self.machine.ip();
+        let step = self.machine.step();
+        if step != Step::Halt {
+            self.history.push(ip);
+            if self.steps_taken().is_multiple_of(self.checkpoint_interval) {
+                self.checkpoints.push(self.machine.snapshot());
+            }
+        }
+        step
+    }
+
+    /// Execute instructions until the machine pauses, see
+    /// [Machine::run](struct.Machine.html#method.run).
+    pub fn run(&mut self) -> Option<i64> {
+        loop {
+            match self.step() {
+                Step::Continue => continue,
+                Step::Halt => break None,
+                Step::Output(value) => break Some(value),
+            }
+        }
+    }
+
+    /// Rewind the machine by up to `num_steps` instructions, returning the
+    /// number actually rewound.
+    pub fn step_back(&mut self, num_steps: usize) -> usize {
+        let num_steps = num_steps.min(self.steps_taken());
+        let target = self.steps_taken() - num_steps;
+
+        let checkpoint_index = target / self.checkpoint_interval;
+        self.checkpoints.truncate(checkpoint_index + 1);
+        let checkpoint_step = checkpoint_index * self.checkpoint_interval;
+
+        self.machine
+            .restore(self.checkpoints[checkpoint_index].clone());
+        for &(_, value) in self.inputs.iter().filter(|(n, _)| *n >= checkpoint_step) {
+            self.machine.input(value);
+        }
+
+        // The teletype already shows the replayed output, so isn't echoed to.
+        let teletype = self.machine.take_teletype();
+        for _ in checkpoint_step..target {
+            self.machine.step();
+        }
+        self.machine.teletype = teletype;
+        self.history.truncate(target);
+        for input in self.inputs.iter_mut().filter(|(n, _)| *n > target) {
+            input.0 = target;
+        }
+
+        num_steps
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_step_back() {
+        // Outputs each input doubled until it reads a zero.
+        let program = "3,15,1006,15,14,1002,15,2,16,4,16,1105,1,0,99";
+        let mut debugger = Debugger::new(Machine::from_source(program), 3);
+        debugger.input(3);
+        debugger.input(5);
+        assert_eq!(debugger.run(), Some(6));
+        assert_eq!(debugger.run(), Some(10));
+        assert_eq!(debugger.run(), None);
+        assert_eq!(debugger.steps_taken(), 10);
+        assert_eq!(debugger.history(), &[0, 2, 5, 9, 11, 0, 2, 5, 9, 11]);
+
+        // Back to before the second input was read, which is buffered again.
+        assert_eq!(debugger.step_back(6), 6);
+        assert_eq!(debugger.machine().ip(), 11);
+        assert_eq!(debugger.history(), &[0, 2, 5, 9]);
+        assert_eq!(debugger.run(), Some(10));
+
+        debugger.input(0);
+        assert_eq!(debugger.run(), None);
+        assert!(debugger.machine().is_halted());
+
+        assert_eq!(debugger.step_back(100), 12);
+        assert_eq!(debugger.machine().ip(), 0);
+        let outputs: Vec<_> = std::iter::from_fn(|| debugger.run()).collect();
+        assert_eq!(outputs, [6, 10]);
+        assert!(debugger.machine().is_halted());
+    }
+
+    #[test]
+    fn test_step_back_keeps_transcript() {
+        let program = "3,15,1006,15,14,1002,15,2,16,4,16,1105,1,0,99";
+        let mut machine = Machine::from_source(program);
+        machine.record_transcript();
+        let mut debugger = Debugger::new(machine, 4);
+        debugger.input(3);
+        debugger.input(5);
+        debugger.input(0);
+        assert_eq!(debugger.run(), Some(6));
+        assert_eq!(debugger.run(), Some(10));
+
+        // Rewind to after the first output, replaying it from a checkpoint.
+        assert_eq!(debugger.step_back(5), 5);
+        let transcript = debugger.machine().transcript().unwrap();
+        assert_eq!(transcript.to_string(), "in 3\nout 6\n");
+
+        assert_eq!(debugger.run(), Some(10));
+        assert_eq!(debugger.run(), None);
+        let transcript = debugger.machine().transcript().unwrap();
+        assert_eq!(transcript.to_string(), "in 3\nout 6\nin 5\nout 10\nin 0\n");
+    }
+}
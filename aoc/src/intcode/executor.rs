@@ -0,0 +1,40 @@
+use super::{MachineState, Step};
+
+/// A backend that executes IntCode instructions on behalf of a
+/// [Machine](struct.Machine.html).
+///
+/// The [Interpreter](struct.Interpreter.html) is the default, but faster
+/// backends (such as a compiler or JIT) may be substituted using
+/// [Machine::with_executor](struct.Machine.html#method.with_executor)
+/// without any other changes to how the Machine is used.
+pub trait Executor {
+    /// Execute instructions until the machine pauses.
+    ///
+    /// Returns Some(value) if an output instruction was executed, or None if
+    /// the machine halted or is awaiting input.
+    fn run(&mut self, state: &mut MachineState) -> Option<i64>;
+
+    /// Execute the single instruction at the instruction pointer.
+    ///
+    /// Backends which can't stop after each instruction, such as a compiler,
+    /// can rely on the default of interpreting it.
+    fn step(&mut self, state: &mut MachineState) -> Step {
+        state.step()
+    }
+}
+
+/// Executes a program one instruction at a time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Interpreter;
+
+impl Executor for Interpreter {
+    fn run(&mut self, state: &mut MachineState) -> Option<i64> {
+        loop {
+            match state.step() {
+                Step::Continue => continue,
+                Step::Halt => break None,
+                Step::Output(value) => break Some(value),
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use super::{Executor, MachineState, Opcode, Step};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A handler for an opcode not defined by the IntCode specification, which
+/// can be registered with an [ExtendedInterpreter](struct.ExtendedInterpreter.html).
+pub trait CustomOpcode {
+    /// The number of parameters following the opcode.
+    fn num_params(&self) -> usize;
+
+    /// Execute the instruction at the state's instruction pointer, using
+    /// [read_param](struct.MachineState.html#method.read_param) and
+    /// [write_param](struct.MachineState.html#method.write_param) to access
+    /// its parameters.
+    ///
+    /// Unless Step::Halt is returned the instruction pointer is then moved
+    /// past the instruction, otherwise the instruction will be executed again
+    /// the next time the machine is run.
+    fn execute(&mut self, state: &mut MachineState) -> Step;
+}
+
+/// Executes a program one instruction at a time, like the
+/// [Interpreter](struct.Interpreter.html), but also supports any registered
+/// [CustomOpcodes](trait.CustomOpcode.html).
+///
+/// ```
+/// use aoc::intcode::{CustomOpcode, ExtendedInterpreter, Machine, MachineState, Program, Step};
+///
+/// // Opcode 42 writes the number of times it has been executed to its parameter.
+/// struct Counter(i64);
+///
+/// impl CustomOpcode for Counter {
+///     fn num_params(&self) -> usize {
+///         1
+///     }
+///
+///     fn execute(&mut self, state: &mut MachineState) -> Step {
+///         self.0 += 1;
+///         state.write_param(0, self.0);
+///         Step::Continue
+///     }
+/// }
+///
+/// let executor = ExtendedInterpreter::new().with_opcode(42, Counter(0));
+/// let program = Program::from("42,9,42,9,4,9,99,0,0,0");
+/// let mut machine = Machine::with_executor(&program, executor);
+/// assert_eq!(machine.run(), Some(2));
+/// ```
+#[derive(Default)]
+pub struct ExtendedInterpreter {
+    handlers: HashMap<i64, Box<dyn CustomOpcode>>,
+}
+
+impl ExtendedInterpreter {
+    pub fn new() -> ExtendedInterpreter {
+        ExtendedInterpreter::default()
+    }
+
+    /// Register a handler for the given opcode, which must be between 0 and
+    /// 99 and not already in use.
+    pub fn with_opcode<C: CustomOpcode + 'static>(
+        mut self,
+        opcode: i64,
+        handler: C,
+    ) -> ExtendedInterpreter {
+        assert!((0..100).contains(&opcode), "Opcode {} out of range", opcode);
+        assert!(
+            Opcode::try_new(opcode).is_none() && !self.handlers.contains_key(&opcode),
+            "Opcode {} is already in use",
+            opcode
+        );
+        self.handlers.insert(opcode, Box::new(handler));
+        self
+    }
+}
+
+impl Executor for ExtendedInterpreter {
+    fn run(&mut self, state: &mut MachineState) -> Option<i64> {
+        loop {
+            match self.step(state) {
+                Step::Continue => continue,
+                Step::Halt => break None,
+                Step::Output(value) => break Some(value),
+            }
+        }
+    }
+
+    fn step(&mut self, state: &mut MachineState) -> Step {
+        let opcode = state.read(state.ip()) % 100;
+        match self.handlers.get_mut(&opcode) {
+            None => state.step(),
+            Some(handler) => {
+                let step = handler.execute(state);
+                if step != Step::Halt && state.fault_message().is_none() {
+                    state.set_ip(state.ip() + handler.num_params() + 1);
+                }
+                step
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ExtendedInterpreter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut opcodes: Vec<_> = self.handlers.keys().collect();
+        opcodes.sort();
+        f.debug_struct("ExtendedInterpreter")
+            .field("opcodes", &opcodes)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::intcode::{Machine, Program};
+
+    // Opcode 50 outputs its parameter, then pauses the first time it is run.
+    struct PauseOnce(bool);
+
+    impl CustomOpcode for PauseOnce {
+        fn num_params(&self) -> usize {
+            1
+        }
+
+        fn execute(&mut self, state: &mut MachineState) -> Step {
+            if !self.0 {
+                self.0 = true;
+                return Step::Halt;
+            }
+            Step::Output(state.read_param(0))
+        }
+    }
+
+    #[test]
+    fn test_custom_opcode() {
+        let executor = ExtendedInterpreter::new().with_opcode(50, PauseOnce(false));
+        let program = Program::from("1101,2,4,9,150,5,50,9,99,0");
+        let mut machine = Machine::with_executor(&program, executor);
+
+        assert_eq!(machine.run(), None);
+        assert_eq!(machine.ip(), 4);
+        assert!(!machine.is_halted());
+        assert!(!machine.is_awaiting_input());
+
+        assert_eq!(machine.run_as_iter().collect::<Vec<_>>(), [5, 6]);
+        assert!(machine.is_halted());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_builtin_opcode_cannot_be_replaced() {
+        ExtendedInterpreter::new().with_opcode(4, PauseOnce(false));
+    }
+}
@@ -0,0 +1,1044 @@
+//! Run IntCode programs as defined by the puzzles in [Advent of Code 2019](https://adventofcode.com/2019).
+//!
+//! # Examples
+//! ```
+//! use aoc::intcode::{Program, Machine};
+//!
+//! let program = Program::from("3,10,4,10,99");
+//! let mut machine = Machine::new(&program);
+//! machine.input(42);
+//! let output = machine.run();
+//! assert_eq!(output, Some(42));
+//!
+//! let program = Program::from("104,1,104,2,104,3,99");
+//! let output = Machine::new(&program).run_as_iter().collect::<Vec<_>>();
+//! assert_eq!(output, [1, 2, 3]);
+//! ```
+
+mod debugger;
+pub use debugger::Debugger;
+
+mod executor;
+pub use executor::{Executor, Interpreter};
+
+mod extension;
+pub use extension::{CustomOpcode, ExtendedInterpreter};
+
+mod network;
+pub use network::{Network, NetworkStatus, Route};
+
+mod optimizer;
+
+mod teletype;
+pub use teletype::Teletype;
+
+mod transcript;
+pub use transcript::{Event, Transcript};
+
+use crate::Error;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Add, Mul};
+use std::str::FromStr;
+
+// Set true for verbose debugging output when intcode machines are running
+const INTCODE_DEBUG: bool = false;
+
+// Simple pass-through macro to only print if INTCODE_DEBUG is true
+macro_rules! intcode_debug {
+    ($($arg:tt)*) => ({
+        if INTCODE_DEBUG {
+            println!($($arg)*);
+        }
+    })
+}
+
+/// A program that can be run on an IntCode [Machine](struct.Machine.html).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Program(Vec<i64>);
+
+impl Program {
+    pub fn write(&mut self, position: usize, value: i64) {
+        self.0[position] = value;
+    }
+
+    /// Produce an equivalent program with constants folded and dead stores
+    /// removed, so that it runs faster on the [Interpreter](struct.Interpreter.html).
+    ///
+    /// Only instructions reachable from address 0 by fall-through or by jumps
+    /// to immediate addresses are rewritten, and never those which the program
+    /// itself writes to, and values are only treated as constant if no write
+    /// could reach them. Writes through relative or computed addresses are
+    /// assumed to only modify data, so they prevent constants being
+    /// propagated but not the rewriting of instructions. The optimized program
+    /// produces the same outputs for the same inputs provided that all code
+    /// is reachable in that way and never written through such addresses,
+    /// which is true of the puzzle inputs.
+    pub fn optimize(&self) -> Program {
+        optimizer::optimize(self)
+    }
+}
+
+impl FromStr for Program {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Program, Error> {
+        let program = input
+            .trim()
+            .split(',')
+            .map(|s| {
+                let s = s.trim();
+                s.parse::<i64>()
+                    .map_err(|_| Error::Parse(format!("Invalid IntCode value '{}'", s)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Program(program))
+    }
+}
+
+/// Parses the source code of a program, panicking if it isn't a comma
+/// separated list of integers. Use [str::parse](#impl-FromStr-for-Program)
+/// to handle invalid source.
+impl From<&str> for Program {
+    fn from(input: &str) -> Program {
+        input.parse().unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+// An IntCode opcode
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Opcode {
+    Halt,
+    Input,
+    Output,
+    AdjustRelativeBase,
+    Add,
+    Mul,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+}
+
+impl Opcode {
+    fn try_new(value: i64) -> Option<Opcode> {
+        let opcode = match value % 100 {
+            99 => Opcode::Halt,
+            1 => Opcode::Add,
+            2 => Opcode::Mul,
+            3 => Opcode::Input,
+            4 => Opcode::Output,
+            5 => Opcode::JumpIfTrue,
+            6 => Opcode::JumpIfFalse,
+            7 => Opcode::LessThan,
+            8 => Opcode::Equals,
+            9 => Opcode::AdjustRelativeBase,
+            _ => return None,
+        };
+        Some(opcode)
+    }
+
+    fn num_params(self) -> usize {
+        match self {
+            Opcode::Halt => 0,
+            Opcode::Input => 1,
+            Opcode::Output => 1,
+            Opcode::AdjustRelativeBase => 1,
+            Opcode::JumpIfTrue => 2,
+            Opcode::JumpIfFalse => 2,
+            Opcode::Add => 3,
+            Opcode::Mul => 3,
+            Opcode::LessThan => 3,
+            Opcode::Equals => 3,
+        }
+    }
+
+    // The index of the parameter this opcode writes to, if any.
+    fn write_param(self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => Some(2),
+            Opcode::Input => Some(0),
+            _ => None,
+        }
+    }
+}
+
+// An IntCode instruction parameter mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ParameterMode {
+    Position,
+    Immediate,
+    Relative,
+}
+
+impl ParameterMode {
+    fn try_new(instruction: i64, param_index: usize) -> Option<ParameterMode> {
+        match ParameterMode::mode_digit(instruction, param_index) {
+            0 => Some(ParameterMode::Position),
+            1 => Some(ParameterMode::Immediate),
+            2 => Some(ParameterMode::Relative),
+            _ => None,
+        }
+    }
+
+    fn mode_digit(instruction: i64, param_index: usize) -> i64 {
+        assert!(param_index <= 2);
+        let all_modes = instruction / 100;
+        (all_modes / (10_i64.pow(param_index as u32))) % 10
+    }
+}
+
+// A single IntCode instruction
+#[derive(Copy, Clone, PartialEq)]
+struct Instruction {
+    value: i64,
+    opcode: Opcode,
+}
+
+impl Instruction {
+    // Decode an instruction, checking that all of its parameter modes are
+    // valid before anything is executed.
+    fn decode(value: i64) -> Result<Instruction, String> {
+        let opcode =
+            Opcode::try_new(value).ok_or_else(|| format!("Unknown opcode '{}'", value % 100))?;
+        for index in 0..opcode.num_params() {
+            let mode = ParameterMode::try_new(value, index).ok_or_else(|| {
+                format!(
+                    "Unknown parameter mode {}",
+                    ParameterMode::mode_digit(value, index)
+                )
+            })?;
+            if mode == ParameterMode::Immediate && opcode.write_param() == Some(index) {
+                return Err("Cannot write in immediate mode".to_string());
+            }
+        }
+        Ok(Instruction { value, opcode })
+    }
+
+    fn debug_param_modes(&self) -> Vec<Option<ParameterMode>> {
+        (0..self.opcode.num_params())
+            .map(|n| ParameterMode::try_new(self.value, n))
+            .collect()
+    }
+}
+
+impl fmt::Debug for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Instruction {{ {:?} {:?} }}",
+            self.opcode,
+            self.debug_param_modes()
+        )
+    }
+}
+
+/// The result of executing a single instruction on a [MachineState](struct.MachineState.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Execution may continue with the next instruction.
+    Continue,
+    /// The machine has halted, or is paused awaiting input.
+    Halt,
+    /// The machine produced an output value.
+    Output(i64),
+}
+
+/// The registers, memory and buffered input of an IntCode machine, operated
+/// on by an [Executor](trait.Executor.html).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MachineState {
+    ip: usize, // Instruction Pointer
+    rbo: i64,  // Relative Base Offset
+    memory: Vec<i64>,
+    input: VecDeque<i64>,
+    transcript: Option<Transcript>,
+    fault: Option<String>,
+}
+
+impl MachineState {
+    fn new(program: &Program) -> MachineState {
+        MachineState {
+            ip: 0,
+            rbo: 0,
+            memory: program.0.clone(),
+            input: VecDeque::new(),
+            transcript: None,
+            fault: None,
+        }
+    }
+
+    /// Read a single value from memory at the given address.
+    pub fn read(&self, address: usize) -> i64 {
+        if address < self.memory.len() {
+            self.memory[address]
+        } else {
+            0
+        }
+    }
+
+    /// Write a single value into memory at the given address.
+    pub fn write(&mut self, address: usize, value: i64) {
+        self.ensure_memory(address);
+        self.memory[address] = value;
+    }
+
+    /// The entire current memory.
+    pub fn memory(&self) -> &Vec<i64> {
+        &self.memory
+    }
+
+    /// The address of the next instruction to execute.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Move the instruction pointer to the given address.
+    pub fn set_ip(&mut self, ip: usize) {
+        self.ip = ip;
+    }
+
+    /// The base address used by relative mode parameters.
+    pub fn relative_base(&self) -> i64 {
+        self.rbo
+    }
+
+    /// Set the base address used by relative mode parameters.
+    pub fn set_relative_base(&mut self, relative_base: i64) {
+        self.rbo = relative_base;
+    }
+
+    /// Execute the instruction at the instruction pointer.
+    pub fn step(&mut self) -> Step {
+        self.exec_next_instruction()
+    }
+
+    /// Read the given parameter (indexed from 0) of the instruction at the
+    /// instruction pointer, according to its parameter mode.
+    pub fn read_param(&mut self, param: usize) -> i64 {
+        self.exec_read(param)
+    }
+
+    /// Write to the given parameter (indexed from 0) of the instruction at
+    /// the instruction pointer, according to its parameter mode.
+    pub fn write_param(&mut self, param: usize, value: i64) {
+        self.exec_write(param, value);
+    }
+
+    /// Stop the machine because the program did something invalid. Once
+    /// faulted every step halts without executing anything.
+    pub fn fault(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        intcode_debug!("FAULT @{}: {}", self.ip, message);
+        self.fault = Some(message);
+    }
+
+    /// The reason the machine faulted, if it did.
+    pub fn fault_message(&self) -> Option<&str> {
+        self.fault.as_deref()
+    }
+
+    fn is_halted(&self) -> bool {
+        self.fault.is_some() || self.read_opcode() == Some(Opcode::Halt)
+    }
+
+    fn is_awaiting_input(&self) -> bool {
+        self.fault.is_none() && self.read_opcode() == Some(Opcode::Input)
+    }
+
+    // None if the next instruction has a custom opcode.
+    fn read_opcode(&self) -> Option<Opcode> {
+        Opcode::try_new(self.read(self.ip))
+    }
+
+    fn input(&mut self, value: i64) {
+        self.input.push_front(value);
+    }
+
+    fn record_event(&mut self, event: Event) {
+        if let Some(transcript) = self.transcript.as_mut() {
+            transcript.record(event);
+        }
+    }
+
+    fn exec_next_instruction(&mut self) -> Step {
+        if self.fault.is_some() {
+            return Step::Halt;
+        }
+        let instruction = match Instruction::decode(self.read(self.ip)) {
+            Ok(instruction) => instruction,
+            Err(message) => {
+                self.fault(message);
+                return Step::Halt;
+            }
+        };
+        intcode_debug!(
+            "@{}: {} => {:?}",
+            self.ip,
+            self.memory[self.ip],
+            instruction
+        );
+        match instruction.opcode {
+            Opcode::Halt => Step::Halt,
+            Opcode::Add => self.exec_binary_op(Add::add),
+            Opcode::Mul => self.exec_binary_op(Mul::mul),
+            Opcode::Input => self.exec_input_op(),
+            Opcode::Output => self.exec_output_op(),
+            Opcode::JumpIfFalse => self.exec_jump_if_op(|v| v == 0),
+            Opcode::JumpIfTrue => self.exec_jump_if_op(|v| v != 0),
+            Opcode::LessThan => self.exec_binary_op(|a, b| if a < b { 1 } else { 0 }),
+            Opcode::Equals => self.exec_binary_op(|a, b| if a == b { 1 } else { 0 }),
+            Opcode::AdjustRelativeBase => self.exec_adjust_rbo(),
+        }
+    }
+
+    fn exec_binary_op<F: Fn(i64, i64) -> i64>(&mut self, func: F) -> Step {
+        let v1 = self.exec_read(0);
+        let v2 = self.exec_read(1);
+        let result = func(v1, v2);
+        self.exec_write(2, result);
+
+        self.ip += 4;
+        Step::Continue
+    }
+
+    fn exec_jump_if_op<F: Fn(i64) -> bool>(&mut self, predicate: F) -> Step {
+        let value = self.exec_read(0);
+        if predicate(value) {
+            let dest = self.exec_read(1);
+            intcode_debug!("jump => {}", dest);
+            self.ip = dest as usize;
+        } else {
+            self.ip += 3;
+        }
+
+        Step::Continue
+    }
+
+    fn exec_input_op(&mut self) -> Step {
+        match self.input.pop_back() {
+            None => Step::Halt,
+            Some(value) => {
+                self.record_event(Event::Input(value));
+                self.exec_write(0, value);
+                self.ip += 2;
+                Step::Continue
+            }
+        }
+    }
+
+    fn exec_output_op(&mut self) -> Step {
+        let value = self.exec_read(0);
+        self.ip += 2;
+        Step::Output(value)
+    }
+
+    fn exec_adjust_rbo(&mut self) -> Step {
+        let value = self.exec_read(0);
+        self.rbo += value;
+        intcode_debug!("rbo = {}", self.rbo);
+
+        self.ip += 2;
+        Step::Continue
+    }
+
+    // param is zero indexed
+    fn exec_read(&mut self, param: usize) -> i64 {
+        let value = self.read(self.ip + param + 1);
+        match self.param_mode(param) {
+            None => 0,
+            Some(ParameterMode::Position) => {
+                let output = self.read_mut(value as usize);
+                intcode_debug!("param@{} => {}", value, output);
+                output
+            }
+            Some(ParameterMode::Immediate) => {
+                intcode_debug!("param: {}", value);
+                value
+            }
+            Some(ParameterMode::Relative) => {
+                let pos = (self.rbo + value) as usize;
+                let output = self.read_mut(pos);
+                intcode_debug!("param@({} + {} = {}) => {}", self.rbo, value, pos, output);
+                output
+            }
+        }
+    }
+
+    // param is zero indexed
+    fn exec_write(&mut self, param: usize, value: i64) {
+        let offset = self.read(self.ip + param + 1);
+        let address = match self.param_mode(param) {
+            None => return,
+            Some(ParameterMode::Position) => {
+                intcode_debug!("write@{} <= {}", offset, value);
+                offset
+            }
+            Some(ParameterMode::Relative) => {
+                let address = self.rbo + offset;
+                intcode_debug!(
+                    "write@({} + {} = {}) <= {}",
+                    self.rbo,
+                    offset,
+                    address,
+                    value
+                );
+                address
+            }
+            Some(ParameterMode::Immediate) => {
+                self.fault("Cannot write in immediate mode");
+                return;
+            }
+        };
+        self.write(address as usize, value);
+    }
+
+    // The mode of the given parameter of the current instruction, faulting
+    // if it isn't valid.
+    fn param_mode(&mut self, param: usize) -> Option<ParameterMode> {
+        let instruction = self.read(self.ip);
+        let mode = ParameterMode::try_new(instruction, param);
+        if mode.is_none() {
+            let digit = ParameterMode::mode_digit(instruction, param);
+            self.fault(format!("Unknown parameter mode {}", digit));
+        }
+        mode
+    }
+
+    fn read_mut(&mut self, address: usize) -> i64 {
+        self.ensure_memory(address);
+        self.memory[address]
+    }
+
+    fn ensure_memory(&mut self, max_address: usize) {
+        if max_address >= self.memory().len() {
+            intcode_debug!("expanding memory to address {}", max_address);
+            self.memory.resize(max_address + 1, 0);
+        }
+    }
+}
+
+/// A machine that runs an IntCode [Program](struct.Program.html).
+///
+/// Execution is performed by an [Executor](trait.Executor.html), which by
+/// default is the [Interpreter](struct.Interpreter.html).
+#[derive(Debug)]
+pub struct Machine<E = Interpreter> {
+    state: MachineState,
+    executor: E,
+    teletype: Option<Teletype>,
+}
+
+impl Machine {
+    /// Construct a new Machine to run the given [Program](struct.Program.html).
+    pub fn new(program: &Program) -> Machine {
+        Machine::with_executor(program, Interpreter)
+    }
+
+    /// Construct a new Machine to run the program produced by the given source code.
+    pub fn from_source(program: &str) -> Machine {
+        Machine::new(&Program::from(program))
+    }
+
+    /// Construct a new Machine to run the given [Program](struct.Program.html),
+    /// buffering an initial input value.
+    pub fn with_input(program: &Program, input: i64) -> Machine {
+        let mut machine = Machine::new(program);
+        machine.input(input);
+        machine
+    }
+
+    /// Construct a new Machine to run the program produced by the given source code,
+    /// buffering an initial input value.
+    pub fn from_source_with_input(program: &str, input: i64) -> Machine {
+        Machine::with_input(&Program::from(program), input)
+    }
+}
+
+impl<E: Executor> Machine<E> {
+    /// Construct a new Machine to run the given [Program](struct.Program.html)
+    /// using the given [Executor](trait.Executor.html).
+    pub fn with_executor(program: &Program, executor: E) -> Machine<E> {
+        Machine {
+            state: MachineState::new(program),
+            executor,
+            teletype: None,
+        }
+    }
+
+    /// The [Executor](trait.Executor.html) running this Machine's program.
+    pub fn executor(&self) -> &E {
+        &self.executor
+    }
+
+    /// Run until a pause state is reached.
+    ///
+    /// Returns once the machine halts execution, with the value:
+    /// - None if there was a Halt instruction (99). See [is_halted](struct.Machine.html#method.is_halted).
+    /// - None if there was an Input instruction (3) and no input was buffered.
+    ///   See [is_awaiting_input](struct.Machine.html#method.is_awaiting_input).
+    /// - Some(value) if there was an Output instruction (4).
+    /// - None if the program did something invalid, such as using an unknown
+    ///   opcode. See [try_run](struct.Machine.html#method.try_run).
+    pub fn run(&mut self) -> Option<i64> {
+        let output = self.executor.run(&mut self.state);
+        match output {
+            None => intcode_debug!("HALTING"),
+            Some(value) => self.record_output(value),
+        }
+        output
+    }
+
+    /// Execute the single instruction at the instruction pointer using the
+    /// Machine's [Executor](trait.Executor.html). Outputs are recorded and
+    /// echoed in the same way as by [run](struct.Machine.html#method.run).
+    ///
+    /// Step::Halt means no instruction was executed, as the machine is halted
+    /// or awaiting input.
+    pub fn step(&mut self) -> Step {
+        let step = self.executor.step(&mut self.state);
+        if let Step::Output(value) = step {
+            self.record_output(value);
+        }
+        step
+    }
+
+    /// Calls [run](struct.Machine.html#method.run), returning an
+    /// [Error::Intcode](../enum.Error.html#variant.Intcode) if the program
+    /// faulted by using an unknown opcode or parameter mode, or by writing to
+    /// an immediate mode parameter.
+    pub fn try_run(&mut self) -> Result<Option<i64>, Error> {
+        let output = self.run();
+        match self.fault() {
+            Some(error) => Err(error),
+            None => Ok(output),
+        }
+    }
+
+    /// Calls [run](struct.Machine.html#method.run) after buffering the given
+    /// input value.
+    pub fn run_with_input(&mut self, input: i64) -> Option<i64> {
+        self.input(input);
+        self.run()
+    }
+
+    /// Constructs an iterator that calls [run](struct.Machine.html#method.run)
+    /// on `next` such that multiple output values can be easily collected.
+    ///
+    /// ```
+    /// use aoc::intcode::{Program, Machine};
+    ///
+    /// let program = Program::from("104,1,104,2,104,3,99");
+    /// let output = Machine::new(&program).run_as_iter().collect::<Vec<_>>();
+    /// assert_eq!(output, [1, 2, 3]);
+    /// ```
+    pub fn run_as_iter(&mut self) -> RunAsIter<'_, E> {
+        RunAsIter(self)
+    }
+
+    /// Calls [run](struct.Machine.html#method.run) until the program pauses,
+    /// returning the output values interpreted as an ASCII string.
+    pub fn run_as_ascii(&mut self) -> String {
+        self.run_as_iter().map(|v| v as u8 as char).collect()
+    }
+
+    /// Buffer the given input value so the next time the program is [run](struct.Machine.html#method.run)
+    /// it may read it.
+    pub fn input(&mut self, value: i64) {
+        self.state.input(value);
+    }
+
+    /// Input the given ASCII string and then input an additional '\n'.
+    pub fn input_ascii(&mut self, ascii_line: &str) {
+        if let Some(teletype) = self.teletype.as_mut() {
+            teletype.input(ascii_line);
+        }
+        for c in ascii_line.chars() {
+            self.input(c as i64);
+        }
+        self.input('\n' as i64);
+    }
+
+    /// Read a single value from the Machine's memory at the given address.
+    pub fn read(&self, address: usize) -> i64 {
+        self.state.read(address)
+    }
+
+    /// Write a single value into the Machine's memory at the given address.
+    pub fn write(&mut self, address: usize, value: i64) {
+        self.state.write(address, value);
+    }
+
+    /// The entire current memory state of this Machine.
+    pub fn memory(&self) -> &Vec<i64> {
+        self.state.memory()
+    }
+
+    /// The address of the next instruction to execute.
+    pub fn ip(&self) -> usize {
+        self.state.ip()
+    }
+
+    /// Move the instruction pointer to the given address, such that the next
+    /// time the machine is [run](struct.Machine.html#method.run) execution
+    /// continues from there.
+    pub fn set_ip(&mut self, ip: usize) {
+        self.state.set_ip(ip);
+    }
+
+    /// The base address used by relative mode parameters.
+    pub fn relative_base(&self) -> i64 {
+        self.state.relative_base()
+    }
+
+    /// Set the base address used by relative mode parameters.
+    pub fn set_relative_base(&mut self, relative_base: i64) {
+        self.state.set_relative_base(relative_base);
+    }
+
+    /// True if the machine has reached a Halt instruction (99), or has faulted.
+    pub fn is_halted(&self) -> bool {
+        self.state.is_halted()
+    }
+
+    /// The reason the machine stopped, if the program did something invalid.
+    pub fn fault(&self) -> Option<Error> {
+        self.state
+            .fault_message()
+            .map(|message| Error::Intcode(format!("{} at address {}", message, self.state.ip())))
+    }
+
+    /// True if the machine is paused awaiting [input](struct.Machine.html#method.input).
+    pub fn is_awaiting_input(&self) -> bool {
+        self.state.is_awaiting_input()
+    }
+
+    /// A copy of the Machine's entire current state, which can later be
+    /// [restored](struct.Machine.html#method.restore).
+    pub fn snapshot(&self) -> MachineState {
+        self.state.clone()
+    }
+
+    /// Return the Machine to a state previously taken by
+    /// [snapshot](struct.Machine.html#method.snapshot).
+    pub fn restore(&mut self, snapshot: MachineState) {
+        self.state = snapshot;
+    }
+
+    /// Start echoing ASCII input and output into a [Teletype](struct.Teletype.html)
+    /// log, discarding any previous log.
+    pub fn echo_teletype(&mut self) {
+        self.teletype = Some(Teletype::new());
+    }
+
+    /// The teletype log so far, if echoing was started.
+    pub fn teletype(&self) -> Option<&Teletype> {
+        self.teletype.as_ref()
+    }
+
+    /// Stop echoing and return the teletype log so far.
+    pub fn take_teletype(&mut self) -> Option<Teletype> {
+        self.teletype.take()
+    }
+
+    /// Start recording every input consumed and output produced into a
+    /// [Transcript](struct.Transcript.html), discarding any previous recording.
+    pub fn record_transcript(&mut self) {
+        self.state.transcript = Some(Transcript::new());
+    }
+
+    /// The transcript recorded so far, if recording was started.
+    pub fn transcript(&self) -> Option<&Transcript> {
+        self.state.transcript.as_ref()
+    }
+
+    /// Stop recording and return the transcript recorded so far.
+    pub fn take_transcript(&mut self) -> Option<Transcript> {
+        self.state.transcript.take()
+    }
+
+    fn record_output(&mut self, value: i64) {
+        intcode_debug!("OUTPUT({})", value);
+        self.state.record_event(Event::Output(value));
+        if let Some(teletype) = self.teletype.as_mut() {
+            teletype.output(value);
+        }
+    }
+}
+
+/// Allows easy collection of multiple output values from a [Machine](struct.Machine.html).
+///
+/// See [Machine::run_as_iter](struct.Machine.html#method.run_as_iter).
+pub struct RunAsIter<'a, E = Interpreter>(&'a mut Machine<E>);
+
+impl<E: Executor> Iterator for RunAsIter<'_, E> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        self.0.run()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_machine_run_state(program: &str, expected_final_state: &[i64]) {
+        let mut m = Machine::from_source(program);
+        m.run();
+        assert!(m.is_halted());
+        assert_eq!(m.memory(), &expected_final_state);
+    }
+
+    fn test_machine_run_output(program: &str, expected_output: i64) {
+        let output = Machine::from_source(program).run().unwrap();
+        assert_eq!(output, expected_output);
+    }
+
+    fn test_machine_run_io(program: &str, input: i64, expected_output: i64) {
+        let output = Machine::from_source(program).run_with_input(input).unwrap();
+        assert_eq!(output, expected_output);
+    }
+
+    #[test]
+    fn test_machine_run() {
+        test_machine_run_state("99", &[99]);
+        test_machine_run_state("1,0,0,0,99", &[2, 0, 0, 0, 99]);
+        test_machine_run_state("2,3,0,3,99", &[2, 3, 0, 6, 99]);
+        test_machine_run_state("2,4,4,5,99,0", &[2, 4, 4, 5, 99, 9801]);
+        test_machine_run_state("1,1,1,4,99,5,6,0,99", &[30, 1, 1, 4, 2, 5, 6, 0, 99]);
+        test_machine_run_state("1002,4,3,4,33", &[1002, 4, 3, 4, 99]);
+        test_machine_run_state("1101,100,-1,4,0", &[1101, 100, -1, 4, 99]);
+
+        let output_input = "3,0,4,0,99";
+        test_machine_run_io(output_input, 0, 0);
+        test_machine_run_io(output_input, 42, 42);
+
+        let equals_eight_pm = "3,9,8,9,10,9,4,9,99,-1,8";
+        test_machine_run_io(equals_eight_pm, 8, 1);
+        test_machine_run_io(equals_eight_pm, -10, 0);
+
+        let equals_eight_im = "3,3,1108,-1,8,3,4,3,99";
+        test_machine_run_io(equals_eight_im, 8, 1);
+        test_machine_run_io(equals_eight_im, -10, 0);
+
+        let less_than_eight_pm = "3,9,7,9,10,9,4,9,99,-1,8";
+        test_machine_run_io(less_than_eight_pm, 7, 1);
+        test_machine_run_io(less_than_eight_pm, 8, 0);
+        test_machine_run_io(less_than_eight_pm, 9, 0);
+
+        let less_than_eight_pm = "3,3,1107,-1,8,3,4,3,99";
+        test_machine_run_io(less_than_eight_pm, 7, 1);
+        test_machine_run_io(less_than_eight_pm, 8, 0);
+        test_machine_run_io(less_than_eight_pm, 9, 0);
+
+        let jump_test_zero_if_zero_pm = "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9";
+        test_machine_run_io(jump_test_zero_if_zero_pm, 0, 0);
+        test_machine_run_io(jump_test_zero_if_zero_pm, 9, 1);
+
+        let jump_test_zero_if_zero_im = "3,3,1105,-1,9,1101,0,0,12,4,12,99,1";
+        test_machine_run_io(jump_test_zero_if_zero_im, 0, 0);
+        test_machine_run_io(jump_test_zero_if_zero_im, 9, 1);
+
+        let complex_cmp_eight = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31, \
+                                 1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104, \
+                                 999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+        test_machine_run_io(complex_cmp_eight, 7, 999);
+        test_machine_run_io(complex_cmp_eight, 8, 1000);
+        test_machine_run_io(complex_cmp_eight, 9, 1001);
+
+        let quine = Program::from("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        let output = Machine::new(&quine).run_as_iter().collect();
+        assert_eq!(Program(output), quine);
+
+        test_machine_run_output("1102,34915192,34915192,7,4,7,99,0", 1_219_070_632_396_864);
+        test_machine_run_output("104,1125899906842624,99", 1_125_899_906_842_624);
+    }
+
+    #[test]
+    fn test_registers() {
+        let mut machine = Machine::from_source("109,5,204,-2,99,104,7,99");
+        assert_eq!(machine.ip(), 0);
+        assert_eq!(machine.relative_base(), 0);
+
+        assert_eq!(machine.run(), Some(-2));
+        assert_eq!(machine.ip(), 4);
+        assert_eq!(machine.relative_base(), 5);
+
+        machine.set_relative_base(2);
+        machine.set_ip(2);
+        assert_eq!(machine.run(), Some(109));
+
+        machine.set_ip(5);
+        assert_eq!(machine.run(), Some(7));
+        assert_eq!(machine.run(), None);
+        assert!(machine.is_halted());
+    }
+
+    #[test]
+    fn test_invalid_programs() {
+        assert_eq!(
+            "1,2,x,99".parse::<Program>(),
+            Err(Error::Parse("Invalid IntCode value 'x'".into()))
+        );
+
+        let mut machine = Machine::from_source("104,1,42,99");
+        assert_eq!(machine.try_run(), Ok(Some(1)));
+        assert_eq!(
+            machine.try_run(),
+            Err(Error::Intcode("Unknown opcode '42' at address 2".into()))
+        );
+        assert!(machine.is_halted());
+        assert!(!machine.is_awaiting_input());
+
+        let mut machine = Machine::from_source("304,0,99");
+        assert_eq!(
+            machine.try_run(),
+            Err(Error::Intcode(
+                "Unknown parameter mode 3 at address 0".into()
+            ))
+        );
+        assert_eq!(machine.ip(), 0);
+
+        let mut machine = Machine::from_source("11101,1,1,5,99,0");
+        assert_eq!(
+            machine.try_run(),
+            Err(Error::Intcode(
+                "Cannot write in immediate mode at address 0".into()
+            ))
+        );
+        assert_eq!(machine.read(5), 0);
+    }
+
+    #[test]
+    fn test_custom_executor() {
+        #[derive(Debug, Default)]
+        struct CountingExecutor(usize);
+
+        impl Executor for CountingExecutor {
+            fn run(&mut self, state: &mut MachineState) -> Option<i64> {
+                loop {
+                    self.0 += 1;
+                    match state.step() {
+                        Step::Continue => continue,
+                        Step::Halt => break None,
+                        Step::Output(value) => break Some(value),
+                    }
+                }
+            }
+        }
+
+        let program = Program::from("3,9,8,9,10,9,4,9,99,-1,8");
+        let mut machine = Machine::with_executor(&program, CountingExecutor::default());
+        assert_eq!(machine.run_with_input(8), Some(1));
+        assert_eq!(machine.run(), None);
+        assert!(machine.is_halted());
+        assert_eq!(machine.executor().0, 4);
+    }
+
+    #[test]
+    fn test_transcript() {
+        // Outputs each input doubled until it reads a zero.
+        let program = Program::from("3,15,1006,15,14,1002,15,2,16,4,16,1105,1,0,99");
+
+        let mut machine = Machine::new(&program);
+        machine.record_transcript();
+        machine.input(3);
+        machine.input(5);
+        assert_eq!(machine.run_as_iter().collect::<Vec<_>>(), [6, 10]);
+        machine.input(0);
+        assert_eq!(machine.run(), None);
+        assert!(machine.is_halted());
+
+        let transcript = machine.take_transcript().unwrap();
+        assert_eq!(
+            transcript.events(),
+            &[
+                Event::Input(3),
+                Event::Output(6),
+                Event::Input(5),
+                Event::Output(10),
+                Event::Input(0),
+            ]
+        );
+        assert_eq!(transcript.to_string(), "in 3\nout 6\nin 5\nout 10\nin 0\n");
+        assert_eq!(transcript.to_string().parse(), Ok(transcript.clone()));
+        assert!("in 3 junk".parse::<Transcript>().is_err());
+        assert!("out".parse::<Transcript>().is_err());
+        assert!("jump 3".parse::<Transcript>().is_err());
+
+        let mut replayed = Machine::new(&program);
+        assert_eq!(transcript.replay(&mut replayed), Ok(()));
+        assert_eq!(replayed.run(), None);
+        assert!(replayed.is_halted());
+
+        let mut tampered = program.clone();
+        tampered.write(7, 3);
+        assert!(transcript.replay(&mut Machine::new(&tampered)).is_err());
+    }
+
+    #[test]
+    fn test_teletype() {
+        // Prints "ok?" then reads a line and outputs 1000 and a partial line.
+        let mut machine = Machine::from_source("104,111,104,107,104,63,3,20,104,1000,104,33,99");
+        machine.echo_teletype();
+        machine.run_as_ascii();
+        assert_eq!(machine.teletype().unwrap().lines(), ["ok?"]);
+
+        machine.input_ascii("go");
+        machine.run_as_iter().count();
+        let teletype = machine.take_teletype().unwrap();
+        assert_eq!(teletype.lines(), ["ok?", "> go", "1000", "!"]);
+        assert!(teletype.log().starts_with("[   0.0"));
+        assert!(machine.teletype().is_none());
+    }
+
+    #[test]
+    fn test_teletype_blank_lines() {
+        // Prints "a", two blank lines and "b", then reads a line.
+        let mut machine = Machine::from_source("104,97,104,10,104,10,104,10,104,98,104,10,3,20,99");
+        machine.echo_teletype();
+        machine.run_as_ascii();
+        machine.input_ascii("");
+        machine.run();
+        let teletype = machine.take_teletype().unwrap();
+        assert_eq!(teletype.lines(), ["a", "", "", "b", "> "]);
+    }
+
+    #[test]
+    fn test_transcript_save_load() {
+        let mut machine = Machine::from_source("3,0,4,0,99");
+        machine.record_transcript();
+        machine.run_with_input(7);
+
+        let path = std::env::temp_dir().join("aoc_intcode_test_transcript.txt");
+        let transcript = machine.take_transcript().unwrap();
+        transcript.save(&path).unwrap();
+        let loaded = Transcript::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, transcript);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_serde() {
+        // Reads two numbers and outputs their sum.
+        let mut machine = Machine::from_source("3,11,3,12,1,11,12,13,4,13,99");
+        machine.record_transcript();
+        machine.input(3);
+        assert_eq!(machine.run(), None);
+
+        let json = serde_json::to_string(&machine.snapshot()).unwrap();
+        let snapshot = serde_json::from_str::<MachineState>(&json).unwrap();
+        let mut restored = Machine::from_source("99");
+        restored.restore(snapshot);
+        assert_eq!(restored.run_with_input(4), Some(7));
+
+        let program = Program::from("1,2,3");
+        let json = serde_json::to_string(&program).unwrap();
+        assert_eq!(serde_json::from_str::<Program>(&json).unwrap(), program);
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Executor, Interpreter, Machine};
+use crate::iter::AocIterExt;
+use std::collections::VecDeque;
+
+/// Where a packet sent by a machine in a [Network](struct.Network.html)
+/// should go, as decided by the routing callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Route {
+    /// Deliver these values as input to the machine with the given index.
+    To(usize, Vec<i64>),
+    /// Drop the packet.
+    Discard,
+    /// Stop running the network immediately.
+    Stop,
+}
+
+/// Why [Network::run](struct.Network.html#method.run) returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkStatus {
+    /// No machine received any input or sent any packets for a whole round.
+    Idle,
+    /// Every machine has halted.
+    Halted,
+    /// The routing callback returned [Route::Stop](enum.Route.html#variant.Stop).
+    Stopped,
+}
+
+/// Runs a group of [Machines](struct.Machine.html) cooperatively, routing the
+/// packets they output to each other.
+///
+/// Each machine is run in turn until it needs more input. Its outputs are
+/// split into packets of a fixed size and each is passed to a routing
+/// callback, along with the index of the machine that sent it.
+///
+/// ```
+/// use aoc::intcode::{Machine, Network, NetworkStatus, Route};
+///
+/// // Two machines which each add one to their input and output it.
+/// let source = "3,9,1001,9,1,9,4,9,99,0";
+/// let machines = vec![Machine::from_source(source), Machine::from_source(source)];
+/// let mut network = Network::new(machines, 1);
+/// network.send(0, &[40]);
+///
+/// let mut result = None;
+/// let status = network.run(None, |sender, packet| match sender {
+///     0 => Route::To(1, packet.to_vec()),
+///     _ => {
+///         result = Some(packet[0]);
+///         Route::Discard
+///     }
+/// });
+/// assert_eq!(status, NetworkStatus::Halted);
+/// assert_eq!(result, Some(42));
+/// ```
+#[derive(Debug)]
+pub struct Network<E = Interpreter> {
+    machines: Vec<Machine<E>>,
+    packet_size: usize,
+    inboxes: Vec<VecDeque<i64>>,
+    outboxes: Vec<Vec<i64>>,
+}
+
+impl<E: Executor> Network<E> {
+    pub fn new(machines: Vec<Machine<E>>, packet_size: usize) -> Network<E> {
+        assert!(packet_size > 0);
+        let num_machines = machines.len();
+        Network {
+            machines,
+            packet_size,
+            inboxes: vec![VecDeque::new(); num_machines],
+            outboxes: vec![Vec::new(); num_machines],
+        }
+    }
+
+    pub fn machines(&self) -> &[Machine<E>] {
+        &self.machines
+    }
+
+    pub fn machine_mut(&mut self, index: usize) -> &mut Machine<E> {
+        &mut self.machines[index]
+    }
+
+    /// Queue values to be input to the machine with the given index the next
+    /// time it is run.
+    pub fn send(&mut self, index: usize, values: &[i64]) {
+        self.inboxes[index].extend(values);
+    }
+
+    /// Run the machines in turn until the network is idle, every machine has
+    /// halted or the routing callback stops it.
+    ///
+    /// Machines with no queued input are given `idle_input` if it is Some, or
+    /// skipped otherwise.
+    pub fn run<R>(&mut self, idle_input: Option<i64>, mut route: R) -> NetworkStatus
+    where
+        R: FnMut(usize, &[i64]) -> Route,
+    {
+        loop {
+            if self.machines.iter().all(|m| m.is_halted()) {
+                return NetworkStatus::Halted;
+            }
+
+            let mut is_idle = true;
+            for index in 0..self.machines.len() {
+                let machine = &mut self.machines[index];
+                if machine.is_halted() {
+                    continue;
+                }
+
+                let inbox = &mut self.inboxes[index];
+                if inbox.is_empty() {
+                    match idle_input {
+                        Some(value) => machine.input(value),
+                        None => continue,
+                    }
+                } else {
+                    is_idle = false;
+                    for value in inbox.drain(..) {
+                        machine.input(value);
+                    }
+                }
+
+                let outbox = &mut self.outboxes[index];
+                outbox.extend(machine.run_as_iter());
+                if outbox.len() < self.packet_size {
+                    continue;
+                }
+
+                is_idle = false;
+                let num_complete = outbox.len() - (outbox.len() % self.packet_size);
+                let packets = outbox.drain(..num_complete);
+                for packet in packets.chunks_exact_vec(self.packet_size) {
+                    match route(index, &packet) {
+                        Route::To(dest, values) => self.inboxes[dest].extend(values),
+                        Route::Discard => (),
+                        Route::Stop => return NetworkStatus::Stopped,
+                    }
+                }
+            }
+
+            if is_idle {
+                return NetworkStatus::Idle;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_network_idle() {
+        // Outputs each input that isn't -1 doubled, forever.
+        let source = "3,18,1008,18,-1,19,1005,19,0,1002,18,2,18,4,18,1105,1,0,0,0";
+        let machines = (0..3).map(|_| Machine::from_source(source)).collect();
+        let mut network = Network::new(machines, 1);
+        network.send(0, &[3]);
+
+        let mut received = Vec::new();
+        let status = network.run(Some(-1), |sender, packet| {
+            if sender < 2 {
+                Route::To(sender + 1, packet.to_vec())
+            } else {
+                received.push(packet[0]);
+                Route::Discard
+            }
+        });
+        assert_eq!(status, NetworkStatus::Idle);
+        assert_eq!(received, [24]);
+    }
+}
@@ -0,0 +1,366 @@
+use super::{Opcode, ParameterMode, Program};
+use std::collections::{BTreeMap, HashSet};
+
+// A decoded instruction found by following the program's control flow.
+#[derive(Debug, Clone, Copy)]
+struct Decoded {
+    opcode: Opcode,
+    modes: [ParameterMode; 3],
+    length: usize,
+}
+
+impl Decoded {
+    fn new(code: &[i64], address: usize) -> Option<Decoded> {
+        let value = code[address];
+        let opcode = Opcode::try_new(value)?;
+        let num_params = opcode.num_params();
+        let length = num_params + 1;
+        if address + length > code.len() {
+            return None;
+        }
+
+        let mut modes = [ParameterMode::Position; 3];
+        for (n, mode) in modes.iter_mut().enumerate().take(num_params) {
+            *mode = ParameterMode::try_new(value, n)?;
+        }
+
+        Some(Decoded {
+            opcode,
+            modes,
+            length,
+        })
+    }
+
+    // The index of the parameter this instruction writes to, if any.
+    fn write_param(&self) -> Option<usize> {
+        self.opcode.write_param()
+    }
+
+    // The indices of the parameters this instruction reads from.
+    fn read_params(&self) -> impl Iterator<Item = usize> {
+        let num_reads = match self.opcode {
+            Opcode::Add | Opcode::Mul | Opcode::LessThan | Opcode::Equals => 2,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Output | Opcode::AdjustRelativeBase => 1,
+            Opcode::Input | Opcode::Halt => 0,
+        };
+        0..num_reads
+    }
+
+    // The address written to in position mode, if any.
+    fn position_write(&self, code: &[i64], address: usize) -> Option<usize> {
+        let param = self.write_param()?;
+        if self.modes[param] == ParameterMode::Position {
+            Some(code[address + param + 1] as usize)
+        } else {
+            None
+        }
+    }
+
+    fn reads_position(&self, code: &[i64], address: usize, target: usize) -> bool {
+        self.read_params().any(|param| {
+            self.modes[param] == ParameterMode::Position
+                && code[address + param + 1] as usize == target
+        })
+    }
+}
+
+pub(super) fn optimize(program: &Program) -> Program {
+    let mut code = program.0.clone();
+
+    // Propagating constants can reveal new jump targets, so repeat until
+    // nothing more changes.
+    while propagate_constants(&mut code) {}
+
+    fold_constants(&mut code);
+    eliminate_dead_stores(&mut code);
+
+    Program(code)
+}
+
+// Decode every instruction reachable from address 0, following fall-through
+// and jumps to immediate addresses.
+fn decode_reachable(code: &[i64]) -> BTreeMap<usize, Decoded> {
+    let mut decoded = BTreeMap::new();
+    let mut open = vec![0];
+
+    while let Some(address) = open.pop() {
+        if address >= code.len() || decoded.contains_key(&address) {
+            continue;
+        }
+
+        let instruction = match Decoded::new(code, address) {
+            Some(instruction) => instruction,
+            None => continue,
+        };
+        decoded.insert(address, instruction);
+
+        // Jumps with constant conditions are still assumed to fall through,
+        // as the condition may be modified and the following instructions
+        // are usually where a called function returns to.
+        match instruction.opcode {
+            Opcode::Halt => (),
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let target = code[address + 2];
+                if instruction.modes[1] == ParameterMode::Immediate && target >= 0 {
+                    open.push(target as usize);
+                }
+                open.push(address + instruction.length);
+            }
+            _ => open.push(address + instruction.length),
+        }
+    }
+
+    decoded
+}
+
+struct Analysis {
+    decoded: BTreeMap<usize, Decoded>,
+    code_addresses: HashSet<usize>,
+    written: HashSet<usize>,
+    // True if some writes go to addresses that can't be known in advance.
+    // These are assumed to only reach data rather than reachable code, as is
+    // the case for relative writes to the stack and computed writes to
+    // arrays in the puzzle inputs, but could reach any data.
+    unknown_writes: bool,
+}
+
+impl Analysis {
+    // None if any decoded instructions overlap, in which case the program
+    // is too unusual to optimize safely.
+    fn new(code: &[i64]) -> Option<Analysis> {
+        let decoded = decode_reachable(code);
+
+        let mut code_addresses = HashSet::new();
+        for (&address, instruction) in decoded.iter() {
+            for a in address..(address + instruction.length) {
+                if !code_addresses.insert(a) {
+                    return None;
+                }
+            }
+        }
+
+        let written = decoded
+            .iter()
+            .filter_map(|(&address, instruction)| instruction.position_write(code, address))
+            .collect();
+
+        let mut analysis = Analysis {
+            decoded,
+            code_addresses,
+            written,
+            unknown_writes: false,
+        };
+        analysis.unknown_writes = analysis.decoded.iter().any(|(&address, instruction)| {
+            instruction.write_param().is_some_and(|param| {
+                instruction.modes[param] != ParameterMode::Position
+                    || analysis.written.contains(&(address + param + 1))
+            })
+        });
+        Some(analysis)
+    }
+
+    // True if the program may modify this instruction as it runs.
+    fn is_modifiable(&self, address: usize, instruction: &Decoded) -> bool {
+        (address..(address + instruction.length)).any(|a| self.written.contains(&a))
+    }
+
+    fn stable_instructions(&self) -> impl Iterator<Item = (usize, Decoded)> + '_ {
+        self.decoded
+            .iter()
+            .map(|(&address, &instruction)| (address, instruction))
+            .filter(move |(address, instruction)| !self.is_modifiable(*address, instruction))
+    }
+}
+
+fn set_immediate_mode(code: &mut [i64], address: usize, param: usize) {
+    code[address] += 10_i64.pow(param as u32 + 2);
+}
+
+// Replace position mode reads of memory which is never written to with
+// immediate values. Returns true if anything changed. Any write to an unknown
+// address could reach the memory being read, so these prevent propagation.
+fn propagate_constants(code: &mut [i64]) -> bool {
+    let analysis = match Analysis::new(code) {
+        Some(analysis) if !analysis.unknown_writes => analysis,
+        _ => return false,
+    };
+
+    let mut changed = false;
+    for (address, instruction) in analysis.stable_instructions() {
+        for param in instruction.read_params() {
+            if instruction.modes[param] != ParameterMode::Position {
+                continue;
+            }
+
+            let source = code[address + param + 1] as usize;
+            let is_constant = source < code.len()
+                && !analysis.written.contains(&source)
+                && !analysis.code_addresses.contains(&source);
+
+            if is_constant {
+                code[address + param + 1] = code[source];
+                set_immediate_mode(code, address, param);
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+// Evaluate arithmetic and comparisons with only immediate inputs, replacing
+// them with an addition of the result and zero.
+fn fold_constants(code: &mut [i64]) {
+    let analysis = match Analysis::new(code) {
+        Some(analysis) => analysis,
+        None => return,
+    };
+
+    for (address, instruction) in analysis.stable_instructions() {
+        let func: fn(i64, i64) -> i64 = match instruction.opcode {
+            Opcode::Add => |a, b| a + b,
+            Opcode::Mul => |a, b| a * b,
+            Opcode::LessThan => |a, b| (a < b) as i64,
+            Opcode::Equals => |a, b| (a == b) as i64,
+            _ => continue,
+        };
+
+        let all_immediate = instruction.modes[..2]
+            .iter()
+            .all(|&m| m == ParameterMode::Immediate);
+
+        if all_immediate {
+            let result = func(code[address + 1], code[address + 2]);
+            let write_mode = code[address] / 10_000;
+            code[address] = 1101 + (write_mode * 10_000);
+            code[address + 1] = result;
+            code[address + 2] = 0;
+        }
+    }
+}
+
+// Remove a write which is immediately overwritten by the next instruction
+// without being read, by jumping over it instead.
+fn eliminate_dead_stores(code: &mut [i64]) {
+    let analysis = match Analysis::new(code) {
+        Some(analysis) => analysis,
+        None => return,
+    };
+
+    let dead_stores: Vec<_> = analysis
+        .stable_instructions()
+        .filter(|(_, instruction)| instruction.opcode != Opcode::Input)
+        .filter_map(|(address, instruction)| {
+            let target = instruction.position_write(code, address)?;
+            if instruction.reads_position(code, address, target) {
+                return None;
+            }
+
+            let next_address = address + instruction.length;
+            let next = analysis.decoded.get(&next_address)?;
+            let overwritten = !analysis.is_modifiable(next_address, next)
+                && next.position_write(code, next_address) == Some(target)
+                && !next.reads_position(code, next_address, target);
+
+            if overwritten {
+                Some((address, next_address))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    for (address, next_address) in dead_stores {
+        code[address] = 1106;
+        code[address + 1] = 0;
+        code[address + 2] = next_address as i64;
+        code[address + 3] = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::intcode::{Machine, Program};
+
+    fn check_equivalent(source: &str, inputs: &[i64]) {
+        let program = Program::from(source);
+        let optimized = program.optimize();
+
+        for &input in inputs {
+            let expected = Machine::with_input(&program, input)
+                .run_as_iter()
+                .collect::<Vec<_>>();
+            let actual = Machine::with_input(&optimized, input)
+                .run_as_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "{} with input {}", source, input);
+        }
+    }
+
+    #[test]
+    fn test_constant_folding() {
+        let program = Program::from("1,8,9,10,4,10,99,0,20,22,0");
+        assert_eq!(
+            program.optimize(),
+            Program::from("1101,42,0,10,4,10,99,0,20,22,0")
+        );
+        check_equivalent("1,8,9,10,4,10,99,0,20,22,0", &[0]);
+    }
+
+    #[test]
+    fn test_dead_store_elimination() {
+        let program = Program::from("1101,1,2,11,1101,3,4,11,4,11,99,0");
+        assert_eq!(
+            program.optimize(),
+            Program::from("1106,0,4,0,1101,7,0,11,4,11,99,0")
+        );
+        check_equivalent("1101,1,2,11,1101,3,4,11,4,11,99,0", &[0]);
+    }
+
+    #[test]
+    fn test_self_modifying_code_is_untouched() {
+        // The first instruction overwrites the operands of the second.
+        let source = "1101,7,0,6,1,0,0,0,4,0,99";
+        assert_eq!(Program::from(source).optimize(), Program::from(source));
+        check_equivalent(source, &[0]);
+    }
+
+    #[test]
+    fn test_relative_writes_prevent_propagation() {
+        // The relative mode write overwrites the value output afterwards.
+        let source = "109,11,21101,5,0,0,4,11,99,0,0,7";
+        assert_eq!(Program::from(source).optimize(), Program::from(source));
+        check_equivalent(source, &[0]);
+    }
+
+    #[test]
+    fn test_relative_writes_allow_folding() {
+        let source = "109,20,21102,6,7,0,204,0,99";
+        assert_eq!(
+            Program::from(source).optimize(),
+            Program::from("109,20,21101,42,0,0,204,0,99")
+        );
+        check_equivalent(source, &[0]);
+    }
+
+    #[test]
+    fn test_io_equivalence() {
+        let inputs = [-10, 0, 7, 8, 9, 42];
+        check_equivalent("3,9,8,9,10,9,4,9,99,-1,8", &inputs);
+        check_equivalent("3,3,1108,-1,8,3,4,3,99", &inputs);
+        check_equivalent("3,9,7,9,10,9,4,9,99,-1,8", &inputs);
+        check_equivalent("3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9", &inputs);
+        check_equivalent("3,3,1105,-1,9,1101,0,0,12,4,12,99,1", &inputs);
+        check_equivalent(
+            "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31, \
+             1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104, \
+             999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+            &inputs,
+        );
+        check_equivalent(
+            "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99",
+            &[0],
+        );
+        check_equivalent("1102,34915192,34915192,7,4,7,99,0", &[0]);
+    }
+}
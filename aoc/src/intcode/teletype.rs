@@ -0,0 +1,108 @@
+use crate::Error;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// A readable log of an ASCII [Machine](struct.Machine.html) session, with
+/// each line of output and each line of input echoed in the order they
+/// happened, prefixed by the time since the log was started.
+///
+/// Input lines are marked with `> `, and any output values outside the ASCII
+/// range are logged as numbers on their own line. Blank lines of output are
+/// kept, so the log shows exactly what the program printed.
+///
+/// ```
+/// use aoc::intcode::Machine;
+///
+/// let mut machine = Machine::from_source("104,72,104,105,104,10,3,20,99");
+/// machine.echo_teletype();
+/// machine.run_as_ascii();
+/// machine.input_ascii("yo");
+/// machine.run();
+///
+/// assert_eq!(machine.teletype().unwrap().lines(), ["Hi", "> yo"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Teletype {
+    start: Instant,
+    log: String,
+    line: String,
+    line_time: Duration,
+}
+
+impl Teletype {
+    pub fn new() -> Teletype {
+        Teletype {
+            start: Instant::now(),
+            log: String::new(),
+            line: String::new(),
+            line_time: Duration::default(),
+        }
+    }
+
+    /// The log so far, including any incomplete line of output.
+    pub fn log(&self) -> String {
+        let mut log = self.log.clone();
+        if !self.line.is_empty() {
+            log += &Teletype::format_line(self.line_time, &self.line);
+        }
+        log
+    }
+
+    /// The lines of the log so far without their timestamps.
+    pub fn lines(&self) -> Vec<String> {
+        self.log()
+            .lines()
+            .map(|line| line.split_once("] ").map_or(line, |(_, text)| text))
+            .map(String::from)
+            .collect()
+    }
+
+    /// Write the log to a file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        fs::write(path, self.log())
+            .map_err(|e| Error::io(format!("Can't write '{}'", path.display()), e))
+    }
+
+    pub(super) fn output(&mut self, value: i64) {
+        if self.line.is_empty() {
+            self.line_time = self.start.elapsed();
+        }
+
+        match value {
+            10 => self.end_line(),
+            0..=127 => self.line.push(value as u8 as char),
+            _ => {
+                if !self.line.is_empty() {
+                    self.end_line();
+                }
+                self.line = value.to_string();
+                self.line_time = self.start.elapsed();
+                self.end_line();
+            }
+        }
+    }
+
+    pub(super) fn input(&mut self, line: &str) {
+        if !self.line.is_empty() {
+            self.end_line();
+        }
+        self.log += &Teletype::format_line(self.start.elapsed(), &format!("> {}", line));
+    }
+
+    fn end_line(&mut self) {
+        self.log += &Teletype::format_line(self.line_time, &self.line);
+        self.line.clear();
+    }
+
+    fn format_line(time: Duration, line: &str) -> String {
+        format!("[{:8.3}s] {}\n", time.as_secs_f64(), line)
+    }
+}
+
+impl Default for Teletype {
+    fn default() -> Teletype {
+        Teletype::new()
+    }
+}
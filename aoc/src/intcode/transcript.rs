@@ -0,0 +1,161 @@
+use super::Machine;
+use crate::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// A single value passing into or out of a [Machine](struct.Machine.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    Input(i64),
+    Output(i64),
+}
+
+/// The full sequence of inputs consumed and outputs produced by a
+/// [Machine](struct.Machine.html), in the order they happened.
+///
+/// Transcripts can be saved to and loaded from a file, and replayed on a fresh
+/// Machine to reproduce a run deterministically.
+///
+/// ```
+/// use aoc::intcode::{Machine, Transcript};
+///
+/// let mut machine = Machine::from_source("3,9,1002,9,2,9,4,9,99,0");
+/// machine.record_transcript();
+/// machine.run_with_input(21);
+/// let transcript = machine.take_transcript().unwrap();
+/// assert_eq!(transcript.outputs().collect::<Vec<_>>(), [42]);
+///
+/// let mut replayed = Machine::from_source("3,9,1002,9,2,9,4,9,99,0");
+/// assert_eq!(transcript.replay(&mut replayed), Ok(()));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transcript(Vec<Event>);
+
+impl Transcript {
+    pub fn new() -> Transcript {
+        Transcript::default()
+    }
+
+    /// All recorded events, in order.
+    pub fn events(&self) -> &[Event] {
+        &self.0
+    }
+
+    /// All recorded input values, in order.
+    pub fn inputs(&self) -> impl Iterator<Item = i64> + '_ {
+        self.0.iter().filter_map(|e| match e {
+            Event::Input(value) => Some(*value),
+            Event::Output(_) => None,
+        })
+    }
+
+    /// All recorded output values, in order.
+    pub fn outputs(&self) -> impl Iterator<Item = i64> + '_ {
+        self.0.iter().filter_map(|e| match e {
+            Event::Input(_) => None,
+            Event::Output(value) => Some(*value),
+        })
+    }
+
+    /// Feed the recorded inputs into the given Machine in the order they were
+    /// originally consumed, checking that every recorded output is reproduced.
+    ///
+    /// Any inputs recorded after the final output are left buffered, to be
+    /// consumed the next time the Machine is run.
+    pub fn replay(&self, machine: &mut Machine) -> Result<(), Error> {
+        for (n, event) in self.0.iter().enumerate() {
+            match *event {
+                Event::Input(value) => machine.input(value),
+                Event::Output(expected) => match machine.run() {
+                    Some(actual) if actual == expected => (),
+                    actual => {
+                        return Err(Error::Intcode(format!(
+                            "Event {}: expected output {}, found {:?}",
+                            n, expected, actual
+                        )))
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Write this transcript to a file, one event per line.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string())
+            .map_err(|e| Error::io(format!("Can't write '{}'", path.display()), e))
+    }
+
+    /// Read a transcript previously written by [save](#method.save).
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Transcript, Error> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|e| Error::io(format!("Can't read '{}'", path.display()), e))?;
+        contents.parse()
+    }
+
+    pub(super) fn record(&mut self, event: Event) {
+        self.0.push(event);
+    }
+}
+
+impl fmt::Display for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for event in &self.0 {
+            match event {
+                Event::Input(value) => writeln!(f, "in {}", value)?,
+                Event::Output(value) => writeln!(f, "out {}", value)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Transcript {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut events = Vec::new();
+        for (n, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let kind = words.next().unwrap();
+            let value = words
+                .next()
+                .and_then(|v| v.parse::<i64>().ok())
+                .ok_or_else(|| {
+                    Error::Parse(format!("Line {}: missing value in '{}'", n + 1, line))
+                })?;
+            if words.next().is_some() {
+                return Err(Error::Parse(format!(
+                    "Line {}: unexpected text after the value in '{}'",
+                    n + 1,
+                    line
+                )));
+            }
+
+            let event = match kind {
+                "in" => Event::Input(value),
+                "out" => Event::Output(value),
+                _ => {
+                    return Err(Error::Parse(format!(
+                        "Line {}: unknown event '{}'",
+                        n + 1,
+                        kind
+                    )))
+                }
+            };
+            events.push(event);
+        }
+        Ok(Transcript(events))
+    }
+}
@@ -0,0 +1,97 @@
+//! Compact ids for names which are used as keys.
+
+use std::collections::HashMap;
+
+/// Maps names to small integer ids and back again, so that name-keyed data
+/// doesn't need to borrow from the input and hashes quickly.
+///
+/// Ids are handed out in order starting from zero, so they can also be used
+/// to index into a `Vec`.
+///
+/// ```
+/// use aoc::interner::Interner;
+///
+/// let mut names = Interner::new();
+/// let com = names.intern("COM");
+/// let you = names.intern("YOU");
+/// assert_eq!((com, you), (0, 1));
+/// assert_eq!(names.intern("COM"), com);
+/// assert_eq!(names.get("YOU"), Some(you));
+/// assert_eq!(names.get("SAN"), None);
+/// assert_eq!(names.name(you), "YOU");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Interner {
+    ids: HashMap<String, u32>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    /// The id of `name`, allocating a new one if it hasn't been seen before.
+    pub fn intern(&mut self, name: &str) -> u32 {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len() as u32;
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        id
+    }
+
+    /// The id of `name`, if it has been interned.
+    pub fn get(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    /// The name with the given id, which must have come from this Interner.
+    pub fn name(&self, id: u32) -> &str {
+        &self.names[id as usize]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Every id and its name, in the order they were interned.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> + '_ {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| (id as u32, name.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let mut names = Interner::new();
+        assert!(names.is_empty());
+        let ids = ["ORE", "FUEL", "A", "ORE", "B", "A"]
+            .iter()
+            .map(|n| names.intern(n))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, [0, 1, 2, 0, 3, 2]);
+        assert_eq!(names.len(), 4);
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            [(0, "ORE"), (1, "FUEL"), (2, "A"), (3, "B")]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_unknown_id() {
+        Interner::new().name(0);
+    }
+}
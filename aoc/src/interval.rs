@@ -0,0 +1,240 @@
+//! Half-open ranges of integers, and sets of them.
+
+use std::cmp::{max, min};
+use std::fmt;
+
+/// The integers from `start` up to but not including `end`. Any interval
+/// with `end <= start` is empty.
+///
+/// ```
+/// use aoc::interval::Interval;
+///
+/// let a = Interval::new(0, 10);
+/// let b = Interval::new(5, 15);
+/// assert_eq!(a.intersect(b), Interval::new(5, 10));
+/// assert_eq!(a.hull(b), Interval::new(0, 15));
+/// assert!(a.contains(9) && !a.contains(10));
+/// assert_eq!(a.len(), 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            (self.end - self.start) as u64
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether the intervals share at least one value.
+    pub fn overlaps(&self, other: Interval) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// The values in both intervals, which may be empty.
+    pub fn intersect(&self, other: Interval) -> Interval {
+        Interval::new(max(self.start, other.start), min(self.end, other.end))
+    }
+
+    /// The smallest interval containing both, including any gap between them.
+    pub fn hull(&self, other: Interval) -> Interval {
+        if self.is_empty() {
+            other
+        } else if other.is_empty() {
+            *self
+        } else {
+            Interval::new(min(self.start, other.start), max(self.end, other.end))
+        }
+    }
+
+    pub fn iter(&self) -> std::ops::Range<i64> {
+        self.start..self.end
+    }
+}
+
+impl From<std::ops::Range<i64>> for Interval {
+    fn from(range: std::ops::Range<i64>) -> Interval {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as the sorted list of disjoint, non-adjacent
+/// intervals that cover it.
+///
+/// ```
+/// use aoc::interval::{Interval, IntervalSet};
+///
+/// let mut set = IntervalSet::new();
+/// set.insert(Interval::new(0, 5));
+/// set.insert(Interval::new(10, 15));
+/// set.insert(Interval::new(5, 7));
+/// assert_eq!(set.intervals(), [Interval::new(0, 7), Interval::new(10, 15)]);
+/// assert_eq!(set.coverage(), 12);
+/// assert_eq!(set.coverage_within(Interval::new(6, 12)), 3);
+///
+/// set.remove(Interval::new(3, 12));
+/// assert_eq!(set.intervals(), [Interval::new(0, 3), Interval::new(12, 15)]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Add every value in `interval`, merging it with any intervals it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |acc, &i| acc.hull(i));
+        self.intervals.splice(first..last, std::iter::once(merged));
+    }
+
+    /// Remove every value in `interval`, splitting any interval it falls
+    /// inside of.
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|i| i.end <= interval.start);
+        let last = self.intervals.partition_point(|i| i.start < interval.end);
+        let remaining = self.intervals[first..last]
+            .iter()
+            .flat_map(|&i| {
+                let before = Interval::new(i.start, interval.start);
+                let after = Interval::new(interval.end, i.end);
+                vec![before, after]
+            })
+            .filter(|i| !i.is_empty())
+            .collect::<Vec<_>>();
+        self.intervals.splice(first..last, remaining);
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    /// The values in both this set and `interval`.
+    pub fn intersect(&self, interval: Interval) -> IntervalSet {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|i| i.intersect(interval))
+                .filter(|i| !i.is_empty())
+                .collect(),
+        }
+    }
+
+    /// The number of values in the set.
+    pub fn coverage(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    /// The number of values in the set that are also in `interval`.
+    pub fn coverage_within(&self, interval: Interval) -> u64 {
+        self.intervals
+            .iter()
+            .map(|i| i.intersect(interval).len())
+            .sum()
+    }
+}
+
+impl std::iter::FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_empty() {
+        let empty = Interval::new(5, 2);
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), 0);
+        assert!(!empty.contains(3));
+        assert!(!Interval::new(0, 5).overlaps(Interval::new(5, 10)));
+        assert_eq!(empty.hull(Interval::new(7, 8)), Interval::new(7, 8));
+        assert_eq!(Interval::from(-3..3).iter().count(), 6);
+    }
+
+    #[test]
+    fn test_interval_set_merging() {
+        let mut set: IntervalSet = vec![(8, 9), (0, 2), (4, 6), (12, 14)]
+            .into_iter()
+            .map(|(s, e)| Interval::new(s, e))
+            .collect();
+        assert_eq!(set.intervals().len(), 4);
+        assert_eq!(set.coverage(), 7);
+
+        set.insert(Interval::new(1, 9));
+        assert_eq!(
+            set.intervals(),
+            [Interval::new(0, 9), Interval::new(12, 14)]
+        );
+        assert!(set.contains(0) && set.contains(8) && set.contains(13));
+        assert!(!set.contains(9) && !set.contains(-1) && !set.contains(14));
+
+        set.remove(Interval::new(-5, 1));
+        set.remove(Interval::new(8, 13));
+        assert_eq!(
+            set.intervals(),
+            [Interval::new(1, 8), Interval::new(13, 14)]
+        );
+        assert_eq!(
+            set.intersect(Interval::new(5, 20)).intervals(),
+            [Interval::new(5, 8), Interval::new(13, 14)]
+        );
+
+        set.remove(Interval::new(0, 20));
+        assert!(set.is_empty());
+    }
+}
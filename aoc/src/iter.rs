@@ -0,0 +1,144 @@
+//! Iterator adaptors for patterns that come up repeatedly in the puzzles.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Extra methods for every iterator.
+///
+/// Itertools has a `counts` method too, so call it as
+/// `AocIterExt::counts(iter)` in modules which use both.
+pub trait AocIterExt: Iterator + Sized {
+    /// Collect the items into `Vec`s of exactly `size` items, dropping any
+    /// left over at the end.
+    ///
+    /// ```
+    /// use aoc::iter::AocIterExt;
+    ///
+    /// let chunks = (1..=7).chunks_exact_vec(3).collect::<Vec<_>>();
+    /// assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6]]);
+    /// ```
+    fn chunks_exact_vec(self, size: usize) -> ChunksExactVec<Self> {
+        assert!(size > 0, "Chunks must not be empty");
+        ChunksExactVec { iter: self, size }
+    }
+
+    /// Take items up to and including the first one which matches
+    /// `predicate`, unlike `take_while` which drops it.
+    ///
+    /// ```
+    /// use aoc::iter::AocIterExt;
+    ///
+    /// let taken = [1, 5, 2, 8, 3].iter().take_until(|&&x| x > 4).collect::<Vec<_>>();
+    /// assert_eq!(taken, [&1, &5]);
+    /// ```
+    fn take_until<P>(self, predicate: P) -> TakeUntil<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        TakeUntil {
+            iter: self,
+            predicate,
+            done: false,
+        }
+    }
+
+    /// How many times each distinct item appears.
+    ///
+    /// ```
+    /// use aoc::iter::AocIterExt;
+    ///
+    /// let counts = "abracadabra".chars().counts();
+    /// assert_eq!(counts[&'a'], 5);
+    /// assert_eq!(counts[&'c'], 1);
+    /// assert_eq!(counts.get(&'z'), None);
+    /// ```
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        for item in self {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+impl<I: Iterator> AocIterExt for I {}
+
+/// The iterator returned by [chunks_exact_vec](trait.AocIterExt.html#method.chunks_exact_vec).
+#[derive(Debug, Clone)]
+pub struct ChunksExactVec<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I: Iterator> Iterator for ChunksExactVec<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let chunk = self.iter.by_ref().take(self.size).collect::<Vec<_>>();
+        if chunk.len() == self.size {
+            Some(chunk)
+        } else {
+            None
+        }
+    }
+}
+
+/// The iterator returned by [take_until](trait.AocIterExt.html#method.take_until).
+#[derive(Debug, Clone)]
+pub struct TakeUntil<I, P> {
+    iter: I,
+    predicate: P,
+    done: bool,
+}
+
+impl<I, P> Iterator for TakeUntil<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next()?;
+        self.done = (self.predicate)(&item);
+        Some(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunks_exact_vec() {
+        assert_eq!((0..6).chunks_exact_vec(2).count(), 3);
+        assert_eq!((0..2).chunks_exact_vec(3).next(), None);
+        assert_eq!(
+            (0..4).chunks_exact_vec(1).collect::<Vec<_>>(),
+            [[0], [1], [2], [3]]
+        );
+    }
+
+    #[test]
+    fn test_take_until() {
+        let none_match = (0..4).take_until(|&x| x > 10).collect::<Vec<_>>();
+        assert_eq!(none_match, [0, 1, 2, 3]);
+        let first_matches = (0..4).take_until(|_| true).collect::<Vec<_>>();
+        assert_eq!(first_matches, [0]);
+        assert_eq!((0..0).take_until(|_| true).next(), None);
+    }
+
+    #[test]
+    fn test_counts() {
+        assert!(std::iter::empty::<u8>().counts().is_empty());
+        let counts = [2, 0, 2, 2, 1].iter().copied().counts();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&2], 3);
+    }
+}
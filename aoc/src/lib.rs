@@ -1,4 +1,25 @@
+mod error;
+pub use error::{Error, Result};
+
+pub mod answers;
+pub mod ascii;
+pub mod bitset;
+pub mod digits;
+pub mod disjoint_set;
+pub mod generate;
 pub mod geom;
 pub mod graph;
+pub mod input;
 pub mod intcode;
+pub mod interner;
+pub mod interval;
+pub mod iter;
+pub mod math;
+pub mod memo;
 pub mod ocr;
+pub mod profiling;
+pub mod render;
+pub mod report;
+pub mod search;
+pub mod solution;
+pub mod tui;
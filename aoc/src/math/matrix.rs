@@ -0,0 +1,211 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Index, IndexMut, Mul};
+
+/// A dense matrix of integers, stored in row-major order.
+///
+/// Raising a matrix to a power with [pow_mod](#method.pow_mod) evaluates a
+/// linear recurrence many steps ahead. For example the Fibonacci numbers:
+///
+/// ```
+/// use aoc::math::Matrix;
+///
+/// let step = Matrix::from_rows(&[&[1, 1], &[1, 0]]);
+/// let nth = step.pow_mod(90, 1_000_000_007);
+/// assert_eq!(nth[(0, 1)], 2_880_067_194_370_816_120 % 1_000_000_007);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    values: Vec<i64>,
+}
+
+impl Matrix {
+    pub fn new(rows: usize, cols: usize, values: Vec<i64>) -> Matrix {
+        assert_eq!(values.len(), rows * cols, "Wrong number of values");
+        Matrix { rows, cols, values }
+    }
+
+    pub fn zero(rows: usize, cols: usize) -> Matrix {
+        Matrix::new(rows, cols, vec![0; rows * cols])
+    }
+
+    pub fn identity(size: usize) -> Matrix {
+        let mut matrix = Matrix::zero(size, size);
+        for i in 0..size {
+            matrix[(i, i)] = 1;
+        }
+        matrix
+    }
+
+    /// Panics if the rows aren't all the same length.
+    pub fn from_rows(rows: &[&[i64]]) -> Matrix {
+        let cols = rows.first().map_or(0, |r| r.len());
+        assert!(rows.iter().all(|r| r.len() == cols), "Ragged rows");
+        let values = rows.iter().flat_map(|r| r.iter().copied()).collect();
+        Matrix::new(rows.len(), cols, values)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row(&self, row: usize) -> &[i64] {
+        &self.values[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Matrix {
+        let mut result = Matrix::zero(self.cols, self.rows);
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                result[(c, r)] = self[(r, c)];
+            }
+        }
+        result
+    }
+
+    /// The product of this matrix and `other` with every entry reduced into
+    /// `0..modulo`. Intermediate values are held in an `i128` so this can't
+    /// overflow.
+    pub fn mul_mod(&self, other: &Matrix, modulo: u64) -> Matrix {
+        self.multiply(other, |a, b, acc| {
+            (acc + i128::from(a) * i128::from(b)).rem_euclid(i128::from(modulo))
+        })
+    }
+
+    /// This square matrix raised to the power `exp`, by repeated squaring,
+    /// with every entry reduced into `0..modulo`.
+    pub fn pow_mod(&self, mut exp: u64, modulo: u64) -> Matrix {
+        assert_eq!(self.rows, self.cols, "Only square matrices have powers");
+        let mut base = self.reduce(modulo);
+        let mut result = Matrix::identity(self.rows).reduce(modulo);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul_mod(&base, modulo);
+            }
+            base = base.mul_mod(&base, modulo);
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn reduce(&self, modulo: u64) -> Matrix {
+        let modulo = i128::from(modulo);
+        let values = self
+            .values
+            .iter()
+            .map(|&v| i128::from(v).rem_euclid(modulo) as i64)
+            .collect();
+        Matrix::new(self.rows, self.cols, values)
+    }
+
+    /// This square matrix raised to the power `exp`, which panics on
+    /// overflow.
+    pub fn pow(&self, mut exp: u32) -> Matrix {
+        assert_eq!(self.rows, self.cols, "Only square matrices have powers");
+        let mut base = self.clone();
+        let mut result = Matrix::identity(self.rows);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = &result * &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    fn multiply<F>(&self, other: &Matrix, mut accumulate: F) -> Matrix
+    where
+        F: FnMut(i64, i64, i128) -> i128,
+    {
+        assert_eq!(self.cols, other.rows, "Mismatched matrix dimensions");
+        let mut result = Matrix::zero(self.rows, other.cols);
+        for r in 0..self.rows {
+            for c in 0..other.cols {
+                let sum =
+                    (0..self.cols).fold(0, |acc, i| accumulate(self[(r, i)], other[(i, c)], acc));
+                result[(r, c)] = i64::try_from(sum).expect("Matrix entry overflowed");
+            }
+        }
+        result
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = i64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &i64 {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        &self.values[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut i64 {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds");
+        &mut self.values[row * self.cols + col]
+    }
+}
+
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    fn mul(self, other: &Matrix) -> Matrix {
+        self.multiply(other, |a, b, acc| {
+            acc.checked_add(i128::from(a) * i128::from(b))
+                .expect("Matrix entry overflowed")
+        })
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for r in 0..self.rows {
+            let row = self.row(r).iter().map(|v| v.to_string());
+            writeln!(f, "[{}]", row.collect::<Vec<_>>().join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_multiply() {
+        let a = Matrix::from_rows(&[&[1, 2, 3], &[4, 5, 6]]);
+        let b = a.transpose();
+        assert_eq!((b.rows(), b.cols()), (3, 2));
+        assert_eq!(&a * &b, Matrix::from_rows(&[&[14, 32], &[32, 77]]));
+        assert_eq!(&a * &Matrix::identity(3), a);
+        assert_eq!(a.to_string(), "[1, 2, 3]\n[4, 5, 6]\n");
+
+        let c = Matrix::from_rows(&[&[-1, 3], &[2, -5]]);
+        assert_eq!(c.mul_mod(&c, 7), Matrix::from_rows(&[&[0, 3], &[2, 3]]));
+        assert_eq!(c.pow(3), &(&c * &c) * &c);
+        assert_eq!(c.pow(0), Matrix::identity(2));
+    }
+
+    #[test]
+    fn test_matrix_pow_mod() {
+        // x -> 3x + 5 applied n times, as an affine transform
+        let m = 119_315_717_514_047;
+        let affine = Matrix::from_rows(&[&[3, 5], &[0, 1]]);
+        let mut expected = 1;
+        for _ in 0..1000 {
+            expected = (3 * expected + 5) % m;
+        }
+        let nth = affine.pow_mod(1000, m);
+        assert_eq!((nth[(0, 0)] + nth[(0, 1)]) % m as i64, expected as i64);
+        assert_eq!(affine.pow_mod(0, 1), Matrix::zero(2, 2));
+    }
+}
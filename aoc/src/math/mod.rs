@@ -0,0 +1,11 @@
+mod matrix;
+pub use matrix::Matrix;
+
+mod mod_num;
+pub use mod_num::{ModNum, Modulo};
+
+mod number_theory;
+pub use number_theory::{crt, extended_gcd, gcd, lcm, mod_inverse};
+
+mod primes;
+pub use primes::{factorize, is_prime, primes_up_to};
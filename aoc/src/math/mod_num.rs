@@ -0,0 +1,298 @@
+use super::mod_inverse;
+use num::{BigInt, Integer, ToPrimitive};
+use std::convert::TryFrom;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+/// An integer modulo some number, which is kept in the range `0..modulo`
+/// through every operation.
+///
+/// Arithmetic is done with `u128` when the modulo fits in a `u64`, and with
+/// `BigInt` otherwise.
+///
+/// ```
+/// use aoc::math::Modulo;
+///
+/// let a = 5.modulo(7);
+/// assert_eq!((a.clone() * a.clone()).value(), Some(4));
+/// assert_eq!((a.clone() - 6.modulo(7)).value(), Some(6));
+/// assert_eq!(a.inv().unwrap().value(), Some(3));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ModNum(Repr);
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Repr {
+    Small { value: u64, modulo: u64 },
+    Big { value: BigInt, modulo: BigInt },
+}
+
+impl ModNum {
+    /// A number with any modulo, falling back on `BigInt` arithmetic if it
+    /// doesn't fit in a `u64`.
+    pub fn new(value: BigInt, modulo: BigInt) -> ModNum {
+        assert!(modulo > BigInt::from(0), "Modulo must be positive");
+        let value = value.mod_floor(&modulo);
+        match (value.to_u64(), modulo.to_u64()) {
+            (Some(value), Some(modulo)) => ModNum(Repr::Small { value, modulo }),
+            _ => ModNum(Repr::Big { value, modulo }),
+        }
+    }
+
+    pub fn value(&self) -> Option<u64> {
+        match &self.0 {
+            Repr::Small { value, .. } => Some(*value),
+            Repr::Big { value, .. } => value.to_u64(),
+        }
+    }
+
+    pub fn big_value(&self) -> BigInt {
+        match &self.0 {
+            Repr::Small { value, .. } => BigInt::from(*value),
+            Repr::Big { value, .. } => value.clone(),
+        }
+    }
+
+    pub fn modulo(&self) -> BigInt {
+        match &self.0 {
+            Repr::Small { modulo, .. } => BigInt::from(*modulo),
+            Repr::Big { modulo, .. } => modulo.clone(),
+        }
+    }
+
+    /// This number raised to the power `exp`.
+    pub fn pow(&self, exp: u64) -> ModNum {
+        match &self.0 {
+            Repr::Small { value, modulo } => {
+                let (mut base, mut exp, mut result) = (*value, exp, 1 % *modulo);
+                while exp > 0 {
+                    if exp & 1 == 1 {
+                        result = mul_mod(result, base, *modulo);
+                    }
+                    base = mul_mod(base, base, *modulo);
+                    exp >>= 1;
+                }
+                ModNum(Repr::Small {
+                    value: result,
+                    modulo: *modulo,
+                })
+            }
+            Repr::Big { value, modulo } => ModNum(Repr::Big {
+                value: value.modpow(&BigInt::from(exp), modulo),
+                modulo: modulo.clone(),
+            }),
+        }
+    }
+
+    /// The multiplicative inverse of this number, which exists only if it is
+    /// coprime to the modulo. The modulo need not be prime.
+    pub fn inv(&self) -> Option<ModNum> {
+        match &self.0 {
+            Repr::Small { value, modulo } => {
+                // Values too large for an i64 are equivalent to a small
+                // negative one.
+                let value = i64::try_from(*value).unwrap_or_else(|_| -((modulo - value) as i64));
+                mod_inverse(value, *modulo).map(|value| {
+                    ModNum(Repr::Small {
+                        value,
+                        modulo: *modulo,
+                    })
+                })
+            }
+            Repr::Big { value, modulo } => {
+                let gcd = value.extended_gcd(modulo);
+                if gcd.gcd == BigInt::from(1) {
+                    Some(ModNum(Repr::Big {
+                        value: gcd.x.mod_floor(modulo),
+                        modulo: modulo.clone(),
+                    }))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+fn mul_mod(a: u64, b: u64, modulo: u64) -> u64 {
+    ((u128::from(a) * u128::from(b)) % u128::from(modulo)) as u64
+}
+
+impl AddAssign<ModNum> for ModNum {
+    fn add_assign(&mut self, other: ModNum) {
+        match (&mut self.0, other.0) {
+            (
+                Repr::Small { value, modulo },
+                Repr::Small {
+                    value: v,
+                    modulo: m,
+                },
+            ) => {
+                assert_eq!(*modulo, m);
+                *value = ((u128::from(*value) + u128::from(v)) % u128::from(m)) as u64;
+            }
+            (
+                Repr::Big { value, modulo },
+                Repr::Big {
+                    value: v,
+                    modulo: m,
+                },
+            ) => {
+                assert_eq!(*modulo, m);
+                *value = (&*value + v).mod_floor(modulo);
+            }
+            _ => panic!("Mismatched modulo"),
+        }
+    }
+}
+
+impl SubAssign<ModNum> for ModNum {
+    fn sub_assign(&mut self, other: ModNum) {
+        match (&mut self.0, other.0) {
+            (
+                Repr::Small { value, modulo },
+                Repr::Small {
+                    value: v,
+                    modulo: m,
+                },
+            ) => {
+                assert_eq!(*modulo, m);
+                *value = ((u128::from(*value) + u128::from(m - v)) % u128::from(m)) as u64;
+            }
+            (
+                Repr::Big { value, modulo },
+                Repr::Big {
+                    value: v,
+                    modulo: m,
+                },
+            ) => {
+                assert_eq!(*modulo, m);
+                *value = (&*value - v).mod_floor(modulo);
+            }
+            _ => panic!("Mismatched modulo"),
+        }
+    }
+}
+
+impl MulAssign<ModNum> for ModNum {
+    fn mul_assign(&mut self, other: ModNum) {
+        match (&mut self.0, other.0) {
+            (
+                Repr::Small { value, modulo },
+                Repr::Small {
+                    value: v,
+                    modulo: m,
+                },
+            ) => {
+                assert_eq!(*modulo, m);
+                *value = mul_mod(*value, v, m);
+            }
+            (
+                Repr::Big { value, modulo },
+                Repr::Big {
+                    value: v,
+                    modulo: m,
+                },
+            ) => {
+                assert_eq!(*modulo, m);
+                *value = (&*value * v).mod_floor(modulo);
+            }
+            _ => panic!("Mismatched modulo"),
+        }
+    }
+}
+
+macro_rules! op {
+    ($trait:ident, $method:ident, $assign_method:ident) => {
+        impl $trait<ModNum> for ModNum {
+            type Output = ModNum;
+
+            fn $method(self, other: ModNum) -> ModNum {
+                let mut result = self;
+                result.$assign_method(other);
+                result
+            }
+        }
+    };
+}
+
+op!(Add, add, add_assign);
+op!(Sub, sub, sub_assign);
+op!(Mul, mul, mul_assign);
+
+pub trait Modulo {
+    fn modulo(self, modulo: u64) -> ModNum;
+}
+
+macro_rules! impl_modulo {
+    ($($t:ty),*) => {
+        $(
+            impl Modulo for $t {
+                fn modulo(self, modulo: u64) -> ModNum {
+                    assert!(modulo > 0, "Modulo must be positive");
+                    let value = (self as i128).rem_euclid(i128::from(modulo)) as u64;
+                    ModNum(Repr::Small { value, modulo })
+                }
+            }
+        )*
+    };
+}
+
+impl_modulo!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl Modulo for BigInt {
+    fn modulo(self, modulo: u64) -> ModNum {
+        ModNum::new(self, BigInt::from(modulo))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_arithmetic() {
+        let m = 119_315_717_514_047;
+        let a = (m - 3).modulo(m);
+        let b = 5.modulo(m);
+        assert_eq!((a.clone() + b.clone()).value(), Some(2));
+        assert_eq!((b.clone() - a.clone()).value(), Some(8));
+        assert_eq!((a.clone() * a.clone()).value(), Some(9));
+        assert_eq!((-7).modulo(m).value(), Some(m - 7));
+        assert_eq!(b.pow(0).value(), Some(1));
+        assert_eq!(b.pow(3).value(), Some(125));
+
+        // Composite and trivial moduli.
+        assert_eq!(3.modulo(10).inv(), Some(7.modulo(10)));
+        assert_eq!(4.modulo(10).inv(), None);
+        assert_eq!(5.modulo(1).inv(), Some(0.modulo(1)));
+        let big = u64::MAX - 1;
+        assert_eq!(
+            ((big - 1).modulo(big).inv().unwrap() * (big - 1).modulo(big)).value(),
+            Some(1)
+        );
+        assert_eq!((b.inv().unwrap() * b).value(), Some(1));
+    }
+
+    #[test]
+    fn test_big_fallback() {
+        // 2^127 - 1 is prime.
+        let m: BigInt = (BigInt::from(1) << 127) - 1;
+        let a = ModNum::new(BigInt::from(-2), m.clone());
+        assert_eq!(a.big_value(), &m - 2);
+        assert_eq!(a.value(), None);
+        assert_eq!(a.modulo(), m);
+
+        let b = ModNum::new(BigInt::from(3), m.clone());
+        assert_eq!((a.clone() + b.clone()).value(), Some(1));
+        assert_eq!((b.clone() - a.clone()).value(), Some(5));
+        assert_eq!((a.clone() * a.clone()).value(), Some(4));
+        assert_eq!(b.pow(4).value(), Some(81));
+        let even = ModNum::new(BigInt::from(2), BigInt::from(1) << 100);
+        assert_eq!(even.inv(), None);
+        assert_eq!((b.inv().unwrap() * b).value(), Some(1));
+
+        // Numbers which fit use the fast path, and agree with the slow one.
+        assert_eq!(ModNum::new(BigInt::from(12), BigInt::from(7)), 5.modulo(7));
+        assert_eq!(BigInt::from(-1).modulo(7), 6.modulo(7));
+    }
+}
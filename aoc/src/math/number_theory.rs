@@ -0,0 +1,170 @@
+use std::convert::TryFrom;
+
+/// The greatest common divisor of `a` and `b`, where `gcd(0, 0) == 0`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// The least common multiple of `a` and `b`, where the lcm of zero and
+/// anything is zero.
+///
+/// ```
+/// use aoc::math::lcm;
+///
+/// assert_eq!(lcm(4, 6), 12);
+/// assert_eq!([18, 28, 44].iter().fold(1, |acc, &x| lcm(acc, x)), 2772);
+/// ```
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+/// Returns `(g, x, y)` such that `g` is the non-negative gcd of `a` and `b`
+/// and `a * x + b * y == g`, or None if the gcd is too large for an `i64`,
+/// which only happens when each of `a` and `b` is either zero or `i64::MIN`.
+///
+/// ```
+/// use aoc::math::extended_gcd;
+///
+/// let (g, x, y) = extended_gcd(240, 46).unwrap();
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// assert_eq!(extended_gcd(i64::MIN, 0), None);
+/// ```
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd_i128(i128::from(a), i128::from(b));
+    Some((
+        i64::try_from(g).ok()?,
+        i64::try_from(x).ok()?,
+        i64::try_from(y).ok()?,
+    ))
+}
+
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        let (next_r, next_x, next_y) = (old_r - q * r, old_x - q * x, old_y - q * y);
+        old_r = r;
+        old_x = x;
+        old_y = y;
+        r = next_r;
+        x = next_x;
+        y = next_y;
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The inverse of `a` modulo `modulo`, which need not be prime. Returns None
+/// if `a` and `modulo` aren't coprime.
+///
+/// ```
+/// use aoc::math::mod_inverse;
+///
+/// assert_eq!(mod_inverse(3, 10), Some(7));
+/// assert_eq!(mod_inverse(-3, 10), Some(3));
+/// assert_eq!(mod_inverse(4, 10), None);
+/// ```
+pub fn mod_inverse(a: i64, modulo: u64) -> Option<u64> {
+    let m = i128::from(modulo);
+    let (g, x, _) = extended_gcd_i128(i128::from(a).rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m) as u64)
+    } else {
+        None
+    }
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulo)` using the
+/// Chinese Remainder Theorem.
+///
+/// The moduli don't need to be coprime. Returns the smallest non-negative
+/// solution along with the lcm of the moduli, which every other solution
+/// differs from it by a multiple of, or None if there is no solution or it
+/// doesn't fit in a `u64`.
+///
+/// ```
+/// use aoc::math::crt;
+///
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// ```
+pub fn crt(congruences: &[(i64, u64)]) -> Option<(u64, u64)> {
+    let mut result: i128 = 0;
+    let mut modulo: i128 = 1;
+    for &(residue, m) in congruences {
+        assert!(m > 0, "Modulo must be positive");
+        let m = i128::from(m);
+        let residue = i128::from(residue).rem_euclid(m);
+
+        let (g, p, _) = extended_gcd_i128(modulo, m);
+        let diff = residue - result;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        let k = ((diff / g) % step * (p % step)).rem_euclid(step);
+        result += modulo * k;
+        modulo *= step;
+        if u64::try_from(modulo).is_err() {
+            return None;
+        }
+        result = result.rem_euclid(modulo);
+    }
+    Some((result as u64, modulo as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(48, 18), 6);
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(21, 6), 42);
+        assert_eq!(lcm(1 << 40, 3 << 20), 3 << 40);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for &(a, b) in &[(0, 0), (0, 7), (7, 0), (-12, 18), (12, -18), (35, 64)] {
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(extended_gcd(i64::MIN, 1), Some((1, 0, 1)));
+        assert_eq!(extended_gcd(i64::MIN, i64::MIN), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1, 10)]), Some((9, 10)));
+        // Moduli which share factors.
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        // Large moduli close to the limit.
+        let m1 = 4_294_967_291;
+        let m2 = 4_294_967_279;
+        let (x, m) = crt(&[(1, m1), (2, m2)]).unwrap();
+        assert_eq!(m, m1 * m2);
+        assert_eq!((x % m1, x % m2), (1, 2));
+        assert_eq!(crt(&[(0, m1), (0, m2), (0, 5)]), None);
+    }
+}
@@ -0,0 +1,119 @@
+use super::Modulo;
+
+/// Whether `n` is prime, using a deterministic Miller-Rabin test.
+///
+/// ```
+/// use aoc::math::is_prime;
+///
+/// assert!(is_prime(10_007));
+/// assert!(is_prime(119_315_717_514_047));
+/// assert!(!is_prime(10_005));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    // These bases are enough to be certain for every u64.
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &p in BASES.iter() {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let minus_one = (n - 1).modulo(n);
+    BASES.iter().all(|&a| {
+        let mut x = a.modulo(n).pow(d);
+        if x.value() == Some(1) || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = x.clone() * x;
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// All of the primes less than or equal to `limit`, in ascending order, found
+/// with a sieve of Eratosthenes.
+///
+/// ```
+/// use aoc::math::primes_up_to;
+///
+/// assert_eq!(primes_up_to(20), [2, 3, 5, 7, 11, 13, 17, 19]);
+/// ```
+pub fn primes_up_to(limit: usize) -> Vec<usize> {
+    let mut is_composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+    for n in 2..=limit {
+        if is_composite[n] {
+            continue;
+        }
+        primes.push(n);
+        for multiple in (n * n..=limit).step_by(n) {
+            is_composite[multiple] = true;
+        }
+    }
+    primes
+}
+
+/// The prime factors of `n` in ascending order, each with its multiplicity.
+///
+/// ```
+/// use aoc::math::factorize;
+///
+/// assert_eq!(factorize(360), [(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(1), []);
+/// ```
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p <= n / p {
+        let mut count = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            count += 1;
+        }
+        if count > 0 {
+            factors.push((p, count));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_prime_agrees_with_sieve() {
+        let primes = primes_up_to(10_000);
+        assert_eq!(primes.len(), 1229);
+        for n in 0..=10_000 {
+            assert_eq!(is_prime(n as u64), primes.binary_search(&n).is_ok());
+        }
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(3_215_031_751)); // a strong pseudoprime to 2, 3, 5 and 7
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(0), []);
+        assert_eq!(factorize(97), [(97, 1)]);
+        assert_eq!(factorize(10_006), [(2, 1), (5003, 1)]);
+        let n = 2 * 2 * 3 * 10_007 * 10_007;
+        let factors = factorize(n);
+        assert_eq!(factors, [(2, 2), (3, 1), (10_007, 2)]);
+        assert_eq!(factors.iter().map(|&(p, k)| p.pow(k)).product::<u64>(), n);
+    }
+}
@@ -0,0 +1,121 @@
+//! Caching of repeated pure computations.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A cache of values computed from hashable keys.
+///
+/// The computation is handed the cache itself, so recursive definitions can
+/// reuse earlier results.
+///
+/// ```
+/// use aoc::memo::Memo;
+///
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo, &n| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+///     })
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(fibonacci(&mut memo, 90), 2_880_067_194_370_816_120);
+/// assert_eq!(memo.len(), 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo::default()
+    }
+
+    /// The cached value for `key`, computing and storing it first if needed.
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Memo<K, V>, &K) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self, &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.cache.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// Wrap a function so that it is only called once for each distinct argument.
+///
+/// ```
+/// use aoc::memo::memoize;
+///
+/// let mut calls = 0;
+/// let mut square = memoize(|x: i64| {
+///     calls += 1;
+///     x * x
+/// });
+/// assert_eq!(square(3), 9);
+/// assert_eq!(square(3), 9);
+/// assert_eq!(square(-4), 16);
+/// drop(square);
+/// assert_eq!(calls, 2);
+/// ```
+pub fn memoize<K, V, F>(mut f: F) -> impl FnMut(K) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: FnMut(K) -> V,
+{
+    let mut memo = Memo::new();
+    move |key: K| memo.get_or_compute(key, |_, key| f(key.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memo_computes_once() {
+        let mut computed = Vec::new();
+        let mut memo = Memo::new();
+        for &n in &[3, 1, 3, 2, 1] {
+            let value = memo.get_or_compute(n, |_, &n| {
+                computed.push(n);
+                n * 10
+            });
+            assert_eq!(value, n * 10);
+        }
+        assert_eq!(computed, [3, 1, 2]);
+        assert_eq!(memo.get(&2), Some(&20));
+        assert_eq!(memo.get(&4), None);
+
+        memo.clear();
+        assert!(memo.is_empty());
+    }
+}
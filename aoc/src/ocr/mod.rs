@@ -1,58 +1,390 @@
-use crate::geom::Dimensions;
+use crate::geom::{Dimensions, Grid, Rect, Vector2D};
+use crate::Error;
 use std::cmp::Ordering;
 use std::fmt;
 
-const LETTER_IMAGE_DATA: [(char, &str); 9] = [
+/// Every letter known to appear in puzzle outputs in the small font. Some
+/// letters, such as 'Y', are wider than the others and can't be represented.
+const SMALL_LETTER_DATA: [(char, &str); 17] = [
     ('A', include_str!("letters/A.txt")),
+    ('B', include_str!("letters/B.txt")),
     ('C', include_str!("letters/C.txt")),
     ('E', include_str!("letters/E.txt")),
     ('F', include_str!("letters/F.txt")),
     ('G', include_str!("letters/G.txt")),
     ('H', include_str!("letters/H.txt")),
+    ('I', include_str!("letters/I.txt")),
+    ('J', include_str!("letters/J.txt")),
+    ('K', include_str!("letters/K.txt")),
+    ('L', include_str!("letters/L.txt")),
+    ('O', include_str!("letters/O.txt")),
     ('P', include_str!("letters/P.txt")),
     ('R', include_str!("letters/R.txt")),
+    ('S', include_str!("letters/S.txt")),
     ('U', include_str!("letters/U.txt")),
+    ('Z', include_str!("letters/Z.txt")),
 ];
 
-pub const LETTER_IMAGE_DIMENSIONS: Dimensions = Dimensions {
-    width: 4,
-    height: 6,
-};
+/// Every letter known to appear in puzzle outputs in the large font.
+const LARGE_LETTER_DATA: [(char, &str); 15] = [
+    ('A', include_str!("large_letters/A.txt")),
+    ('B', include_str!("large_letters/B.txt")),
+    ('C', include_str!("large_letters/C.txt")),
+    ('E', include_str!("large_letters/E.txt")),
+    ('F', include_str!("large_letters/F.txt")),
+    ('G', include_str!("large_letters/G.txt")),
+    ('H', include_str!("large_letters/H.txt")),
+    ('J', include_str!("large_letters/J.txt")),
+    ('K', include_str!("large_letters/K.txt")),
+    ('L', include_str!("large_letters/L.txt")),
+    ('N', include_str!("large_letters/N.txt")),
+    ('P', include_str!("large_letters/P.txt")),
+    ('R', include_str!("large_letters/R.txt")),
+    ('X', include_str!("large_letters/X.txt")),
+    ('Z', include_str!("large_letters/Z.txt")),
+];
+
+/// A font of letters which all have the same dimensions.
+///
+/// New letters can be registered to recognise fonts other than the ones
+/// built in, and a letter can be registered more than once to recognise
+/// several variations of it.
+///
+/// ```
+/// use aoc::geom::Dimensions;
+/// use aoc::ocr::{GlyphSet, LetterImage};
+///
+/// let mut glyphs = GlyphSet::new(Dimensions::new_const(3, 3));
+/// glyphs.register('+', LetterImage::from(" @ \n@@@\n @ "));
+/// glyphs.register('x', LetterImage::from("@ @\n @ \n@ @"));
+/// assert_eq!(glyphs.try_ocr_string("@ @  @ \n @  @@@\n@ @  @ ", 1.0).unwrap(), "x+");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlyphSet {
+    dimensions: Dimensions,
+    letters: Vec<(char, LetterImage)>,
+    similarity: Similarity,
+}
+
+impl GlyphSet {
+    /// An empty glyph set for letters of the given size.
+    pub fn new(dimensions: Dimensions) -> GlyphSet {
+        GlyphSet {
+            dimensions,
+            letters: Vec::new(),
+            similarity: Similarity::default(),
+        }
+    }
+
+    fn from_data(dimensions: Dimensions, data: &[(char, &str)]) -> GlyphSet {
+        let mut glyphs = GlyphSet::new(dimensions);
+        for &(c, image) in data {
+            glyphs.register(c, LetterImage::from(image));
+        }
+        glyphs
+    }
+
+    /// The 4x6 font used by most puzzles.
+    pub fn small() -> GlyphSet {
+        GlyphSet::from_data(Dimensions::new_const(4, 6), &SMALL_LETTER_DATA)
+    }
+
+    /// The 6x10 font used by some puzzles.
+    pub fn large() -> GlyphSet {
+        GlyphSet::from_data(Dimensions::new_const(6, 10), &LARGE_LETTER_DATA)
+    }
+
+    /// The built in glyph set whose letters are `height` pixels tall, if
+    /// there is one.
+    pub fn for_height(height: usize) -> Option<GlyphSet> {
+        vec![GlyphSet::small(), GlyphSet::large()]
+            .into_iter()
+            .find(|set| set.dimensions.height == height)
+    }
+
+    pub fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    /// Each letter in the set along with its image.
+    pub fn letters(&self) -> impl Iterator<Item = (char, &LetterImage)> {
+        self.letters.iter().map(|(c, image)| (*c, image))
+    }
+
+    /// Change how images are compared to the letters in the set, which is by
+    /// [Similarity::Hamming](enum.Similarity.html#variant.Hamming) unless set.
+    pub fn set_similarity(&mut self, similarity: Similarity) {
+        self.similarity = similarity;
+    }
 
-pub struct LetterImage(pub Vec<bool>);
+    /// Teach the set to recognise `image` as `character`. Panics if the image
+    /// isn't the same size as the set's letters.
+    pub fn register(&mut self, character: char, image: LetterImage) {
+        assert_eq!(image.dimensions, self.dimensions);
+        self.letters.push((character, image));
+    }
+
+    /// Find the letter in this set most like `img`, as with
+    /// [try_ocr](fn.try_ocr.html).
+    pub fn try_ocr(&self, img: LetterImage, threshold: f64) -> Result<OcrResult, Error> {
+        let best = self.candidates(&img)?[0];
+        if best.confidence < threshold {
+            return Err(Error::Parse(format!(
+                "Unrecognised letter, closest to '{}' with confidence {:.2}:{}",
+                best.character, best.confidence, img
+            )));
+        }
+        Ok(best)
+    }
+
+    /// Every letter in the set with how closely `img` matches it, from most
+    /// to least alike. A letter registered more than once is listed once,
+    /// with its best match.
+    pub fn candidates(&self, img: &LetterImage) -> Result<Vec<OcrResult>, Error> {
+        if img.dimensions != self.dimensions {
+            return Err(Error::Parse(format!(
+                "Image is {}x{} but letters are {}x{}",
+                img.dimensions.width,
+                img.dimensions.height,
+                self.dimensions.width,
+                self.dimensions.height
+            )));
+        }
+        if self.letters.is_empty() {
+            return Err(Error::Unsupported("No letters in the glyph set".into()));
+        }
+
+        let mut candidates: Vec<OcrResult> = Vec::new();
+        for (c, letter) in self.letters() {
+            let confidence = img.score_similarity(letter, self.similarity);
+            match candidates.iter_mut().find(|r| r.character == c) {
+                Some(result) => result.confidence = result.confidence.max(confidence),
+                None => candidates.push(OcrResult {
+                    character: c,
+                    confidence,
+                }),
+            }
+        }
+        candidates.sort_by(|a, b| compare_confidence(b, a));
+        Ok(candidates)
+    }
+
+    /// Draw `text` as a banner of letters from this set, in the same format as
+    /// puzzle outputs, with '@' for lit pixels and a blank column between
+    /// letters. Spaces leave a gap the width of a letter. Returns an error if
+    /// the set has no image for a character.
+    pub fn render(&self, text: &str) -> Result<String, Error> {
+        let images = text
+            .chars()
+            .map(|c| match self.letters().find(|&(letter, _)| letter == c) {
+                Some((_, image)) => Ok(Some(image)),
+                None if c == ' ' => Ok(None),
+                None => Err(Error::Unsupported(format!("No glyph for '{}'", c))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut banner = String::new();
+        for y in 0..self.dimensions.height as i64 {
+            for (i, image) in images.iter().enumerate() {
+                if i > 0 {
+                    banner.push(' ');
+                }
+                for x in 0..self.dimensions.width as i64 {
+                    let is_lit = image.is_some_and(|img| img.pixel(Vector2D::new(x, y)));
+                    banner.push(if is_lit { '@' } else { ' ' });
+                }
+            }
+            banner.push('\n');
+        }
+        Ok(banner)
+    }
+
+    /// Read every letter in a rendered banner using this set, as with
+    /// [try_ocr_string](fn.try_ocr_string.html).
+    pub fn try_ocr_string(&self, rendered: &str, threshold: f64) -> Result<String, Error> {
+        let rows = crop_banner(banner_rows(rendered));
+        if rows.is_empty() {
+            return Ok(String::new());
+        }
+        if rows.len() != self.dimensions.height {
+            return Err(Error::Parse(format!(
+                "Banner is {} pixels tall but letters are {}",
+                rows.len(),
+                self.dimensions.height
+            )));
+        }
+        self.read_banner(&rows, threshold)
+    }
+
+    /// Find every letter from this set in an image of a banner, as with
+    /// [locate_glyphs](fn.locate_glyphs.html).
+    pub fn locate_glyphs(&self, grid: &Grid<bool>) -> Result<Vec<(Vector2D, OcrResult)>, Error> {
+        let (top, rows) = crop_banner_with_top(grid_rows(grid));
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        if rows.len() != self.dimensions.height {
+            return Err(Error::Parse(format!(
+                "Banner is {} pixels tall but letters are {}",
+                rows.len(),
+                self.dimensions.height
+            )));
+        }
+        let glyphs = self.find_glyphs(&rows)?;
+        Ok(glyphs
+            .into_iter()
+            .map(|(left, result)| (Vector2D::new(left, top as i64), result))
+            .collect())
+    }
+
+    /// Read the letters in a banner which has been cropped to their height.
+    fn read_banner(&self, rows: &[Vec<bool>], threshold: f64) -> Result<String, Error> {
+        let mut letters = String::new();
+        for (left, result) in self.find_glyphs(rows)? {
+            if result.confidence < threshold {
+                return Err(Error::Parse(format!(
+                    "Unrecognised letter at column {}, closest to '{}' with confidence {:.2}",
+                    left, result.character, result.confidence
+                )));
+            }
+            letters.push(result.character);
+        }
+        Ok(letters)
+    }
+
+    /// Find the best match for each letter in a banner which has been cropped
+    /// to their height, along with the column of its left edge.
+    fn find_glyphs(&self, rows: &[Vec<bool>]) -> Result<Vec<(i64, OcrResult)>, Error> {
+        if self.letters.is_empty() {
+            return Err(Error::Unsupported("No letters in the glyph set".into()));
+        }
+        let height = rows.len();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let is_lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+        let is_blank_column = |x: usize| (0..height).all(|y| !is_lit(x, y));
+
+        // Find each run of columns with something lit in it.
+        let mut runs = Vec::new();
+        let mut x = 0;
+        while x < width {
+            if is_blank_column(x) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x < width && !is_blank_column(x) {
+                x += 1;
+            }
+            runs.push(start..x);
+        }
+
+        let letter_width = self.dimensions.width;
+        let letter_at = |left: i64| {
+            let data = (0..height)
+                .flat_map(|y| (left..left + letter_width as i64).map(move |x| (x, y)))
+                .map(|(x, y)| x >= 0 && is_lit(x as usize, y))
+                .collect::<Vec<_>>();
+            let result = self.try_ocr(LetterImage::new(self.dimensions, &data), 0.0);
+            (left, result.unwrap())
+        };
+        let best_letter = |lefts: Vec<i64>| {
+            lefts
+                .into_iter()
+                .map(letter_at)
+                .max_by(|(_, a), (_, b)| compare_confidence(a, b))
+                .unwrap()
+        };
+
+        let mut glyphs = Vec::new();
+        for run in runs {
+            if run.len() < letter_width {
+                // Narrow letters, such as 'I', don't fill their whole cell, so
+                // try each position the cell could have and take the best match.
+                let slack = letter_width - run.len();
+                let lefts = (0..=slack).map(|s| run.start as i64 - s as i64);
+                glyphs.push(best_letter(lefts.collect()));
+            } else {
+                // Letters without a gap between them are assumed to be evenly
+                // spaced.
+                for left in run.step_by(letter_width + 1) {
+                    glyphs.push(best_letter(vec![left as i64]));
+                }
+            }
+        }
+        Ok(glyphs)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LetterImage {
+    dimensions: Dimensions,
+    pixels: Vec<bool>,
+}
 
 impl LetterImage {
-    pub fn new(data: &[bool]) -> LetterImage {
-        assert_eq!(data.len(), LETTER_IMAGE_DIMENSIONS.area());
-        LetterImage(Vec::from(data))
+    pub fn new(dimensions: Dimensions, data: &[bool]) -> LetterImage {
+        assert_eq!(data.len(), dimensions.area());
+        LetterImage {
+            dimensions,
+            pixels: Vec::from(data),
+        }
     }
 
-    fn score_similarity(&self, other: &LetterImage) -> f64 {
-        let sum: f64 = self
-            .0
-            .iter()
-            .copied()
-            .zip(other.0.iter().copied())
-            .map(|(a, b)| if a == b { 1.0 } else { 0.0 })
-            .sum();
-        sum as f64 / LETTER_IMAGE_DIMENSIONS.area() as f64
+    pub fn dimensions(&self) -> Dimensions {
+        self.dimensions
+    }
+
+    fn pixel(&self, pos: Vector2D) -> bool {
+        self.dimensions.contains(pos) && self.pixels[self.dimensions.pos_to_node_index(pos)]
+    }
+
+    /// How alike the images are by the given measure, when `other` is moved
+    /// by up to [MAX_ALIGNMENT_OFFSET](constant.MAX_ALIGNMENT_OFFSET.html)
+    /// pixels in each direction to line up best with this one.
+    fn score_similarity(&self, other: &LetterImage, similarity: Similarity) -> f64 {
+        assert_eq!(self.dimensions, other.dimensions);
+        let range = -MAX_ALIGNMENT_OFFSET..=MAX_ALIGNMENT_OFFSET;
+        let offsets = range
+            .clone()
+            .flat_map(|y| range.clone().map(move |x| Vector2D::new(x, y)));
+        offsets
+            .map(|offset| {
+                let mut counts = PixelCounts::default();
+                for pos in self.dimensions.iter() {
+                    match (self.pixel(pos), other.pixel(pos - offset)) {
+                        (true, true) => counts.both_lit += 1,
+                        (false, false) => counts.both_unlit += 1,
+                        (true, false) => counts.only_first_lit += 1,
+                        (false, true) => counts.only_second_lit += 1,
+                    }
+                }
+                similarity.score(counts)
+            })
+            .fold(0.0, f64::max)
     }
 }
 
 impl From<&str> for LetterImage {
     fn from(s: &str) -> LetterImage {
+        let width = s.lines().next().map_or(0, |line| line.chars().count());
+        let height = s.lines().count();
         let data = s
             .lines()
             .flat_map(|line| line.chars().map(|c| !c.is_whitespace()))
             .collect::<Vec<_>>();
-        assert!(data.len() == LETTER_IMAGE_DIMENSIONS.area());
-        LetterImage(data)
+        LetterImage::new(Dimensions { width, height }, &data)
+    }
+}
+
+impl From<&Grid<bool>> for LetterImage {
+    fn from(grid: &Grid<bool>) -> LetterImage {
+        LetterImage::new(grid.dimensions(), grid.cells())
     }
 }
 
 impl fmt::Display for LetterImage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (pos, pixel) in LETTER_IMAGE_DIMENSIONS.iter().zip(self.0.iter().copied()) {
+        for (pos, pixel) in self.dimensions.iter().zip(self.pixels.iter().copied()) {
             if pos.x == 0 {
                 writeln!(f)?;
             }
@@ -63,37 +395,556 @@ impl fmt::Display for LetterImage {
     }
 }
 
+/// How to measure the similarity of an image to a glyph, from 0 for nothing
+/// alike to 1 for identical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Similarity {
+    /// The fraction of all pixels which match.
+    #[default]
+    Hamming,
+    /// The average of the fraction of lit pixels which match and the fraction
+    /// of unlit pixels which match, so that a few lit pixels in a mostly
+    /// empty glyph count as much as its background.
+    LitWeighted,
+    /// The number of pixels lit in both images over the number lit in either,
+    /// ignoring the background entirely.
+    IntersectionOverUnion,
+}
+
+/// How the pixels of two images compare.
+#[derive(Debug, Default, Clone, Copy)]
+struct PixelCounts {
+    both_lit: usize,
+    both_unlit: usize,
+    only_first_lit: usize,
+    only_second_lit: usize,
+}
+
+impl Similarity {
+    fn score(self, counts: PixelCounts) -> f64 {
+        // The fraction of `a`, or 1 if there's nothing to compare.
+        let ratio = |a: usize, b: usize| {
+            if a + b == 0 {
+                1.0
+            } else {
+                a as f64 / (a + b) as f64
+            }
+        };
+        let mismatched = counts.only_first_lit + counts.only_second_lit;
+        match self {
+            Similarity::Hamming => ratio(counts.both_lit + counts.both_unlit, mismatched),
+            Similarity::LitWeighted => {
+                let lit = ratio(counts.both_lit, mismatched);
+                let unlit = ratio(counts.both_unlit, mismatched);
+                (lit + unlit) / 2.0
+            }
+            Similarity::IntersectionOverUnion => ratio(counts.both_lit, mismatched),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct OcrResult {
     pub character: char,
     pub confidence: f64,
 }
 
+/// How many pixels letters can be out of alignment with the glyphs they are
+/// compared to in any direction, such as when a banner's letters don't sit
+/// exactly on a grid.
+pub const MAX_ALIGNMENT_OFFSET: i64 = 1;
+
+/// A confidence below which a letter is probably not one that's known,
+/// suitable for passing to [try_ocr](fn.try_ocr.html).
+pub const MIN_CONFIDENCE: f64 = 0.9;
+
+/// Find the letter most like `img` in the glyph set with the same height.
+/// Panics if there is no such glyph set.
 pub fn ocr(img: LetterImage) -> OcrResult {
-    LETTER_IMAGE_DATA
-        .iter()
-        .copied()
-        .map(|(c, s)| OcrResult {
-            character: c,
-            confidence: img.score_similarity(&LetterImage::from(s)),
-        })
-        .max_by(|a, b| {
-            a.confidence
-                .partial_cmp(&b.confidence)
-                .unwrap_or(Ordering::Equal)
+    try_ocr(img, 0.0).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Find the letter most like `img` in the glyph set with the same height,
+/// or an error if there is no such glyph set or the best match has a
+/// confidence below `threshold`.
+///
+/// ```
+/// use aoc::ocr::{try_ocr, LetterImage, MIN_CONFIDENCE};
+///
+/// let h = LetterImage::from("@  @\n@  @\n@@@@\n@  @\n@  @\n@  @");
+/// assert_eq!(try_ocr(h, MIN_CONFIDENCE).unwrap().character, 'H');
+///
+/// let noise = LetterImage::from("@ @ \n @ @\n@ @ \n @ @\n@ @ \n @ @");
+/// assert!(try_ocr(noise, MIN_CONFIDENCE).is_err());
+/// ```
+pub fn try_ocr(img: LetterImage, threshold: f64) -> Result<OcrResult, Error> {
+    let height = img.dimensions.height;
+    let glyphs = GlyphSet::for_height(height)
+        .ok_or_else(|| Error::Unsupported(format!("No glyphs are {} pixels tall", height)))?;
+    glyphs.try_ocr(img, threshold)
+}
+
+/// Every letter in the glyph set with the same height as `img` with how
+/// closely `img` matches it, from most to least alike. Panics if there is no
+/// such glyph set.
+///
+/// ```
+/// use aoc::ocr::{ocr_candidates, LetterImage};
+///
+/// // An E with a gap in its bottom row, which is nearly an F.
+/// let img = LetterImage::from("@@@@\n@   \n@@@ \n@   \n@   \n@@ @");
+/// let candidates = ocr_candidates(&img);
+/// assert_eq!(candidates[0].character, 'E');
+/// assert_eq!(candidates[1].character, 'F');
+/// assert!(candidates[0].confidence > candidates[1].confidence);
+/// ```
+pub fn ocr_candidates(img: &LetterImage) -> Vec<OcrResult> {
+    let height = img.dimensions.height;
+    GlyphSet::for_height(height)
+        .ok_or_else(|| Error::Unsupported(format!("No glyphs are {} pixels tall", height)))
+        .and_then(|glyphs| glyphs.candidates(img))
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn compare_confidence(a: &OcrResult, b: &OcrResult) -> Ordering {
+    a.confidence
+        .partial_cmp(&b.confidence)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Read every letter in a rendered banner of text, such as a puzzle's
+/// output, where lit pixels are any character other than whitespace or '.'.
+/// Blank margins are cropped, and letters are separated by blank columns.
+/// The glyph set is chosen by the height of the banner once cropped. Panics
+/// if there is no glyph set of that height.
+///
+/// ```
+/// use aoc::ocr::ocr_string;
+///
+/// let banner = "
+///   @  @ @@@@
+///   @  @ @
+///   @@@@ @@@
+///   @  @ @
+///   @  @ @
+///   @  @ @@@@";
+/// assert_eq!(ocr_string(banner), "HE");
+/// ```
+pub fn ocr_string(rendered: &str) -> String {
+    try_ocr_string(rendered, 0.0).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Read every letter in a rendered banner as with
+/// [ocr_string](fn.ocr_string.html), or an error if there's no glyph set of
+/// the right height or any letter has a confidence below `threshold`.
+pub fn try_ocr_string(rendered: &str, threshold: f64) -> Result<String, Error> {
+    read_banner(banner_rows(rendered), threshold)
+}
+
+/// Read every letter in an image of a banner, where lit pixels are true, as
+/// with [ocr_string](fn.ocr_string.html).
+///
+/// ```
+/// use aoc::geom::{Dimensions, Grid};
+/// use aoc::ocr::ocr_grid;
+///
+/// let rows = ["@  @ @@@@", "@  @ @   ", "@@@@ @@@ ", "@  @ @   ", "@  @ @   ", "@  @ @@@@"];
+/// let pixels = rows.iter().flat_map(|row| row.chars().map(|c| c == '@'));
+/// let grid = Grid::from_vec(Dimensions::new_const(9, 6), pixels.collect());
+/// assert_eq!(ocr_grid(&grid), "HE");
+/// ```
+pub fn ocr_grid(grid: &Grid<bool>) -> String {
+    try_ocr_grid(grid, 0.0).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Read every letter in an image of a banner as with
+/// [ocr_grid](fn.ocr_grid.html), or an error as with
+/// [try_ocr_string](fn.try_ocr_string.html).
+pub fn try_ocr_grid(grid: &Grid<bool>, threshold: f64) -> Result<String, Error> {
+    read_banner(grid_rows(grid), threshold)
+}
+
+/// Draw `text` as a banner in the small font, which can be read back with
+/// [ocr_string](fn.ocr_string.html). Panics if there's no glyph for one of
+/// the characters.
+///
+/// ```
+/// use aoc::ocr::{ocr_string, render_banner};
+///
+/// let banner = render_banner("HI");
+/// assert_eq!(banner.lines().next(), Some("@  @  @@@"));
+/// assert_eq!(ocr_string(&banner), "HI");
+/// ```
+pub fn render_banner(text: &str) -> String {
+    GlyphSet::small()
+        .render(text)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Read every letter formed by a set of lit points, such as panels painted
+/// by a robot, as with [ocr_grid](fn.ocr_grid.html). The points can be
+/// anywhere, as only their bounding box is read.
+///
+/// ```
+/// use aoc::geom::Vector2D;
+/// use aoc::ocr::ocr_points;
+///
+/// let l = (-3..3).map(|y| Vector2D::new(10, y)).chain((11..14).map(|x| Vector2D::new(x, 2)));
+/// assert_eq!(ocr_points(l), "L");
+/// ```
+pub fn ocr_points<I>(lit: I) -> String
+where
+    I: IntoIterator<Item = Vector2D>,
+{
+    try_ocr_points(lit, 0.0).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Read every letter formed by a set of lit points as with
+/// [ocr_points](fn.ocr_points.html), or an error as with
+/// [try_ocr_string](fn.try_ocr_string.html).
+pub fn try_ocr_points<I>(lit: I, threshold: f64) -> Result<String, Error>
+where
+    I: IntoIterator<Item = Vector2D>,
+{
+    let lit: Vec<Vector2D> = lit.into_iter().collect();
+    let bounds = match Rect::bounding(lit.iter().copied()) {
+        Some(bounds) => bounds,
+        None => return Ok(String::new()),
+    };
+    let mut grid = Grid::filled(bounds.dimensions(), false);
+    for pos in lit {
+        grid[pos - bounds.top_left] = true;
+    }
+    try_ocr_grid(&grid, threshold)
+}
+
+/// Find every letter in an image of a banner, with the position of the top
+/// left of each letter's cell in the image, using the built in glyph set of
+/// the right height. Letters are found however poor their best match, so the
+/// confidences show which parts of a garbled banner can't be read.
+///
+/// ```
+/// use aoc::geom::{Dimensions, Grid, Vector2D};
+/// use aoc::ocr::{locate_glyphs, render_banner};
+///
+/// let banner = render_banner("OK");
+/// let pixels = banner.lines().flat_map(|line| line.chars().map(|c| c == '@'));
+/// let image = Grid::from_vec(Dimensions::new_const(9, 6), pixels.collect());
+/// let glyphs = locate_glyphs(&image).unwrap();
+/// assert_eq!(glyphs[0].0, Vector2D::new(0, 0));
+/// assert_eq!(glyphs[1].0, Vector2D::new(5, 0));
+/// assert_eq!(glyphs[1].1.character, 'K');
+/// ```
+pub fn locate_glyphs(grid: &Grid<bool>) -> Result<Vec<(Vector2D, OcrResult)>, Error> {
+    let (_, rows) = crop_banner_with_top(grid_rows(grid));
+    if rows.is_empty() {
+        return Ok(Vec::new());
+    }
+    let height = rows.len();
+    let glyphs = GlyphSet::for_height(height)
+        .ok_or_else(|| Error::Unsupported(format!("No glyphs are {} pixels tall", height)))?;
+    glyphs.locate_glyphs(grid)
+}
+
+fn grid_rows(grid: &Grid<bool>) -> Vec<Vec<bool>> {
+    grid.rows().map(|row| row.to_vec()).collect()
+}
+
+/// Read a banner with the built in glyph set of the right height.
+fn read_banner(rows: Vec<Vec<bool>>, threshold: f64) -> Result<String, Error> {
+    let rows = crop_banner(rows);
+    if rows.is_empty() {
+        return Ok(String::new());
+    }
+    let height = rows.len();
+    let glyphs = GlyphSet::for_height(height)
+        .ok_or_else(|| Error::Unsupported(format!("No glyphs are {} pixels tall", height)))?;
+    glyphs.read_banner(&rows, threshold)
+}
+
+/// The pixels of a rendered banner, where lit pixels are any character other
+/// than whitespace or '.'.
+fn banner_rows(rendered: &str) -> Vec<Vec<bool>> {
+    rendered
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| !c.is_whitespace() && c != '.')
+                .collect()
         })
-        .unwrap()
+        .collect()
+}
+
+/// Remove any blank rows above and below a banner.
+fn crop_banner(rows: Vec<Vec<bool>>) -> Vec<Vec<bool>> {
+    crop_banner_with_top(rows).1
+}
+
+/// Remove any blank rows above and below a banner, along with the number of
+/// rows removed from the top.
+fn crop_banner_with_top(rows: Vec<Vec<bool>>) -> (usize, Vec<Vec<bool>>) {
+    let top = rows
+        .iter()
+        .position(|row| row.contains(&true))
+        .unwrap_or(rows.len());
+    let mut rows: Vec<Vec<bool>> = rows.into_iter().skip(top).collect();
+    let height = rows
+        .iter()
+        .rposition(|row| row.contains(&true))
+        .map_or(0, |last| last + 1);
+    rows.truncate(height);
+    (top, rows)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ascii::assert_ascii_eq;
+
+    fn glyph_sets() -> Vec<(GlyphSet, &'static [(char, &'static str)])> {
+        vec![
+            (GlyphSet::small(), &SMALL_LETTER_DATA),
+            (GlyphSet::large(), &LARGE_LETTER_DATA),
+        ]
+    }
 
     #[test]
     fn test_ocr() {
-        for (c, img_data) in LETTER_IMAGE_DATA.iter().copied() {
-            let img = LetterImage::from(img_data);
-            assert_eq!(ocr(img).character, c);
+        for (glyphs, _) in glyph_sets() {
+            for (c, img) in glyphs.letters() {
+                assert_eq!(img.dimensions(), glyphs.dimensions());
+                let result = ocr(img.clone());
+                assert_eq!(result.character, c);
+                assert_eq!(result.confidence, 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_glyph_set_for_height() {
+        assert_eq!(
+            GlyphSet::for_height(6).unwrap().dimensions(),
+            Dimensions::new_const(4, 6)
+        );
+        assert_eq!(
+            GlyphSet::for_height(10).unwrap().dimensions(),
+            Dimensions::new_const(6, 10)
+        );
+        assert!(GlyphSet::for_height(8).is_none());
+    }
+
+    #[test]
+    fn test_ocr_string() {
+        for (glyphs, data) in glyph_sets() {
+            // Every letter in a row, with a margin and uneven gaps between them.
+            let width = glyphs.dimensions().width;
+            let letters: Vec<Vec<&str>> = data
+                .iter()
+                .map(|(_, image)| image.lines().collect())
+                .collect();
+            let mut banner = String::from("\n.......\n");
+            for y in 0..glyphs.dimensions().height {
+                banner.push_str("..");
+                for (i, letter) in letters.iter().enumerate() {
+                    banner.push_str(&format!("{:width$}", letter[y], width = width));
+                    banner.push_str(if i % 2 == 0 { " " } else { "  " });
+                }
+                banner.push('\n');
+            }
+            let expected: String = data.iter().map(|&(c, _)| c).collect();
+            assert_eq!(ocr_string(&banner), expected);
+            assert_eq!(glyphs.try_ocr_string(&banner, 1.0).unwrap(), expected);
+        }
+
+        assert_eq!(ocr_string(" \n\n"), "");
+    }
+
+    #[test]
+    fn test_try_ocr_string() {
+        let banner = "@  @ @@@@\n@  @ @\n@@@@ @@@\n@  @ @\n@  @ @\n@  @ @@@@";
+        assert_eq!(try_ocr_string(banner, MIN_CONFIDENCE).unwrap(), "HE");
+
+        // A smudge in the middle of the E.
+        let smudged = "@  @ @@@@\n@  @ @@@@\n@@@@ @@@@\n@  @ @@@@\n@  @ @\n@  @ @@@@";
+        assert_eq!(ocr_string(smudged).len(), 2);
+        assert!(try_ocr_string(smudged, MIN_CONFIDENCE).is_err());
+
+        assert!(try_ocr_string("@\n@\n@", 0.0).is_err());
+    }
+
+    #[test]
+    fn test_ocr_grid() {
+        let img = LetterImage::from(SMALL_LETTER_DATA[0].1);
+        let grid = Grid::from_vec(img.dimensions(), img.pixels.clone());
+        assert_eq!(LetterImage::from(&grid), img);
+
+        // The same letter twice, with a blank column between.
+        let mut pixels = Vec::new();
+        for row in grid.rows() {
+            pixels.extend_from_slice(row);
+            pixels.push(false);
+            pixels.extend_from_slice(row);
+        }
+        let banner = Grid::from_vec(Dimensions::new_const(9, 6), pixels);
+        assert_eq!(ocr_grid(&banner), "AA");
+        assert_eq!(try_ocr_grid(&banner, MIN_CONFIDENCE).unwrap(), "AA");
+    }
+
+    #[test]
+    fn test_ocr_offset_letters() {
+        // Letters shifted by a pixel in each direction.
+        let right = LetterImage::from(" @  \n @  \n @@@\n @  \n @  \n @  ");
+        assert_eq!(try_ocr(right, 1.0).unwrap().character, 'H');
+
+        let up = LetterImage::from("@   \n@   \n@   \n@   \n@@@@\n    ");
+        assert_eq!(try_ocr(up, 1.0).unwrap().character, 'L');
+
+        let down = LetterImage::from("    \n@@@ \n@  @\n@  @\n@@@ \n@   ");
+        assert_eq!(try_ocr(down, 1.0).unwrap().character, 'P');
+    }
+
+    #[test]
+    fn test_similarity() {
+        // A faint plus sign looks most like nothing at all, unless lit pixels
+        // are given more weight.
+        let mut glyphs = GlyphSet::new(Dimensions::new_const(5, 5));
+        glyphs.register(' ', LetterImage::from("     \n     \n     \n     \n     "));
+        glyphs.register('+', LetterImage::from("  @  \n  @  \n@@@@@\n  @  \n  @  "));
+        let faint = LetterImage::from("     \n     \n  @@ \n     \n     ");
+
+        let expected = [
+            (Similarity::Hamming, ' '),
+            (Similarity::LitWeighted, '+'),
+            (Similarity::IntersectionOverUnion, '+'),
+        ];
+        for &(similarity, c) in expected.iter() {
+            glyphs.set_similarity(similarity);
+            assert_eq!(glyphs.try_ocr(faint.clone(), 0.0).unwrap().character, c);
+
+            // Identical images always score 1.
+            let plus = glyphs.letters[1].1.clone();
+            assert_eq!(glyphs.try_ocr(plus, 1.0).unwrap().character, '+');
+        }
+    }
+
+    #[test]
+    fn test_ocr_candidates() {
+        // Every glyph is clearly closer to itself than to any other letter.
+        for (glyphs, _) in glyph_sets() {
+            for (c, img) in glyphs.letters() {
+                let candidates = glyphs.candidates(img).unwrap();
+                assert_eq!(candidates.len(), glyphs.letters.len());
+                assert_eq!(candidates[0].character, c);
+                assert!(candidates[0].confidence - candidates[1].confidence > 0.05);
+            }
+        }
+
+        // Variations of a letter are merged.
+        let mut glyphs = GlyphSet::small();
+        glyphs.register('A', LetterImage::from("@@@@\n@  @\n@  @\n@@@@\n@  @\n@  @"));
+        let img = LetterImage::from(SMALL_LETTER_DATA[0].1);
+        let candidates = glyphs.candidates(&img).unwrap();
+        assert_eq!(candidates.len(), SMALL_LETTER_DATA.len());
+        assert_eq!(candidates[0].character, 'A');
+        assert_eq!(candidates[0].confidence, 1.0);
+    }
+
+    #[test]
+    fn test_render_banner() {
+        for (glyphs, data) in glyph_sets() {
+            let text: String = data.iter().map(|&(c, _)| c).collect();
+            let banner = glyphs.render(&text).unwrap();
+            assert_eq!(banner.lines().count(), glyphs.dimensions().height);
+            assert_eq!(glyphs.try_ocr_string(&banner, 1.0).unwrap(), text);
+        }
+
+        assert_ascii_eq(
+            "@         @   \n\
+             @         @   \n\
+             @         @   \n\
+             @         @   \n\
+             @         @   \n\
+             @@@@      @@@@\n",
+            &render_banner("L L"),
+        );
+        assert!(GlyphSet::small().render("HELLO?").is_err());
+    }
+
+    #[test]
+    fn test_locate_glyphs() {
+        // "LIE" with a margin above, and a smudged E.
+        let banner = "\n\n@     @@@ @@@@\n@      @  @@@@\n@      @  @@@\n@      @  @\n@      @  @\n@@@@  @@@ @@@@";
+        let rows = banner_rows(banner);
+        let width = rows.iter().map(Vec::len).max().unwrap();
+        let pixels = rows
+            .iter()
+            .flat_map(|row| (0..width).map(move |x| row.get(x).copied().unwrap_or(false)))
+            .collect();
+        let grid = Grid::from_vec(Dimensions::new_const(width, rows.len()), pixels);
+
+        let glyphs = locate_glyphs(&grid).unwrap();
+        let positions: Vec<_> = glyphs.iter().map(|&(pos, _)| pos).collect();
+        let letters: String = glyphs.iter().map(|(_, r)| r.character).collect();
+        assert_eq!(
+            positions,
+            [(0, 2), (5, 2), (10, 2)]
+                .iter()
+                .map(|&(x, y)| Vector2D::new(x, y))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(letters, "LIE");
+        assert_eq!(glyphs[1].1.confidence, 1.0);
+        assert!(glyphs[2].1.confidence < MIN_CONFIDENCE);
+        assert!(try_ocr_grid(&grid, MIN_CONFIDENCE).is_err());
+    }
+
+    #[test]
+    fn test_ocr_points() {
+        // "HI" drawn well away from the origin.
+        let banner = "@  @  @@@\n@  @   @\n@@@@   @\n@  @   @\n@  @   @\n@  @  @@@";
+        let lit: Vec<_> = banner
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.char_indices()
+                    .filter(|&(_, c)| c == '@')
+                    .map(move |(x, _)| Vector2D::new(x as i64 - 100, y as i64 + 50))
+            })
+            .collect();
+        assert_eq!(ocr_points(lit.iter().copied()), "HI");
+        assert_eq!(
+            try_ocr_points(lit.into_iter().rev(), MIN_CONFIDENCE).unwrap(),
+            "HI"
+        );
+        assert_eq!(ocr_points(Vec::new()), "");
+    }
+
+    #[test]
+    fn test_register_glyphs() {
+        // A variation of 'A' with a flat top.
+        let flat_a = LetterImage::from("@@@@\n@  @\n@  @\n@@@@\n@  @\n@  @");
+        let mut glyphs = GlyphSet::small();
+        assert!(glyphs.try_ocr(flat_a.clone(), 1.0).is_err());
+        glyphs.register('A', flat_a.clone());
+        assert_eq!(glyphs.try_ocr(flat_a, 1.0).unwrap().character, 'A');
+
+        let wrong_size = LetterImage::from("@@@\n@ @\n@@@");
+        assert!(glyphs.try_ocr(wrong_size, 0.0).is_err());
+        assert!(GlyphSet::new(glyphs.dimensions())
+            .try_ocr_string("@@@@\n@\n@@@\n@\n@\n@@@@", 0.0)
+            .is_err());
+        assert!(glyphs.try_ocr_string("@@@\n@@@", 0.0).is_err());
+    }
+
+    #[test]
+    fn test_letters_are_distinct() {
+        for (glyphs, _) in glyph_sets() {
+            let letters: Vec<_> = glyphs.letters().collect();
+            for (i, (a, a_img)) in letters.iter().enumerate() {
+                for (b, b_img) in &letters[i + 1..] {
+                    assert!(a_img != b_img, "{} and {} look the same", a, b);
+                }
+            }
         }
     }
 }
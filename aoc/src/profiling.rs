@@ -0,0 +1,197 @@
+//! Named scoped timers which are aggregated into a summary table.
+//!
+//! Timers nest: a [Timer](struct.Timer.html) created while another is running
+//! on the same thread is recorded under the label `outer/inner`.
+//!
+//! ```
+//! use aoc::profiling::{self, Timer};
+//!
+//! {
+//!     let _timer = Timer::new("doc_solve");
+//!     for _ in 0..3 {
+//!         let _timer = Timer::new("step");
+//!     }
+//! }
+//!
+//! let summary = profiling::summary();
+//! assert_eq!(summary.get("doc_solve").unwrap().count, 1);
+//! assert_eq!(summary.get("doc_solve/step").unwrap().count, 3);
+//! println!("{}", summary);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+static RECORDS: Mutex<BTreeMap<String, TimingStats>> = Mutex::new(BTreeMap::new());
+
+thread_local! {
+    static ACTIVE_LABELS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Times the scope it lives in, recording the duration when dropped.
+#[derive(Debug)]
+pub struct Timer {
+    start_time: Instant,
+    label: String,
+}
+
+impl Timer {
+    pub fn new(name: &str) -> Timer {
+        let label = ACTIVE_LABELS.with(|labels| {
+            let mut labels = labels.borrow_mut();
+            let label = match labels.last() {
+                Some(parent) => format!("{}/{}", parent, name),
+                None => name.to_string(),
+            };
+            labels.push(label.clone());
+            label
+        });
+        Timer {
+            start_time: Instant::now(),
+            label,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        let duration = Instant::now() - self.start_time;
+        ACTIVE_LABELS.with(|labels| {
+            let mut labels = labels.borrow_mut();
+            if let Some(index) = labels.iter().rposition(|l| *l == self.label) {
+                labels.remove(index);
+            }
+        });
+        record(&self.label, duration);
+    }
+}
+
+/// Add a duration to the statistics for `label` without using a Timer.
+pub fn record(label: &str, duration: Duration) {
+    let mut records = RECORDS.lock().unwrap();
+    let stats = records.entry(label.to_string()).or_default();
+    stats.count += 1;
+    stats.total += duration;
+}
+
+/// A snapshot of everything recorded so far.
+pub fn summary() -> Summary {
+    Summary {
+        entries: RECORDS.lock().unwrap().clone(),
+    }
+}
+
+/// Forget everything recorded so far.
+pub fn reset() {
+    RECORDS.lock().unwrap().clear();
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TimingStats {
+    pub count: u32,
+    pub total: Duration,
+}
+
+impl TimingStats {
+    pub fn mean(&self) -> Duration {
+        if self.count == 0 {
+            Duration::default()
+        } else {
+            self.total / self.count
+        }
+    }
+}
+
+/// Timing statistics for each label, which displays as a table sorted by
+/// label so that nested timers follow their parents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    entries: BTreeMap<String, TimingStats>,
+}
+
+impl Summary {
+    pub fn get(&self, label: &str) -> Option<&TimingStats> {
+        self.entries.get(label)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &TimingStats)> {
+        self.entries
+            .iter()
+            .map(|(label, stats)| (label.as_str(), stats))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .entries
+            .keys()
+            .map(|label| label.len())
+            .chain(std::iter::once("label".len()))
+            .max()
+            .unwrap();
+        writeln!(
+            f,
+            "{:<width$}  {:>8}  {:>14}  {:>14}",
+            "label",
+            "count",
+            "total",
+            "mean",
+            width = width
+        )?;
+        for (label, stats) in self.iter() {
+            writeln!(
+                f,
+                "{:<width$}  {:>8}  {:>14}  {:>14}",
+                label,
+                stats.count,
+                format!("{:.3?}", stats.total),
+                format!("{:.3?}", stats.mean()),
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_timers() {
+        {
+            let outer = Timer::new("test_outer");
+            assert_eq!(outer.label(), "test_outer");
+            for _ in 0..2 {
+                let inner = Timer::new("inner");
+                assert_eq!(inner.label(), "test_outer/inner");
+            }
+        }
+        record("test_outer/manual", Duration::from_millis(30));
+        record("test_outer/manual", Duration::from_millis(10));
+
+        let summary = summary();
+        assert_eq!(summary.get("test_outer").unwrap().count, 1);
+        assert_eq!(summary.get("test_outer/inner").unwrap().count, 2);
+        let manual = summary.get("test_outer/manual").unwrap();
+        assert_eq!(manual.total, Duration::from_millis(40));
+        assert_eq!(manual.mean(), Duration::from_millis(20));
+        assert!(summary.get("inner").is_none());
+
+        let table = summary.to_string();
+        assert!(table.lines().next().unwrap().starts_with("label"));
+        assert!(table.lines().any(|l| l.starts_with("test_outer/inner ")));
+    }
+}
@@ -0,0 +1,424 @@
+//! Rendering grids as images.
+//!
+//! Saving to PNG needs the `image` feature, and saving animated GIFs needs the
+//! `animation` feature.
+
+use crate::geom::{Dimensions, Grid, Rect, Vector2D};
+use crate::Error;
+use std::collections::HashMap;
+
+/// A colour with 8 bits per channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb::new(0, 0, 0);
+    pub const WHITE: Rgb = Rgb::new(255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8) -> Rgb {
+        Rgb { r, g, b }
+    }
+
+    pub const fn grey(level: u8) -> Rgb {
+        Rgb::new(level, level, level)
+    }
+}
+
+/// Lit pixels are white on black, as in puzzle images.
+impl From<bool> for Rgb {
+    fn from(lit: bool) -> Rgb {
+        if lit {
+            Rgb::WHITE
+        } else {
+            Rgb::BLACK
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from((r, g, b): (u8, u8, u8)) -> Rgb {
+        Rgb::new(r, g, b)
+    }
+}
+
+/// The pixels of `grid` as RGB triples in row-major order, with each cell
+/// drawn as a `scale` by `scale` square. Returns the width and height of the
+/// image along with the pixels.
+///
+/// ```
+/// use aoc::geom::{Dimensions, Grid};
+/// use aoc::render::to_rgb_pixels;
+///
+/// let grid = Grid::from_vec(Dimensions::new_const(2, 1), vec![true, false]);
+/// let (width, height, pixels) = to_rgb_pixels(&grid, 2);
+/// assert_eq!((width, height), (4, 2));
+/// assert_eq!(&pixels[..12], &[255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0]);
+/// ```
+pub fn to_rgb_pixels<T>(grid: &Grid<T>, scale: usize) -> (usize, usize, Vec<u8>)
+where
+    T: Clone + Into<Rgb>,
+{
+    assert!(scale > 0, "Scale must be positive");
+    let dimensions = grid.dimensions();
+    let (width, height) = (dimensions.width * scale, dimensions.height * scale);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for row in grid.rows() {
+        let colours = row.iter().map(|c| c.clone().into()).collect::<Vec<Rgb>>();
+        for _ in 0..scale {
+            for colour in colours.iter() {
+                for _ in 0..scale {
+                    pixels.extend_from_slice(&[colour.r, colour.g, colour.b]);
+                }
+            }
+        }
+    }
+    (width, height, pixels)
+}
+
+/// Save `grid` as a PNG with one pixel per cell.
+#[cfg(feature = "image")]
+pub fn save_png<T, P>(grid: &Grid<T>, path: P) -> Result<(), Error>
+where
+    T: Clone + Into<Rgb>,
+    P: AsRef<std::path::Path>,
+{
+    save_png_scaled(grid, 1, path)
+}
+
+/// Save `grid` as a PNG with each cell drawn as a `scale` by `scale` square,
+/// since puzzle images are often tiny.
+#[cfg(feature = "image")]
+pub fn save_png_scaled<T, P>(grid: &Grid<T>, scale: usize, path: P) -> Result<(), Error>
+where
+    T: Clone + Into<Rgb>,
+    P: AsRef<std::path::Path>,
+{
+    use std::convert::TryFrom;
+
+    let path = path.as_ref();
+    let context = format!("Can't save '{}'", path.display());
+    let unsupported = |e: &dyn std::fmt::Display| Error::Unsupported(format!("{}: {}", context, e));
+    let error = |e: png::EncodingError| match e {
+        png::EncodingError::IoError(e) => Error::io(&context, e),
+        e => unsupported(&e),
+    };
+
+    let (width, height, pixels) = to_rgb_pixels(grid, scale);
+    let width = u32::try_from(width).map_err(|e| unsupported(&e))?;
+    let height = u32::try_from(height).map_err(|e| unsupported(&e))?;
+
+    let file = std::fs::File::create(path).map_err(|e| Error::io(&context, e))?;
+    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(error)?;
+    writer.write_image_data(&pixels).map_err(error)
+}
+
+/// Which way positive y points when rendering text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YAxis {
+    /// Rows are drawn from the lowest y, as for screen coordinates.
+    Down,
+    /// Rows are drawn from the highest y, as for mathematical coordinates.
+    Up,
+}
+
+/// Draw every position within the bounding box of `map`, with y pointing
+/// down and unset positions drawn as `default_char`. Each row ends in a
+/// newline, and an empty map renders as an empty string.
+///
+/// ```
+/// use aoc::geom::Vector2D;
+/// use aoc::render::render_sparse;
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert(Vector2D::new(-1, 0), '#');
+/// map.insert(Vector2D::new(1, 1), '@');
+/// assert_eq!(render_sparse(&map, '.'), "#..\n..@\n");
+/// ```
+pub fn render_sparse(map: &HashMap<Vector2D, char>, default_char: char) -> String {
+    render_sparse_oriented(map, default_char, YAxis::Down)
+}
+
+/// The same as [render_sparse](fn.render_sparse.html), but with a choice of
+/// which way y points.
+pub fn render_sparse_oriented(
+    map: &HashMap<Vector2D, char>,
+    default_char: char,
+    y_axis: YAxis,
+) -> String {
+    let bounds = match Rect::bounding(map.keys().copied()) {
+        Some(bounds) => bounds,
+        None => return String::new(),
+    };
+    let rows: Vec<i64> = match y_axis {
+        YAxis::Down => (bounds.top_left.y..=bounds.bottom_right.y).collect(),
+        YAxis::Up => (bounds.top_left.y..=bounds.bottom_right.y).rev().collect(),
+    };
+    let mut canvas = String::new();
+    for y in rows {
+        for x in bounds.top_left.x..=bounds.bottom_right.x {
+            let c = map.get(&Vector2D::new(x, y)).copied();
+            canvas.push(c.unwrap_or(default_char));
+        }
+        canvas.push('\n');
+    }
+    canvas
+}
+
+/// Records a sequence of grids as the frames of an animation, such as a game
+/// being played or a map being explored.
+///
+/// Frames may have different sizes. Each is drawn from the top left of a
+/// canvas big enough for the largest, on the background colour.
+///
+/// ```
+/// use aoc::geom::{Dimensions, Grid, Vector2D};
+/// use aoc::render::Animation;
+///
+/// let mut animation = Animation::new(4, 100);
+/// for i in 0..3 {
+///     let mut frame = Grid::filled(Dimensions::new_const(3, 1), false);
+///     frame[Vector2D::new(i as i64, 0)] = true;
+///     animation.add_frame(&frame).unwrap();
+/// }
+/// assert_eq!(animation.num_frames(), 3);
+/// assert_eq!(animation.dimensions(), Dimensions::new_const(12, 4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Animation {
+    scale: usize,
+    frame_delay_ms: u32,
+    background: Rgb,
+    palette: Vec<Rgb>,
+    frames: Vec<Grid<u8>>,
+}
+
+impl Animation {
+    /// An empty animation where each cell is drawn as a `scale` by `scale`
+    /// square, showing each frame for `frame_delay_ms` milliseconds.
+    pub fn new(scale: usize, frame_delay_ms: u32) -> Animation {
+        assert!(scale > 0, "Scale must be positive");
+        Animation {
+            scale,
+            frame_delay_ms,
+            background: Rgb::BLACK,
+            palette: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn with_background(self, background: Rgb) -> Animation {
+        Animation { background, ..self }
+    }
+
+    pub fn frame_delay_ms(&self) -> u32 {
+        self.frame_delay_ms
+    }
+
+    pub fn background(&self) -> Rgb {
+        self.background
+    }
+
+    pub fn num_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// The size in pixels of the whole animation.
+    pub fn dimensions(&self) -> Dimensions {
+        let (width, height) = self.frames.iter().fold((0, 0), |(w, h), frame| {
+            let d = frame.dimensions();
+            (w.max(d.width), h.max(d.height))
+        });
+        Dimensions::new_const(width * self.scale, height * self.scale)
+    }
+
+    /// Add a frame to the end of the animation. Fails if the animation would
+    /// need more than the 256 colours a GIF can hold.
+    pub fn add_frame<T>(&mut self, grid: &Grid<T>) -> Result<(), Error>
+    where
+        T: Clone + Into<Rgb>,
+    {
+        let mut palette = self.palette.clone();
+        let mut indices = Vec::with_capacity(grid.cells().len());
+        for colour in grid.cells().iter().map(|c| c.clone().into()) {
+            let index = match palette.iter().position(|&p| p == colour) {
+                Some(index) => index,
+                None => {
+                    palette.push(colour);
+                    palette.len() - 1
+                }
+            };
+            if index > usize::from(u8::MAX) {
+                return Err(Error::Unsupported(
+                    "Animations can't have more than 256 colours".into(),
+                ));
+            }
+            indices.push(index as u8);
+        }
+        self.palette = palette;
+        self.frames.push(Grid::from_vec(grid.dimensions(), indices));
+        Ok(())
+    }
+
+    /// Save the animation as a GIF which loops forever.
+    #[cfg(feature = "animation")]
+    pub fn save_gif<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), Error> {
+        use std::borrow::Cow;
+        use std::convert::TryFrom;
+
+        let path = path.as_ref();
+        let context = format!("Can't save '{}'", path.display());
+        let unsupported =
+            |e: &dyn std::fmt::Display| Error::Unsupported(format!("{}: {}", context, e));
+        let error = |e: gif::EncodingError| match e {
+            gif::EncodingError::Io(e) => Error::io(&context, e),
+            e => unsupported(&e),
+        };
+
+        if self.frames.is_empty() {
+            return Err(unsupported(&"There are no frames"));
+        }
+
+        let mut palette = self.palette.clone();
+        let background = match palette.iter().position(|&p| p == self.background) {
+            Some(index) => index,
+            None => {
+                palette.push(self.background);
+                palette.len() - 1
+            }
+        };
+        let background = u8::try_from(background).map_err(|e| unsupported(&e))?;
+        let palette_bytes = palette
+            .iter()
+            .flat_map(|c| vec![c.r, c.g, c.b])
+            .collect::<Vec<u8>>();
+
+        let dimensions = self.dimensions();
+        let width = u16::try_from(dimensions.width).map_err(|e| unsupported(&e))?;
+        let height = u16::try_from(dimensions.height).map_err(|e| unsupported(&e))?;
+        let delay = u16::try_from(self.frame_delay_ms / 10).map_err(|e| unsupported(&e))?;
+
+        let file = std::fs::File::create(path).map_err(|e| Error::io(&context, e))?;
+        let writer = std::io::BufWriter::new(file);
+        let mut encoder =
+            gif::Encoder::new(writer, width, height, &palette_bytes).map_err(error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(error)?;
+
+        for frame in self.frames.iter() {
+            let mut pixels = vec![background; dimensions.area()];
+            for (pos, &index) in frame.iter() {
+                for dy in 0..self.scale {
+                    let y = pos.y as usize * self.scale + dy;
+                    let start = y * dimensions.width + pos.x as usize * self.scale;
+                    for pixel in pixels[start..start + self.scale].iter_mut() {
+                        *pixel = index;
+                    }
+                }
+            }
+            let frame = gif::Frame {
+                width,
+                height,
+                delay,
+                buffer: Cow::Owned(pixels),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Dimensions;
+
+    #[test]
+    fn test_rgb_pixels() {
+        let colours = vec![Rgb::new(1, 2, 3), Rgb::grey(9), Rgb::BLACK, Rgb::WHITE];
+        let grid = Grid::from_vec(Dimensions::new_const(2, 2), colours);
+        let (width, height, pixels) = to_rgb_pixels(&grid, 1);
+        assert_eq!((width, height), (2, 2));
+        assert_eq!(pixels, [1, 2, 3, 9, 9, 9, 0, 0, 0, 255, 255, 255]);
+
+        let (width, height, pixels) = to_rgb_pixels(&grid, 3);
+        assert_eq!((width, height), (6, 6));
+        assert_eq!(pixels.len(), 6 * 6 * 3);
+        assert_eq!(&pixels[6 * 3 * 2 + 3 * 3..6 * 3 * 2 + 3 * 4], &[9, 9, 9]);
+        assert_eq!(&pixels[6 * 3 * 5 + 3 * 5..], &[255, 255, 255]);
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn test_save_png() {
+        let grid = Grid::from_vec(Dimensions::new_const(3, 1), vec![true, false, true]);
+        let path = std::env::temp_dir().join(format!("aoc_render_{}.png", std::process::id()));
+        save_png_scaled(&grid, 4, &path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[1..4], b"PNG");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_render_sparse_orientation() {
+        let map: HashMap<_, _> = vec![
+            (Vector2D::new(0, 0), 'a'),
+            (Vector2D::new(2, 0), 'b'),
+            (Vector2D::new(0, -2), 'c'),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(render_sparse(&map, ' '), "c  \n   \na b\n");
+        assert_eq!(
+            render_sparse_oriented(&map, ' ', YAxis::Up),
+            "a b\n   \nc  \n"
+        );
+        assert_eq!(render_sparse(&HashMap::new(), ' '), "");
+    }
+
+    #[test]
+    fn test_animation_frames() {
+        let mut animation = Animation::new(2, 50).with_background(Rgb::grey(7));
+        let small = Grid::from_vec(Dimensions::new_const(1, 2), vec![true, false]);
+        let large = Grid::filled(Dimensions::new_const(3, 1), Rgb::new(1, 2, 3));
+        animation.add_frame(&small).unwrap();
+        animation.add_frame(&large).unwrap();
+        assert_eq!(animation.num_frames(), 2);
+        assert_eq!(animation.dimensions(), Dimensions::new_const(6, 4));
+        assert_eq!(animation.frame_delay_ms(), 50);
+        assert_eq!(animation.background(), Rgb::grey(7));
+
+        let too_many = Grid::from_vec(
+            Dimensions::new_const(300, 1),
+            (0..300)
+                .map(|i| Rgb::new((i % 256) as u8, (i / 256) as u8, 0))
+                .collect(),
+        );
+        assert!(animation.add_frame(&too_many).is_err());
+        assert_eq!(animation.num_frames(), 2);
+    }
+
+    #[cfg(feature = "animation")]
+    #[test]
+    fn test_save_gif() {
+        let mut animation = Animation::new(3, 100);
+        for i in 0..4 {
+            let cells = (0..8).map(|c| c == i).collect();
+            let frame = Grid::from_vec(Dimensions::new_const(4, 2), cells);
+            animation.add_frame(&frame).unwrap();
+        }
+        let path = std::env::temp_dir().join(format!("aoc_render_{}.gif", std::process::id()));
+        animation.save_gif(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        assert_eq!(&bytes[..6], b"GIF89a");
+        std::fs::remove_file(&path).unwrap();
+        assert!(Animation::new(1, 10).save_gif(&path).is_err());
+    }
+}
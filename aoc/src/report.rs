@@ -0,0 +1,221 @@
+//! Collecting the answers and timings from running solutions.
+
+use std::fmt;
+use std::time::Duration;
+
+/// The answer to one part of a day's puzzle and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+/// The results of running any number of solutions, in the order they ran.
+///
+/// ```
+/// use aoc::report::RunReport;
+/// use std::time::Duration;
+///
+/// let mut report = RunReport::new();
+/// report.record(1, 1, 3325342, Duration::from_millis(2));
+/// report.record(1, 2, "HCGFE", Duration::from_micros(1500));
+/// assert_eq!(report.total_duration(), Duration::from_micros(3500));
+/// assert_eq!(
+///     report.to_json(),
+///     concat!(
+///         r#"{"results":[{"day":1,"part":1,"answer":"3325342","duration_ms":2.000},"#,
+///         r#"{"day":1,"part":2,"answer":"HCGFE","duration_ms":1.500}],"total_ms":3.500}"#
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunReport {
+    results: Vec<PartResult>,
+}
+
+impl RunReport {
+    pub fn new() -> RunReport {
+        RunReport::default()
+    }
+
+    pub fn record<T: ToString>(&mut self, day: u32, part: u32, answer: T, duration: Duration) {
+        self.results.push(PartResult {
+            day,
+            part,
+            answer: answer.to_string(),
+            duration,
+        });
+    }
+
+    pub fn results(&self) -> &[PartResult] {
+        &self.results
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.results.iter().map(|r| r.duration).sum()
+    }
+
+    /// The report as a JSON object with a `results` array and the total time,
+    /// with durations in fractional milliseconds.
+    pub fn to_json(&self) -> String {
+        let results = self
+            .results
+            .iter()
+            .map(|r| {
+                format!(
+                    r#"{{"day":{},"part":{},"answer":{},"duration_ms":{:.3}}}"#,
+                    r.day,
+                    r.part,
+                    json_string(&r.answer),
+                    as_millis(r.duration)
+                )
+            })
+            .collect::<Vec<_>>();
+        format!(
+            r#"{{"results":[{}],"total_ms":{:.3}}}"#,
+            results.join(","),
+            as_millis(self.total_duration())
+        )
+    }
+
+    /// The report as CSV with a header line and a row per part, with
+    /// durations in fractional milliseconds.
+    ///
+    /// ```
+    /// use aoc::report::RunReport;
+    /// use std::time::Duration;
+    ///
+    /// let mut report = RunReport::new();
+    /// report.record(8, 2, "HCGFE", Duration::from_micros(1500));
+    /// assert_eq!(report.to_csv(), "day,part,answer,duration_ms\n8,2,HCGFE,1.500\n");
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("day,part,answer,duration_ms\n");
+        for r in &self.results {
+            csv.push_str(&format!(
+                "{},{},{},{:.3}\n",
+                r.day,
+                r.part,
+                csv_field(&r.answer),
+                as_millis(r.duration)
+            ));
+        }
+        csv
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// An aligned table with a row per part and a final row for the total time.
+impl fmt::Display for RunReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let answer_width = self
+            .results
+            .iter()
+            .map(|r| r.answer.chars().count())
+            .chain(std::iter::once("answer".len()))
+            .max()
+            .unwrap();
+        writeln!(
+            f,
+            "{:>3}  {:>4}  {:<width$}  {:>12}",
+            "day",
+            "part",
+            "answer",
+            "time",
+            width = answer_width
+        )?;
+        for r in self.results.iter() {
+            writeln!(
+                f,
+                "{:>3}  {:>4}  {:<width$}  {:>12}",
+                r.day,
+                r.part,
+                r.answer,
+                format!("{:.3?}", r.duration),
+                width = answer_width
+            )?;
+        }
+        writeln!(
+            f,
+            "{:>3}  {:>4}  {:<width$}  {:>12}",
+            "",
+            "",
+            "total",
+            format!("{:.3?}", self.total_duration()),
+            width = answer_width
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_table() {
+        let mut report = RunReport::new();
+        assert!(report.is_empty());
+        report.record(8, 2, "HCGFE", Duration::from_millis(3));
+        report.record(12, 2, 324_618_307_124_784u64, Duration::from_millis(150));
+        assert_eq!(report.results()[1].answer, "324618307124784");
+
+        let table = report.to_string();
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "day  part  answer                   time");
+        assert_eq!(lines[1], "  8     2  HCGFE                 3.000ms");
+        assert_eq!(lines[2], " 12     2  324618307124784     150.000ms");
+        assert_eq!(lines[3], "           total               153.000ms");
+    }
+
+    #[test]
+    fn test_json_escaping() {
+        assert_eq!(json_string("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+        assert_eq!(
+            RunReport::new().to_json(),
+            r#"{"results":[],"total_ms":0.000}"#
+        );
+    }
+
+    #[test]
+    fn test_csv_quoting() {
+        assert_eq!(csv_field("1703"), "1703");
+        assert_eq!(csv_field("a,b"), r#""a,b""#);
+        assert_eq!(csv_field("say \"hi\""), r#""say ""hi""""#);
+        assert_eq!(RunReport::new().to_csv(), "day,part,answer,duration_ms\n");
+    }
+}
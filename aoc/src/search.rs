@@ -0,0 +1,170 @@
+//! Searches over ordered ranges of integers and sequences of states.
+
+pub use crate::graph::{ida_star, ida_star_with_table, iddfs};
+use num::PrimInt;
+
+/// The largest value in `lo..=hi` for which `predicate` holds, assuming that
+/// it holds for every value up to some point and for none after it. Returns
+/// None if it doesn't hold for `lo`.
+///
+/// ```
+/// use aoc::search::binary_search_max;
+///
+/// assert_eq!(binary_search_max(0u64, 1000, |n| n * n <= 200), Some(14));
+/// assert_eq!(binary_search_max(20u64, 1000, |n| n * n <= 200), None);
+/// ```
+pub fn binary_search_max<T, P>(lo: T, hi: T, mut predicate: P) -> Option<T>
+where
+    T: PrimInt,
+    P: FnMut(T) -> bool,
+{
+    if lo > hi || !predicate(lo) {
+        return None;
+    }
+
+    // predicate(lo) is always true, and predicate(hi) is unknown
+    let (mut lo, mut hi) = (lo, hi);
+    while lo < hi {
+        // rounding up, without overflowing
+        let mid = (lo | hi) - ((lo ^ hi) >> 1);
+        if predicate(mid) {
+            lo = mid;
+        } else {
+            hi = mid - T::one();
+        }
+    }
+    Some(lo)
+}
+
+/// The smallest value in `lo..=hi` for which `predicate` holds, assuming
+/// that it holds for none of the values up to some point and every value
+/// after it. Returns None if it doesn't hold for `hi`.
+///
+/// ```
+/// use aoc::search::binary_search_min;
+///
+/// assert_eq!(binary_search_min(0u64, 1000, |n| n * n >= 200), Some(15));
+/// assert_eq!(binary_search_min(0u64, 10, |n| n * n >= 200), None);
+/// ```
+pub fn binary_search_min<T, P>(lo: T, hi: T, mut predicate: P) -> Option<T>
+where
+    T: PrimInt,
+    P: FnMut(T) -> bool,
+{
+    if lo > hi || !predicate(hi) {
+        return None;
+    }
+
+    // predicate(hi) is always true, and predicate(lo) is unknown
+    let (mut lo, mut hi) = (lo, hi);
+    while lo < hi {
+        // rounding down, without overflowing
+        let mid = (lo & hi) + ((lo ^ hi) >> 1);
+        if predicate(mid) {
+            hi = mid;
+        } else {
+            lo = mid + T::one();
+        }
+    }
+    Some(lo)
+}
+
+/// Finds the cycle that repeatedly applying `step` to `initial` must
+/// eventually enter, using Brent's algorithm. Returns the number of states
+/// before the cycle starts, and the length of the cycle.
+///
+/// Only a couple of states are held at a time, so they don't need to be
+/// hashable. This never returns if the states don't repeat.
+///
+/// ```
+/// use aoc::search::find_cycle;
+///
+/// // 2, 4, 8, 16, 12, 4, ...
+/// assert_eq!(find_cycle(2, |&n| (n * 2) % 20), (1, 4));
+/// ```
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> (usize, usize)
+where
+    S: Clone + PartialEq,
+    F: FnMut(&S) -> S,
+{
+    // find the cycle length by teleporting the tortoise to the hare at each
+    // power of two until the hare catches up with it
+    let mut power = 1;
+    let mut cycle_length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == cycle_length {
+            tortoise = hare.clone();
+            power *= 2;
+            cycle_length = 0;
+        }
+        hare = step(&hare);
+        cycle_length += 1;
+    }
+
+    // walk two states a cycle apart until they meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..cycle_length {
+        hare = step(&hare);
+    }
+    let mut prefix_length = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix_length += 1;
+    }
+
+    (prefix_length, cycle_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_search_bounds() {
+        for limit in 0..20 {
+            assert_eq!(binary_search_max(0, 19, |n| n <= limit), Some(limit));
+            assert_eq!(binary_search_min(0, 19, |n| n >= limit), Some(limit));
+        }
+        assert_eq!(binary_search_max(3, 3, |_| true), Some(3));
+        assert_eq!(binary_search_max(4, 3, |_| true), None);
+        assert_eq!(
+            binary_search_min(i8::MIN, i8::MAX, |n| n >= -100),
+            Some(-100)
+        );
+        assert_eq!(binary_search_max(0, u64::MAX, |_| true), Some(u64::MAX));
+        assert_eq!(binary_search_min(0, u64::MAX, |n| n > 0), Some(1));
+    }
+
+    #[test]
+    fn test_binary_search_calls() {
+        let mut calls = 0;
+        let result = binary_search_max(0u32, 1 << 20, |n| {
+            calls += 1;
+            n < 12345
+        });
+        assert_eq!(result, Some(12344));
+        assert!(calls <= 22);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        assert_eq!(find_cycle(0, |_| 0), (0, 1));
+        assert_eq!(find_cycle(0, |&n| (n + 1) % 7), (0, 7));
+        for prefix in 0..10 {
+            for length in 1..10 {
+                let step = |&n: &usize| {
+                    if n + 1 < prefix + length {
+                        n + 1
+                    } else {
+                        prefix
+                    }
+                };
+                assert_eq!(find_cycle(0, step), (prefix, length));
+            }
+        }
+    }
+}
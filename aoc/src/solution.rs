@@ -0,0 +1,33 @@
+//! A common interface to every day's solution, so that days can be run
+//! programmatically against any input rather than only from their own `main`.
+
+/// Solves both parts of one day's puzzle for a given input.
+///
+/// ```
+/// use aoc::solution::Solution;
+///
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     fn part1(&self, input: &str) -> String {
+///         input.lines().map(|l| l.parse::<i32>().unwrap()).sum::<i32>().to_string()
+///     }
+///
+///     fn part2(&self, input: &str) -> String {
+///         input.lines().count().to_string()
+///     }
+/// }
+///
+/// assert_eq!(Sum.part1("1\n2\n3"), "6");
+/// assert_eq!(Sum.part2("1\n2\n3"), "3");
+/// ```
+pub trait Solution {
+    fn part1(&self, input: &str) -> String;
+    fn part2(&self, input: &str) -> String;
+}
+
+/// Print the answers to both parts, as each day's binary does.
+pub fn print_answers(solution: &dyn Solution, input: &str) {
+    println!("part1 = {}", solution.part1(input));
+    println!("part2 = {}", solution.part2(input));
+}
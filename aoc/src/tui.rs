@@ -0,0 +1,249 @@
+//! Live visualizations in the terminal, drawn one frame at a time.
+//!
+//! While a visualization is running, space pauses or resumes it, `n` steps
+//! forward one frame while paused, `+` and `-` change the frame rate and `q`
+//! or escape quits.
+//!
+//! Drawing to the terminal needs the `tui` feature.
+
+use std::time::Duration;
+
+/// What the caller should do after drawing a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    Continue,
+    Quit,
+}
+
+/// The playback state of a visualization, as changed by key presses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Playback {
+    frames_per_second: f64,
+    paused: bool,
+    step: bool,
+    quit: bool,
+}
+
+impl Playback {
+    pub const MIN_FRAMES_PER_SECOND: f64 = 0.5;
+    pub const MAX_FRAMES_PER_SECOND: f64 = 1000.0;
+
+    pub fn new(frames_per_second: f64) -> Playback {
+        Playback {
+            frames_per_second: frames_per_second.clamp(
+                Playback::MIN_FRAMES_PER_SECOND,
+                Playback::MAX_FRAMES_PER_SECOND,
+            ),
+            paused: false,
+            step: false,
+            quit: false,
+        }
+    }
+
+    pub fn frames_per_second(&self) -> f64 {
+        self.frames_per_second
+    }
+
+    pub fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.frames_per_second)
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn is_quitting(&self) -> bool {
+        self.quit
+    }
+
+    /// Update the state for a key press. Escape should be passed as `'q'`.
+    ///
+    /// ```
+    /// use aoc::tui::Playback;
+    ///
+    /// let mut playback = Playback::new(10.0);
+    /// playback.handle_key(' ');
+    /// assert!(playback.is_paused());
+    /// playback.handle_key('+');
+    /// assert_eq!(playback.frames_per_second(), 20.0);
+    /// ```
+    pub fn handle_key(&mut self, key: char) {
+        match key.to_ascii_lowercase() {
+            ' ' => self.paused = !self.paused,
+            'n' | '.' => self.step = true,
+            '+' | '=' => self.change_speed(2.0),
+            '-' | '_' => self.change_speed(0.5),
+            'q' => self.quit = true,
+            _ => (),
+        }
+    }
+
+    fn change_speed(&mut self, factor: f64) {
+        *self = Playback {
+            paused: self.paused,
+            step: self.step,
+            quit: self.quit,
+            ..Playback::new(self.frames_per_second * factor)
+        };
+    }
+
+    /// Whether the next frame can be shown, which uses up any pending step.
+    pub fn take_frame(&mut self) -> bool {
+        if !self.paused {
+            true
+        } else if self.step {
+            self.step = false;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Takes over the terminal to draw frames, restoring it when dropped.
+///
+/// ```no_run
+/// use aoc::tui::{Control, Visualizer};
+///
+/// let mut visualizer = Visualizer::new(30.0).unwrap();
+/// for i in 0..100 {
+///     let frame = format!("{}\n{}", i, "#".repeat(i));
+///     if visualizer.draw(&frame).unwrap() == Control::Quit {
+///         break;
+///     }
+/// }
+/// ```
+#[cfg(feature = "tui")]
+#[derive(Debug)]
+pub struct Visualizer {
+    playback: Playback,
+    last_frame: Option<std::time::Instant>,
+}
+
+#[cfg(feature = "tui")]
+impl Visualizer {
+    pub fn new(frames_per_second: f64) -> Result<Visualizer, crate::Error> {
+        use crossterm::{cursor, execute, terminal};
+
+        terminal::enable_raw_mode()?;
+        execute!(
+            std::io::stdout(),
+            terminal::EnterAlternateScreen,
+            cursor::Hide
+        )?;
+        Ok(Visualizer {
+            playback: Playback::new(frames_per_second),
+            last_frame: None,
+        })
+    }
+
+    pub fn playback(&self) -> &Playback {
+        &self.playback
+    }
+
+    /// Replace the screen with `frame`, then wait until it is time for the
+    /// next one, handling any keys pressed in the meantime.
+    pub fn draw(&mut self, frame: &str) -> Result<Control, crate::Error> {
+        use crossterm::{cursor, queue, style, terminal};
+        use std::io::Write;
+        use std::time::Instant;
+
+        let mut stdout = std::io::stdout();
+        queue!(
+            stdout,
+            terminal::Clear(terminal::ClearType::All),
+            cursor::MoveTo(0, 0)
+        )?;
+        for line in frame.lines() {
+            queue!(stdout, style::Print(line), cursor::MoveToNextLine(1))?;
+        }
+        stdout.flush()?;
+
+        loop {
+            let elapsed = self.last_frame.map_or(Duration::MAX, |t| t.elapsed());
+            let remaining = self.playback.frame_duration().saturating_sub(elapsed);
+            if remaining == Duration::ZERO && self.playback.take_frame() {
+                break;
+            }
+            let timeout = if remaining == Duration::ZERO {
+                Duration::from_millis(50)
+            } else {
+                remaining
+            };
+            self.poll_keys(timeout)?;
+            if self.playback.is_quitting() {
+                return Ok(Control::Quit);
+            }
+        }
+        self.last_frame = Some(Instant::now());
+        Ok(Control::Continue)
+    }
+
+    fn poll_keys(&mut self, timeout: Duration) -> Result<(), crate::Error> {
+        use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+
+        if !event::poll(timeout)? {
+            return Ok(());
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Release {
+                return Ok(());
+            }
+            match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.playback.handle_key('q')
+                }
+                KeyCode::Char(c) => self.playback.handle_key(c),
+                KeyCode::Esc => self.playback.handle_key('q'),
+                KeyCode::Right => self.playback.handle_key('n'),
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tui")]
+impl Drop for Visualizer {
+    fn drop(&mut self) {
+        use crossterm::{cursor, execute, terminal};
+
+        let _ = execute!(
+            std::io::stdout(),
+            cursor::Show,
+            terminal::LeaveAlternateScreen
+        );
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_playback_keys() {
+        let mut playback = Playback::new(10.0);
+        assert!(playback.take_frame());
+        assert_eq!(playback.frame_duration(), Duration::from_millis(100));
+
+        playback.handle_key(' ');
+        assert!(!playback.take_frame());
+        playback.handle_key('n');
+        assert!(playback.take_frame());
+        assert!(!playback.take_frame());
+        playback.handle_key(' ');
+        assert!(playback.take_frame());
+
+        for _ in 0..20 {
+            playback.handle_key('-');
+        }
+        assert_eq!(
+            playback.frames_per_second(),
+            Playback::MIN_FRAMES_PER_SECOND
+        );
+        assert!(!playback.is_quitting());
+        playback.handle_key('Q');
+        assert!(playback.is_quitting());
+    }
+}
@@ -0,0 +1,257 @@
+//! Run the solution to any day of Advent of Code 2019.
+//!
+//! ```text
+//! aoc2019 run <day>...
+//! aoc2019 run all
+//! aoc2019 run <day> --input <path>
+//! aoc2019 all
+//! aoc2019 list
+//! ```
+//!
+//! `run` and `all` take `--format json` or `--format csv` to print only the
+//! results, with each part's day, answer and duration, for other programs to
+//! read.
+//!
+//! With `--verify`, each answer is also checked against those saved in
+//! [aoc::answers], exiting with an error if any are wrong. Parts without a
+//! saved answer are listed as unknown. The saved answers are read from
+//! `answers.txt` in the input directory, and `answers.example.txt` has the
+//! answers for the bundled inputs to copy there.
+//!
+//! Days are run against the inputs found by [aoc::input], which falls back on
+//! the ones bundled with them. A single day can be given its own input with
+//! `--input <path>`, or `--input -` to read stdin.
+//!
+//! Each part's answer is printed as soon as it is found, followed by a table
+//! of every answer and how long it took. `all` runs every day in parallel and
+//! prints just the table, along with the wall time of the whole run.
+
+mod registry;
+
+use aoc::answers::{Answers, Verification, ANSWERS_FILE};
+use aoc::input::{self, InputLoader};
+use aoc::report::RunReport;
+use aoc::solution::Solution;
+use rayon::prelude::*;
+use registry::{Day, DAYS};
+use std::env;
+use std::process;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: aoc2019 run <day>... | aoc2019 run <day> --input <path|-> | \
+                     aoc2019 all | aoc2019 list, with [--format text|json|csv] [--verify]";
+
+/// How to print the results of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// Remove `--format` and its value from `args`, if it is there.
+    fn take(args: &mut Vec<String>) -> Result<Format, String> {
+        let index = match args.iter().position(|a| a == "--format") {
+            Some(index) => index,
+            None => return Ok(Format::Text),
+        };
+        if index + 1 >= args.len() {
+            return Err("--format needs one of text, json or csv".into());
+        }
+        let value = args.remove(index + 1);
+        args.remove(index);
+        match value.as_str() {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format '{}'", value)),
+        }
+    }
+
+    fn print(self, report: &RunReport) {
+        match self {
+            Format::Text => print!("{}", report),
+            Format::Json => println!("{}", report.to_json()),
+            Format::Csv => print!("{}", report.to_csv()),
+        }
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let loader = InputLoader::from_args(&mut args).unwrap_or_else(|e| usage_error(e));
+    let format = Format::take(&mut args).unwrap_or_else(|e| usage_error(e));
+    let verify = take_flag(&mut args, "--verify");
+    let report = match args.first().map(String::as_str) {
+        Some("list") if args.len() == 1 => {
+            for day in DAYS {
+                println!("day{:02}", day.day);
+            }
+            return;
+        }
+        Some("all") if args.len() == 1 && !loader.is_explicit() => {
+            let days = DAYS.iter().collect::<Vec<_>>();
+            let inputs = load_inputs(&loader, &days);
+            let start = Instant::now();
+            let report = run_parallel(&days, &inputs);
+            let wall_time = start.elapsed();
+            format.print(&report);
+            if format == Format::Text {
+                println!("\nAll of 2019 in {:.3?}", wall_time);
+            }
+            report
+        }
+        Some("run") if args.len() > 1 => {
+            let days = parse_days(&args[1..]).unwrap_or_else(|e| usage_error(e));
+            if loader.is_explicit() && days.len() > 1 {
+                usage_error("--input can only be used with a single day");
+            }
+            let inputs = load_inputs(&loader, &days);
+            let progress = format == Format::Text;
+            let report = run(&days, &inputs, progress);
+            if progress {
+                println!();
+            }
+            format.print(&report);
+            report
+        }
+        _ => usage_error(""),
+    };
+    if verify && !verify_answers(&report) {
+        process::exit(1);
+    }
+}
+
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|a| a != flag);
+    args.len() != len
+}
+
+/// Check every answer against the saved ones, reporting any which are wrong.
+/// Returns false if there were any.
+fn verify_answers(report: &RunReport) -> bool {
+    let path = input::input_dir().join(ANSWERS_FILE);
+    let answers = if path.exists() {
+        Answers::load(&path).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        })
+    } else {
+        eprintln!(
+            "No answers saved in '{}', copy answers.example.txt there to verify the bundled inputs",
+            path.display()
+        );
+        Answers::new()
+    };
+    let (mut correct, mut incorrect, mut unknown) = (0, 0, 0);
+    for r in report.results() {
+        match answers.verify(r.day, r.part, &r.answer) {
+            Verification::Correct => correct += 1,
+            Verification::Incorrect { expected } => {
+                eprintln!(
+                    "day{:02} part{} = {} is wrong, expected {}",
+                    r.day, r.part, r.answer, expected
+                );
+                incorrect += 1;
+            }
+            Verification::Unknown => {
+                eprintln!(
+                    "day{:02} part{} = {} has no saved answer",
+                    r.day, r.part, r.answer
+                );
+                unknown += 1;
+            }
+        }
+    }
+    eprintln!(
+        "{} correct, {} incorrect, {} unknown",
+        correct, incorrect, unknown
+    );
+    incorrect == 0
+}
+
+fn usage_error(message: impl ToString) -> ! {
+    let message = message.to_string();
+    if !message.is_empty() {
+        eprintln!("{}", message);
+    }
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn parse_days(args: &[String]) -> Result<Vec<&'static Day>, String> {
+    if args == ["all"] {
+        return Ok(DAYS.iter().collect());
+    }
+    args.iter()
+        .map(|arg| {
+            arg.parse()
+                .ok()
+                .and_then(registry::find)
+                .ok_or_else(|| format!("There is no day '{}'", arg))
+        })
+        .collect()
+}
+
+/// The input for each day, exiting if any can't be loaded.
+fn load_inputs(loader: &InputLoader, days: &[&Day]) -> Vec<String> {
+    days.iter()
+        .map(|day| loader.clone().with_bundled(day.input).load(day.day))
+        .collect::<aoc::Result<_>>()
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        })
+}
+
+/// Run both parts of the given days one after another, printing each answer
+/// as it is found if `progress`.
+fn run(days: &[&Day], inputs: &[String], progress: bool) -> RunReport {
+    let mut report = RunReport::new();
+    for (day, input) in days.iter().zip(inputs) {
+        for (part, answer, duration) in run_day(day, input, progress) {
+            report.record(day.day, part, answer, duration);
+        }
+    }
+    report
+}
+
+/// Run both parts of the given days, with the days spread across a thread
+/// pool.
+fn run_parallel(days: &[&Day], inputs: &[String]) -> RunReport {
+    let answers = days
+        .par_iter()
+        .zip(inputs)
+        .map(|(day, input)| run_day(day, input, false))
+        .collect::<Vec<_>>();
+    let mut report = RunReport::new();
+    for (day, parts) in days.iter().zip(answers) {
+        for (part, answer, duration) in parts {
+            report.record(day.day, part, answer, duration);
+        }
+    }
+    report
+}
+
+/// The answer to each part of a day and how long it took to find.
+fn run_day(day: &Day, input: &str, progress: bool) -> Vec<(u32, String, Duration)> {
+    let parts: [fn(&dyn Solution, &str) -> String; 2] =
+        [|s, input| s.part1(input), |s, input| s.part2(input)];
+    (1..)
+        .zip(&parts)
+        .map(|(part, solve)| {
+            let start = Instant::now();
+            let answer = solve(day.solution, input);
+            let duration = start.elapsed();
+            if progress {
+                println!(
+                    "day{:02} part{} = {} ({:.3?})",
+                    day.day, part, answer, duration
+                );
+            }
+            (part, answer, duration)
+        })
+        .collect()
+}
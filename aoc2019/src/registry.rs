@@ -0,0 +1,155 @@
+//! Every day's solution, so they can be looked up by number.
+
+use aoc::solution::Solution;
+
+/// One day's solution, along with the puzzle input bundled with it.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u32,
+    pub solution: &'static (dyn Solution + Sync),
+    pub input: &'static str,
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        solution: &day01::Day01,
+        input: day01::DAY01_INPUT,
+    },
+    Day {
+        day: 2,
+        solution: &day02::Day02,
+        input: day02::DAY02_INPUT,
+    },
+    Day {
+        day: 3,
+        solution: &day03::Day03,
+        input: day03::DAY03_INPUT,
+    },
+    Day {
+        day: 4,
+        solution: &day04::Day04,
+        input: day04::DAY04_INPUT,
+    },
+    Day {
+        day: 5,
+        solution: &day05::Day05,
+        input: day05::DAY05_INPUT,
+    },
+    Day {
+        day: 6,
+        solution: &day06::Day06,
+        input: day06::DAY06_INPUT,
+    },
+    Day {
+        day: 7,
+        solution: &day07::Day07,
+        input: day07::DAY07_INPUT,
+    },
+    Day {
+        day: 8,
+        solution: &day08::Day08,
+        input: day08::DAY08_INPUT,
+    },
+    Day {
+        day: 9,
+        solution: &day09::Day09,
+        input: day09::DAY09_INPUT,
+    },
+    Day {
+        day: 10,
+        solution: &day10::Day10,
+        input: day10::DAY10_INPUT,
+    },
+    Day {
+        day: 11,
+        solution: &day11::Day11,
+        input: day11::DAY11_INPUT,
+    },
+    Day {
+        day: 12,
+        solution: &day12::Day12,
+        input: day12::DAY12_INPUT,
+    },
+    Day {
+        day: 13,
+        solution: &day13::Day13,
+        input: day13::DAY13_INPUT,
+    },
+    Day {
+        day: 14,
+        solution: &day14::Day14,
+        input: day14::DAY14_INPUT,
+    },
+    Day {
+        day: 15,
+        solution: &day15::Day15,
+        input: day15::DAY15_INPUT,
+    },
+    Day {
+        day: 16,
+        solution: &day16::Day16,
+        input: day16::DAY16_INPUT,
+    },
+    Day {
+        day: 17,
+        solution: &day17::Day17,
+        input: day17::DAY17_INPUT,
+    },
+    Day {
+        day: 18,
+        solution: &day18::Day18 { low_memory: false },
+        input: day18::DAY18_INPUT,
+    },
+    Day {
+        day: 19,
+        solution: &day19::Day19,
+        input: day19::DAY19_INPUT,
+    },
+    Day {
+        day: 20,
+        solution: &day20::Day20,
+        input: day20::DAY20_INPUT,
+    },
+    Day {
+        day: 21,
+        solution: &day21::Day21,
+        input: day21::DAY21_INPUT,
+    },
+    Day {
+        day: 22,
+        solution: &day22::Day22,
+        input: day22::DAY22_INPUT,
+    },
+    Day {
+        day: 23,
+        solution: &day23::Day23,
+        input: day23::DAY23_INPUT,
+    },
+    Day {
+        day: 24,
+        solution: &day24::Day24,
+        input: day24::DAY24_INPUT,
+    },
+    Day {
+        day: 25,
+        solution: &day25::Day25,
+        input: day25::DAY25_INPUT,
+    },
+];
+
+pub fn find(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(DAYS.iter().map(|d| d.day).eq(1..=25));
+        assert_eq!(find(1).unwrap().solution.part1("12\n14"), "4");
+        assert!(find(26).is_none());
+    }
+}
@@ -0,0 +1,68 @@
+//! Solution to Advent of Code 2019 [Day 1](https://adventofcode.com/2019/day/1).
+
+use aoc::solution::Solution;
+
+pub const DAY01_INPUT: &str = include_str!("day01_input.txt");
+
+fn parse_masses(input: &str) -> impl Iterator<Item = i32> + '_ {
+    input.lines().map(|s| s.parse::<i32>().unwrap())
+}
+
+fn fuel_required(mass: i32) -> i32 {
+    (mass / 3) - 2
+}
+
+fn day01_part1(input: &str) -> i32 {
+    parse_masses(input).map(fuel_required).sum()
+}
+
+fn total_fuel_required(mass: i32) -> i32 {
+    let fuel_mass = fuel_required(mass);
+    if fuel_mass > 0 {
+        fuel_mass + total_fuel_required(fuel_mass)
+    } else {
+        0
+    }
+}
+
+fn day01_part2(input: &str) -> i32 {
+    parse_masses(input).map(total_fuel_required).sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn part1(&self, input: &str) -> String {
+        day01_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day01_part2(input).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fuel_required() {
+        assert_eq!(fuel_required(12), 2);
+        assert_eq!(fuel_required(14), 2);
+        assert_eq!(fuel_required(1969), 654);
+        assert_eq!(fuel_required(100_756), 33583);
+    }
+
+    #[test]
+    fn test_total_fuel_required() {
+        assert_eq!(total_fuel_required(14), 2);
+        assert_eq!(total_fuel_required(1969), 966);
+        assert_eq!(total_fuel_required(100_756), 50346);
+    }
+
+    #[test]
+    fn test_day01() {
+        assert_eq!(day01_part1(DAY01_INPUT), 3_325_342);
+        assert_eq!(day01_part2(DAY01_INPUT), 4_985_158);
+    }
+}
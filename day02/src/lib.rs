@@ -0,0 +1,51 @@
+//! Solution to Advent of Code 2019 [Day 2](https://adventofcode.com/2019/day/2).
+
+use aoc::intcode::{Machine, Program};
+use aoc::solution::Solution;
+
+pub const DAY02_INPUT: &str = include_str!("day02_input.txt");
+
+fn run_machine(program: &Program, noun: i64, verb: i64) -> i64 {
+    let mut p = (*program).clone();
+    p.write(1, noun);
+    p.write(2, verb);
+    let mut m = Machine::new(&p);
+    m.run();
+    m.read(0)
+}
+
+fn day02_part1(program: &Program) -> i64 {
+    run_machine(program, 12, 2)
+}
+
+fn day02_part2(program: &Program) -> i64 {
+    let target = 19_690_720;
+    for n in 0..100 {
+        for v in 0..100 {
+            let out = run_machine(program, n, v);
+            if out == target {
+                return (100 * n) + v;
+            }
+        }
+    }
+    panic!("Failed to find answer");
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn part1(&self, input: &str) -> String {
+        day02_part1(&Program::from(input)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day02_part2(&Program::from(input)).to_string()
+    }
+}
+
+#[test]
+fn test_day02() {
+    let program = Program::from(DAY02_INPUT);
+    assert_eq!(day02_part1(&program), 11_590_668);
+    assert_eq!(day02_part2(&program), 2254);
+}
@@ -0,0 +1,126 @@
+//! Solution to Advent of Code 2019 [Day 3](https://adventofcode.com/2019/day/3).
+
+use aoc::geom::{Polyline, Vector2D};
+use aoc::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+fn find_closest_intersection_distance(wire1: &Polyline, wire2: &Polyline) -> usize {
+    find_intersections(wire1, wire2)
+        .into_iter()
+        .map(Vector2D::manhattan_length)
+        .min()
+        .unwrap()
+}
+
+fn find_intersections(wire1: &Polyline, wire2: &Polyline) -> HashSet<Vector2D> {
+    let wire1_positions = wire1.walk().collect::<HashSet<_>>();
+    wire2
+        .walk()
+        .filter(|p| wire1_positions.contains(p))
+        .collect()
+}
+
+fn find_shortest_intersection_walk(wire1: &Polyline, wire2: &Polyline) -> usize {
+    let mut wire1_positions = HashMap::new();
+    for (n, p) in wire1.walk().enumerate() {
+        let steps = n + 1;
+        wire1_positions.entry(p).or_insert(steps);
+    }
+
+    let mut intersections = HashMap::new();
+    for (n, p) in wire2.walk().enumerate() {
+        if let Some(s1) = wire1_positions.get(&p) {
+            let steps = n + 1;
+            intersections.entry(p).or_insert(steps + s1);
+        }
+    }
+
+    intersections.values().copied().min().unwrap()
+}
+
+pub const DAY03_INPUT: &str = include_str!("day03_input.txt");
+
+fn parse_wires(input: &str) -> (Polyline, Polyline) {
+    let mut lines = input.trim().lines();
+    let p1 = lines.next().unwrap().parse().unwrap();
+    let p2 = lines.next().unwrap().parse().unwrap();
+    (p1, p2)
+}
+
+fn day03_part1(input: &str) -> usize {
+    let (p1, p2) = parse_wires(input);
+    find_closest_intersection_distance(&p1, &p2)
+}
+
+fn day03_part2(input: &str) -> usize {
+    let (p1, p2) = parse_wires(input);
+    find_shortest_intersection_walk(&p1, &p2)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn part1(&self, input: &str) -> String {
+        day03_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day03_part2(input).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_closest_intersection_distance_examples() {
+        let check = |wire1: &str, wire2: &str, expected_distance| {
+            let p1 = wire1.parse().unwrap();
+            let p2 = wire2.parse().unwrap();
+            assert_eq!(
+                find_closest_intersection_distance(&p1, &p2),
+                expected_distance
+            );
+        };
+
+        check("R8,U5,L5,D3", "U7,R6,D4,L4", 6);
+        check(
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72",
+            "U62,R66,U55,R34,D71,R55,D58,R83",
+            159,
+        );
+        check(
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51",
+            "U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+            135,
+        );
+    }
+
+    #[test]
+    fn find_shortest_intersection_walk_examples() {
+        let check = |wire1: &str, wire2: &str, expected_distance| {
+            let p1 = wire1.parse().unwrap();
+            let p2 = wire2.parse().unwrap();
+            assert_eq!(find_shortest_intersection_walk(&p1, &p2), expected_distance);
+        };
+
+        check("R8,U5,L5,D3", "U7,R6,D4,L4", 30);
+        check(
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72",
+            "U62,R66,U55,R34,D71,R55,D58,R83",
+            610,
+        );
+        check(
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51",
+            "U98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+            410,
+        );
+    }
+
+    #[test]
+    fn test_day03() {
+        assert_eq!(day03_part1(DAY03_INPUT), 731);
+        assert_eq!(day03_part2(DAY03_INPUT), 5672);
+    }
+}
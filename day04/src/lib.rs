@@ -0,0 +1,108 @@
+//! Solution to Advent of Code 2019 [Day 4](https://adventofcode.com/2019/day/4).
+
+use aoc::digits::fixed_width_digits;
+use aoc::solution::Solution;
+
+/// The puzzle input is just the range of passwords to check.
+pub const DAY04_INPUT: &str = "178416-676461";
+
+#[derive(PartialEq)]
+struct Password([u8; 6]);
+
+impl Password {
+    fn new(num: u32) -> Password {
+        let mut p = Password([0; 6]);
+        for (digit, value) in p.0.iter_mut().zip(fixed_width_digits(num.into(), 6)) {
+            *digit = value;
+        }
+        p
+    }
+
+    fn is_valid(&self) -> bool {
+        let p = &self.0;
+        (
+            // two adjacent equal digits
+            p[0] == p[1] || p[1] == p[2] || p[2] == p[3] || p[3] == p[4] || p[4] == p[5]
+        ) && (
+            // increasing digits
+            p[0] <= p[1] && p[1] <= p[2] && p[2] <= p[3] && p[3] <= p[4] && p[4] <= p[5]
+        )
+    }
+
+    #[rustfmt::skip]
+    fn is_valid_part2(&self) -> bool {
+        let p = &self.0;
+
+           (/* no digit */  p[0] == p[1] && p[1] != p[2])
+        || (p[0] != p[1] && p[1] == p[2] && p[2] != p[3])
+        || (p[1] != p[2] && p[2] == p[3] && p[3] != p[4])
+        || (p[2] != p[3] && p[3] == p[4] && p[4] != p[5])
+        || (p[3] != p[4] && p[4] == p[5]  /* no digit */)
+    }
+
+    fn increment(&mut self) {
+        self.increment_digit(5);
+    }
+
+    fn increment_digit(&mut self, digit: usize) {
+        if self.0[digit] == 9 {
+            self.0[digit] = 0;
+            self.increment_digit(digit - 1);
+        } else {
+            self.0[digit] += 1;
+        }
+    }
+}
+
+fn day04(input: &str) -> (usize, usize) {
+    let (start, end) = input.trim().split_once('-').unwrap();
+    let mut p = Password::new(start.parse().unwrap());
+    let end = Password::new(end.parse().unwrap());
+    let mut part1 = 0;
+    let mut part2 = 0;
+    while p != end {
+        p.increment();
+        if p.is_valid() {
+            part1 += 1;
+            if p.is_valid_part2() {
+                part2 += 1;
+            }
+        }
+    }
+    (part1, part2)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn part1(&self, input: &str) -> String {
+        day04(input).0.to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day04(input).1.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_password_validity() {
+        assert!(Password::new(111_111).is_valid());
+        assert!(!Password::new(223_450).is_valid());
+        assert!(!Password::new(123_789).is_valid());
+
+        assert!(Password::new(112_233).is_valid_part2());
+        assert!(!Password::new(123_444).is_valid_part2());
+        assert!(Password::new(111_122).is_valid_part2());
+    }
+
+    #[test]
+    fn test_day04() {
+        let (p1, p2) = day04(DAY04_INPUT);
+        assert_eq!(p1, 1650);
+        assert_eq!(p2, 1129);
+    }
+}
@@ -0,0 +1,38 @@
+//! Solution to Advent of Code 2019 [Day 5](https://adventofcode.com/2019/day/5).
+
+use aoc::intcode::Machine;
+use aoc::solution::Solution;
+
+pub const DAY05_INPUT: &str = include_str!("day05_input.txt");
+
+fn day05_part1(input: &str) -> i64 {
+    let output = Machine::from_source_with_input(input, 1)
+        .run_as_iter()
+        .collect::<Vec<_>>();
+    assert!(!output.is_empty());
+    let (last, rest) = output.split_last().unwrap();
+    assert!(rest.iter().all(|o| *o == 0), "Failed a TEST");
+    *last
+}
+
+fn day05_part2(input: &str) -> i64 {
+    Machine::from_source_with_input(input, 5).run().unwrap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn part1(&self, input: &str) -> String {
+        day05_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day05_part2(input).to_string()
+    }
+}
+
+#[test]
+fn test_day05() {
+    assert_eq!(day05_part1(DAY05_INPUT), 13_933_662);
+    assert_eq!(day05_part2(DAY05_INPUT), 2_369_720);
+}
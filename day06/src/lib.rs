@@ -0,0 +1,156 @@
+//! Solution to Advent of Code 2019 [Day 6](https://adventofcode.com/2019/day/6).
+
+use aoc::interner::Interner;
+use aoc::memo::Memo;
+use aoc::solution::Solution;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+struct OrbitMap {
+    names: Interner,
+    objects: Vec<u32>,
+    orbits: HashMap<u32, u32>,
+}
+
+impl OrbitMap {
+    fn new(map: &str) -> OrbitMap {
+        let mut names = Interner::new();
+        let mut objects = Vec::new();
+        let orbits = map
+            .lines()
+            .map(|s| {
+                let mid = s.find(')').unwrap();
+                let primary = names.intern(&s[..mid]);
+                let satellite = names.intern(&s[(mid + 1)..]);
+                assert!(!objects.contains(&satellite));
+                objects.push(satellite);
+                (satellite, primary)
+            })
+            .collect::<HashMap<_, _>>();
+        OrbitMap {
+            names,
+            objects,
+            orbits,
+        }
+    }
+
+    fn find_primary(&self, satellite: u32) -> Option<u32> {
+        self.orbits.get(&satellite).copied()
+    }
+
+    fn walk_orbits(&self, object: u32) -> WalkOrbits<'_> {
+        WalkOrbits::new(self, object)
+    }
+
+    fn total_orbits(&self) -> usize {
+        let mut depths = Memo::new();
+        self.objects
+            .iter()
+            .map(|&o| self.orbit_depth(&mut depths, o))
+            .sum()
+    }
+
+    fn orbit_depth(&self, depths: &mut Memo<u32, usize>, object: u32) -> usize {
+        depths.get_or_compute(object, |depths, &o| match self.find_primary(o) {
+            Some(primary) => 1 + self.orbit_depth(depths, primary),
+            None => 0,
+        })
+    }
+
+    fn find_num_transits(&self, object_a: &str, object_b: &str) -> usize {
+        let object_a = self.names.get(object_a).unwrap();
+        let object_b = self.names.get(object_b).unwrap();
+        let path_a = self.walk_orbits(object_a).collect::<HashSet<_>>();
+        let (distance_b, common) = self
+            .walk_orbits(object_b)
+            .enumerate()
+            .find(|(_, o)| path_a.contains(o))
+            .unwrap();
+        // +1 because we lose an orbit count walking from the common point
+        let distance_common = self.walk_orbits(common).count() + 1;
+        let distance_a = path_a.len() - distance_common;
+        distance_a + distance_b
+    }
+}
+
+struct WalkOrbits<'a> {
+    map: &'a OrbitMap,
+    object: Option<u32>,
+}
+
+impl<'a> WalkOrbits<'a> {
+    fn new(map: &'a OrbitMap, object: u32) -> WalkOrbits<'a> {
+        let object = Some(object);
+        WalkOrbits { map, object }
+    }
+}
+
+impl<'a> Iterator for WalkOrbits<'a> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.object = self.map.find_primary(self.object?);
+        self.object
+    }
+}
+
+pub const DAY06_INPUT: &str = include_str!("day06_input.txt");
+
+fn day06_part1(input: &str) -> usize {
+    OrbitMap::new(input).total_orbits()
+}
+
+fn day06_part2(input: &str) -> usize {
+    OrbitMap::new(input).find_num_transits("YOU", "SAN")
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn part1(&self, input: &str) -> String {
+        day06_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day06_part2(input).to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::generate::{orbit_map, Rng};
+
+    #[test]
+    fn test_examples() {
+        const DAY06_EXAMPLE: &str = include_str!("day06_example.txt");
+        let map = OrbitMap::new(DAY06_EXAMPLE);
+        assert_eq!(map.total_orbits(), 42);
+
+        const DAY06_EXAMPLE_TRANSIT: &str = include_str!("day06_example_transit.txt");
+        let transit_map = OrbitMap::new(DAY06_EXAMPLE_TRANSIT);
+        assert_eq!(transit_map.find_num_transits("YOU", "SAN"), 4);
+    }
+
+    #[test]
+    fn test_random_maps() {
+        let mut rng = Rng::new(6);
+        for _ in 0..10 {
+            let input = orbit_map(&mut rng, 500);
+            let map = OrbitMap::new(&input);
+            let expected = map
+                .objects
+                .iter()
+                .map(|&o| std::iter::successors(Some(o), |&o| map.find_primary(o)).count() - 1)
+                .sum::<usize>();
+            assert_eq!(map.total_orbits(), expected);
+            assert!(map.find_num_transits("YOU", "SAN") < 500);
+        }
+    }
+
+    #[test]
+    fn test_day06() {
+        assert_eq!(day06_part1(DAY06_INPUT), 315_757);
+        assert_eq!(day06_part2(DAY06_INPUT), 481);
+    }
+}
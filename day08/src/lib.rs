@@ -0,0 +1,137 @@
+//! Solution to Advent of Code 2019 [Day 8](https://adventofcode.com/2019/day/8).
+
+use aoc::geom::{Dimensions, Grid};
+use aoc::iter::AocIterExt;
+use aoc::ocr::{try_ocr_grid, MIN_CONFIDENCE};
+use aoc::solution::Solution;
+use aoc::Result;
+use std::collections::HashMap;
+use std::fmt;
+use std::iter;
+
+#[derive(Debug)]
+struct Image {
+    layers: Vec<Layer>,
+    dimensions: Dimensions,
+}
+
+impl Image {
+    fn new(data: &str, dimensions: Dimensions) -> Image {
+        let layers = data
+            .trim()
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as u8)
+            .chunks_exact_vec(dimensions.area())
+            .map(|layer| Layer(Grid::from_vec(dimensions, layer)))
+            .collect();
+        Image { layers, dimensions }
+    }
+
+    fn render_to_layer(&self) -> Layer {
+        if self.layers.len() > 1 {
+            let mut canvas = iter::repeat_n(2, self.dimensions.area()).collect::<Vec<_>>();
+            for layer in &self.layers {
+                layer.render(&mut canvas);
+            }
+            Layer(Grid::from_vec(self.dimensions, canvas))
+        } else {
+            self.layers[0].clone()
+        }
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render_to_layer())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Layer(Grid<u8>);
+
+impl Layer {
+    fn digit_counts(&self) -> HashMap<u8, usize> {
+        self.0.cells().iter().copied().counts()
+    }
+
+    /// The pixels of the layer which are white.
+    fn pixels(&self) -> Grid<bool> {
+        self.0.map(|&colour| colour == 1)
+    }
+
+    fn render(&self, canvas: &mut [u8]) {
+        assert_eq!(self.0.cells().len(), canvas.len());
+        for (n, &colour) in self.0.cells().iter().enumerate() {
+            if canvas[n] == 2 {
+                canvas[n] = colour;
+            }
+        }
+    }
+}
+
+impl fmt::Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.0.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for &colour in row {
+                let c = if colour == 1 { '@' } else { ' ' };
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub const DAY08_INPUT: &str = include_str!("day08_input.txt");
+
+fn day08_image(input: &str) -> Image {
+    Image::new(
+        input,
+        Dimensions {
+            width: 25,
+            height: 6,
+        },
+    )
+}
+
+fn day08_part1(img: &Image) -> usize {
+    let count = |counts: &HashMap<u8, usize>, digit| counts.get(&digit).copied().unwrap_or(0);
+    let counts = img
+        .layers
+        .iter()
+        .map(Layer::digit_counts)
+        .min_by_key(|counts| count(counts, 0))
+        .unwrap();
+
+    count(&counts, 1) * count(&counts, 2)
+}
+
+fn day08_part2(img: &Image) -> Result<String> {
+    try_ocr_grid(&img.render_to_layer().pixels(), MIN_CONFIDENCE)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn part1(&self, input: &str) -> String {
+        day08_part1(&day08_image(input)).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day08_part2(&day08_image(input)).expect("Can't read the message")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day08() {
+        let img = day08_image(DAY08_INPUT);
+        assert_eq!(day08_part1(&img), 1703);
+        assert_eq!(day08_part2(&img).unwrap(), "HCGFE");
+    }
+}
@@ -0,0 +1,34 @@
+//! Solution to Advent of Code 2019 [Day 9](https://adventofcode.com/2019/day/9).
+
+use aoc::intcode::{Machine, Program};
+use aoc::solution::Solution;
+
+pub const DAY09_INPUT: &str = include_str!("day09_input.txt");
+
+fn day09_part1(input: &str) -> i64 {
+    let program = Program::from(input);
+    Machine::new(&program).run_with_input(1).unwrap()
+}
+
+fn day09_part2(input: &str) -> i64 {
+    let program = Program::from(input);
+    Machine::new(&program).run_with_input(2).unwrap()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn part1(&self, input: &str) -> String {
+        day09_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day09_part2(input).to_string()
+    }
+}
+
+#[test]
+fn test_day09() {
+    assert_eq!(day09_part1(DAY09_INPUT), 2_351_176_124);
+    assert_eq!(day09_part2(DAY09_INPUT), 73_110);
+}
@@ -0,0 +1,183 @@
+//! Solution to Advent of Code 2019 [Day 10](https://adventofcode.com/2019/day/10).
+
+use aoc::geom::{Angle, Dimensions, Vector2D};
+use aoc::solution::Solution;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+#[derive(Debug)]
+struct AsteroidField {
+    asteroids: HashSet<Vector2D>,
+    dimensions: Dimensions,
+}
+
+impl AsteroidField {
+    fn new(input: &str) -> AsteroidField {
+        let lines = input.trim().lines();
+        let dimensions = Dimensions {
+            width: lines.clone().next().unwrap().len(),
+            height: lines.clone().count(),
+        };
+        let asteroids = lines
+            .enumerate()
+            .flat_map(|(y, li)| {
+                assert_eq!(li.len(), dimensions.width);
+                li.trim()
+                    .chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(x, _)| Vector2D {
+                        x: x as i64,
+                        y: y as i64,
+                    })
+            })
+            .collect();
+        AsteroidField {
+            asteroids,
+            dimensions,
+        }
+    }
+
+    fn find_best_monitoring_asteroid(&self) -> (Vector2D, usize) {
+        self.asteroids
+            .iter()
+            .copied()
+            .map(|a| (a, self.num_visible_asteroids(a)))
+            .max_by(|a, b| a.1.cmp(&b.1))
+            .unwrap()
+    }
+
+    fn num_visible_asteroids(&self, pos: Vector2D) -> usize {
+        self.asteroids
+            .iter()
+            .copied()
+            .map(|t| t - pos)
+            .filter(|offset| *offset != Vector2D::zero())
+            .map(Vector2D::primitive)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    fn vaporisation_order(&self, station_pos: Vector2D) -> Vec<Vector2D> {
+        assert!(self.asteroids.contains(&station_pos));
+
+        // Sort by angle from the centrepoint, closer objects first when
+        // they share an angle of attack.
+        let mut offsets = self
+            .asteroids
+            .iter()
+            .map(|a| *a - station_pos)
+            .filter(|o| *o != Vector2D::zero())
+            .map(|o| (Angle::from(o), o))
+            .collect::<Vec<_>>();
+        offsets.sort_by_key(|&(angle, o)| (angle, o.manhattan_length()));
+
+        // Asteroids hidden behind others at the same angle are only reached
+        // on later rotations, so order by rotation and then angle.
+        let mut rotations = HashMap::new();
+        let mut order = offsets
+            .into_iter()
+            .map(|(angle, o)| {
+                let rotation = rotations.entry(angle).or_insert(0);
+                *rotation += 1;
+                (*rotation, angle, o)
+            })
+            .collect::<Vec<_>>();
+        order.sort_by_key(|&(rotation, angle, _)| (rotation, angle));
+
+        // Done, convert back to original positions
+        order.into_iter().map(|(_, _, o)| o + station_pos).collect()
+    }
+}
+
+impl fmt::Display for AsteroidField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for coord in self.dimensions.iter() {
+            if coord.x == 0 {
+                writeln!(f)?;
+            }
+            let is_roid = self.asteroids.contains(&coord);
+            let c = if is_roid { '#' } else { '.' };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+pub const DAY10_INPUT: &str = include_str!("day10_input.txt");
+
+fn day10(input: &str) -> (usize, usize) {
+    let field = AsteroidField::new(input);
+    let best = field.find_best_monitoring_asteroid();
+    let part1 = best.1;
+    let order = field.vaporisation_order(best.0);
+    let target = order[199];
+    let part2 = ((target.x * 100) + target.y) as usize;
+    (part1, part2)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn part1(&self, input: &str) -> String {
+        day10(input).0.to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day10(input).1.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_FIELDS: [&str; 5] = [
+        include_str!("day10_example1.txt"),
+        include_str!("day10_example2.txt"),
+        include_str!("day10_example3.txt"),
+        include_str!("day10_example4.txt"),
+        include_str!("day10_example5.txt"),
+    ];
+
+    #[test]
+    fn test_find_best_monitoring_asteroid() {
+        check_find_best_monitoring_asteroid(EXAMPLE_FIELDS[0], (Vector2D { x: 3, y: 4 }, 8));
+        check_find_best_monitoring_asteroid(EXAMPLE_FIELDS[1], (Vector2D { x: 5, y: 8 }, 33));
+        check_find_best_monitoring_asteroid(EXAMPLE_FIELDS[2], (Vector2D { x: 1, y: 2 }, 35));
+        check_find_best_monitoring_asteroid(EXAMPLE_FIELDS[3], (Vector2D { x: 6, y: 3 }, 41));
+        check_find_best_monitoring_asteroid(EXAMPLE_FIELDS[4], (Vector2D { x: 11, y: 13 }, 210));
+    }
+
+    fn check_find_best_monitoring_asteroid(input: &str, expected: (Vector2D, usize)) {
+        let best = AsteroidField::new(input).find_best_monitoring_asteroid();
+        assert_eq!(best, expected);
+    }
+
+    #[test]
+    fn test_vaporisation_order() {
+        let field = AsteroidField::new(EXAMPLE_FIELDS[4]);
+        let pos = field.find_best_monitoring_asteroid().0;
+        let order = field.vaporisation_order(pos);
+
+        assert_eq!(order.len(), 299);
+        assert_eq!(order[0], Vector2D { x: 11, y: 12 });
+        assert_eq!(order[1], Vector2D { x: 12, y: 1 });
+        assert_eq!(order[2], Vector2D { x: 12, y: 2 });
+        assert_eq!(order[9], Vector2D { x: 12, y: 8 });
+        assert_eq!(order[19], Vector2D { x: 16, y: 0 });
+        assert_eq!(order[49], Vector2D { x: 16, y: 9 });
+        assert_eq!(order[99], Vector2D { x: 10, y: 16 });
+        assert_eq!(order[198], Vector2D { x: 9, y: 6 });
+        assert_eq!(order[199], Vector2D { x: 8, y: 2 });
+        assert_eq!(order[200], Vector2D { x: 10, y: 9 });
+        assert_eq!(order[298], Vector2D { x: 11, y: 1 });
+    }
+
+    #[test]
+    fn test_day10() {
+        let (part1, part2) = day10(DAY10_INPUT);
+        assert_eq!(part1, 292);
+        assert_eq!(part2, 317);
+    }
+}
@@ -0,0 +1,111 @@
+//! Solution to Advent of Code 2019 [Day 11](https://adventofcode.com/2019/day/11).
+
+use aoc::geom::{Direction, SparseGrid, Turn, Vector2D};
+use aoc::intcode::{Machine, Program};
+use aoc::ocr::{try_ocr_points, MIN_CONFIDENCE};
+use aoc::render::render_sparse;
+use aoc::solution::Solution;
+use aoc::{Error, Result};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+struct HullPaintingRobot {
+    machine: Machine,
+    position: Vector2D,
+    direction: Direction,
+    panels: SparseGrid<i64>,
+}
+
+impl HullPaintingRobot {
+    fn new(program: &Program) -> HullPaintingRobot {
+        HullPaintingRobot {
+            machine: Machine::new(program),
+            position: Vector2D::zero(),
+            direction: Direction::North,
+            panels: SparseGrid::new(),
+        }
+    }
+
+    fn run_to_completion(&mut self, initial_colour: i64) -> Result<()> {
+        self.machine.input(initial_colour);
+        loop {
+            let paint_colour = self.machine.run();
+            if paint_colour.is_none() {
+                assert!(self.machine.is_halted());
+                break;
+            }
+            self.panels.insert(self.position, paint_colour.unwrap());
+
+            let turn = self
+                .machine
+                .run()
+                .ok_or_else(|| Error::Intcode("Robot painted without turning".into()))?;
+            let turn = Turn::try_from(turn)?;
+            self.direction = self.direction.turn(turn);
+            self.position += self.direction.offset();
+
+            let colour = self.panels.get_or_insert(self.position, 0);
+            self.machine.input(*colour);
+        }
+        Ok(())
+    }
+
+    fn panels(&self) -> &SparseGrid<i64> {
+        &self.panels
+    }
+
+    fn white_panels(&self) -> impl Iterator<Item = Vector2D> + '_ {
+        self.panels
+            .iter()
+            .filter(|&(_, &colour)| colour == 1)
+            .map(|(pos, _)| pos)
+    }
+
+    // Draw the white panels as '#', with north at the top.
+    fn render(&self) -> String {
+        let panels: HashMap<Vector2D, char> = self.white_panels().map(|pos| (pos, '#')).collect();
+        render_sparse(&panels, ' ')
+    }
+}
+
+pub const DAY11_INPUT: &str = include_str!("day11_input.txt");
+
+fn day11_part1(program: &Program) -> Result<usize> {
+    let mut robot = HullPaintingRobot::new(program);
+    robot.run_to_completion(0)?;
+    Ok(robot.panels().len())
+}
+
+fn day11_part2(program: &Program) -> Result<String> {
+    let mut robot = HullPaintingRobot::new(program);
+    robot.run_to_completion(1)?;
+    try_ocr_points(robot.white_panels(), MIN_CONFIDENCE)
+        .map_err(|e| Error::Parse(format!("{} in the painted panels:\n{}", e, robot.render())))
+}
+
+#[test]
+fn test_day11() {
+    let program = Program::from(DAY11_INPUT);
+    assert_eq!(day11_part1(&program).unwrap(), 1883);
+    assert_eq!(day11_part2(&program).unwrap(), "APUGURFH");
+
+    let mut robot = HullPaintingRobot::new(&program);
+    robot.run_to_completion(1).unwrap();
+    let rendered = robot.render();
+    assert_eq!(rendered.lines().count(), 6);
+    assert!(rendered.starts_with(" ##  ###  #  #"));
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn part1(&self, input: &str) -> String {
+        day11_part1(&Program::from(input))
+            .expect("The robot malfunctioned")
+            .to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day11_part2(&Program::from(input)).expect("Can't read the registration identifier")
+    }
+}
@@ -0,0 +1,243 @@
+//! Solution to Advent of Code 2019 [Day 12](https://adventofcode.com/2019/day/12).
+
+use aoc::geom::Vector3D;
+use aoc::iter::AocIterExt;
+use aoc::math::lcm;
+use aoc::search::find_cycle;
+use aoc::solution::Solution;
+use itertools::Itertools;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn part1(&self, input: &str) -> String {
+        day12_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day12_part2(input).to_string()
+    }
+}
+
+fn day12_part1(input: &str) -> i64 {
+    let vectors = parse_vectors(input);
+    let mut data = SystemData::new(&vectors);
+    for _ in 0..1000 {
+        data.step();
+    }
+    data.energy()
+}
+
+fn day12_part2(input: &str) -> u64 {
+    find_cycle_length(&parse_vectors(input))
+}
+
+pub const DAY12_INPUT: &str = "<x=-7, y=17, z=-11>\n\
+                           <x=9, y=12, z=5>\n\
+                           <x=-9, y=0, z=-4>\n\
+                           <x=4, y=6, z=0>\n";
+
+fn parse_vectors(input: &str) -> Vec<Vector3D> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+const NUM_BODIES: usize = 4;
+
+#[derive(Debug)]
+struct SystemData {
+    axes: [AxisData; 3],
+}
+
+impl SystemData {
+    fn new(initial_positions: &[Vector3D]) -> SystemData {
+        let axes = [
+            AxisData::new(initial_positions, 0),
+            AxisData::new(initial_positions, 1),
+            AxisData::new(initial_positions, 2),
+        ];
+        SystemData { axes }
+    }
+
+    fn step(&mut self) {
+        for a in &mut self.axes {
+            a.step();
+        }
+    }
+
+    fn state(&self) -> Vec<Vector3D> {
+        (0..NUM_BODIES)
+            .flat_map(|i| {
+                vec![
+                    Vector3D {
+                        x: self.axes[0].positions[i],
+                        y: self.axes[1].positions[i],
+                        z: self.axes[2].positions[i],
+                    },
+                    Vector3D {
+                        x: self.axes[0].velocities[i],
+                        y: self.axes[1].velocities[i],
+                        z: self.axes[2].velocities[i],
+                    },
+                ]
+            })
+            .collect()
+    }
+
+    fn energy(&self) -> i64 {
+        let state = self.state();
+        state
+            .into_iter()
+            .chunks_exact_vec(2)
+            .map(|body| {
+                let (pos, vel) = (body[0], body[1]);
+                pos.manhattan_length() as i64 * vel.manhattan_length() as i64
+            })
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct AxisData {
+    positions: [i64; NUM_BODIES],
+    velocities: [i64; NUM_BODIES],
+}
+
+impl AxisData {
+    fn new(initial_positions: &[Vector3D], axis: usize) -> AxisData {
+        AxisData {
+            positions: [
+                initial_positions[0][axis],
+                initial_positions[1][axis],
+                initial_positions[2][axis],
+                initial_positions[3][axis],
+            ],
+            velocities: [0, 0, 0, 0],
+        }
+    }
+
+    fn step(&mut self) {
+        // gravity
+        for i in 0..NUM_BODIES {
+            for j in (i + 1)..NUM_BODIES {
+                let pi = self.positions[i];
+                let pj = self.positions[j];
+                let to_i = (pj - pi).signum();
+                self.velocities[i] += to_i;
+                self.velocities[j] -= to_i;
+            }
+        }
+
+        // velocity
+        for i in 0..NUM_BODIES {
+            self.positions[i] += self.velocities[i];
+        }
+    }
+}
+
+fn find_cycle_length(initial_positions: &[Vector3D]) -> u64 {
+    let cycles = (0..=2)
+        .map(|i| {
+            let initial = AxisData::new(initial_positions, i);
+            let (_, cycle_length) = find_cycle(initial, |&data| {
+                let mut next = data;
+                next.step();
+                next
+            });
+            cycle_length as u64
+        })
+        .collect_vec();
+    cycles.into_iter().fold(1, lcm)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse_states(input: &str) -> Vec<Vector3D> {
+        input
+            .lines()
+            .flat_map(|line| {
+                let (pos, vel) = line
+                    .trim()
+                    .strip_prefix("pos=")
+                    .and_then(|l| l.split_once(", vel="))
+                    .unwrap();
+                vec![pos.parse().unwrap(), vel.parse().unwrap()]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_example_data() {
+        let vectors = parse_vectors(
+            "<x=-1, y=0, z=2>\n\
+             <x=2, y=-10, z=-7>\n\
+             <x=4, y=-8, z=8>\n\
+             <x=3, y=5, z=-1>",
+        );
+        let mut system = SystemData::new(&vectors);
+        assert_eq!(
+            system.state(),
+            parse_states(
+                "pos=<x=-1, y=  0, z= 2>, vel=<x= 0, y= 0, z= 0>\n\
+                 pos=<x= 2, y=-10, z=-7>, vel=<x= 0, y= 0, z= 0>\n\
+                 pos=<x= 4, y= -8, z= 8>, vel=<x= 0, y= 0, z= 0>\n\
+                 pos=<x= 3, y=  5, z=-1>, vel=<x= 0, y= 0, z= 0>\n"
+            )
+        );
+
+        system.step();
+        assert_eq!(
+            system.state(),
+            parse_states(
+                "pos=<x= 2, y=-1, z= 1>, vel=<x= 3, y=-1, z=-1>\n\
+                 pos=<x= 3, y=-7, z=-4>, vel=<x= 1, y= 3, z= 3>\n\
+                 pos=<x= 1, y=-7, z= 5>, vel=<x=-3, y= 1, z=-3>\n\
+                 pos=<x= 2, y= 2, z= 0>, vel=<x=-1, y=-3, z= 1>\n"
+            )
+        );
+
+        for _ in 0..9 {
+            system.step();
+        }
+        assert_eq!(
+            system.state(),
+            parse_states(
+                "pos=<x= 2, y= 1, z=-3>, vel=<x=-3, y=-2, z= 1>\n\
+                 pos=<x= 1, y=-8, z= 0>, vel=<x=-1, y= 1, z= 3>\n\
+                 pos=<x= 3, y=-6, z= 1>, vel=<x= 3, y= 2, z=-3>\n\
+                 pos=<x= 2, y= 0, z= 4>, vel=<x= 1, y=-1, z=-1>\n"
+            )
+        );
+
+        assert_eq!(system.energy(), 179);
+
+        assert_eq!(find_cycle_length(&vectors), 2772);
+    }
+
+    #[test]
+    fn test_example_data_2() {
+        let vectors = parse_vectors(
+            "<x=-8, y=-10, z=0>\n\
+             <x=5, y=5, z=10>\n\
+             <x=2, y=-7, z=3>\n\
+             <x=9, y=-8, z=-3>\n",
+        );
+
+        let mut system = SystemData::new(&vectors);
+
+        for _ in 0..100 {
+            system.step();
+        }
+
+        assert_eq!(system.energy(), 1940);
+
+        assert_eq!(find_cycle_length(&vectors), 4_686_774_924);
+    }
+
+    #[test]
+    fn test_day12() {
+        assert_eq!(day12_part1(DAY12_INPUT), 7013);
+        assert_eq!(day12_part2(DAY12_INPUT), 324_618_307_124_784);
+    }
+}
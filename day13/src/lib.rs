@@ -0,0 +1,208 @@
+//! Solution to Advent of Code 2019 [Day 13](https://adventofcode.com/2019/day/13).
+
+use aoc::geom::{ExpandingGrid, Vector2D};
+use aoc::intcode::{Machine, Program};
+use aoc::solution::Solution;
+#[cfg(feature = "visualize")]
+use aoc::tui::{Control, Visualizer};
+use itertools::Itertools;
+use std::fmt;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn part1(&self, input: &str) -> String {
+        day13_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day13_part2(input).to_string()
+    }
+}
+
+fn day13_part1(input: &str) -> usize {
+    let mut cabinet = ArcadeCabinet::new(input);
+    cabinet.run();
+    cabinet
+        .render()
+        .chars()
+        .filter(|&c| c == char::from(Tile::Block))
+        .count()
+}
+
+fn day13_part2(input: &str) -> i64 {
+    let mut cabinet = ArcadeCabinet::new(input);
+    cabinet.play(|_| ());
+    cabinet.score()
+}
+
+/// Play the game while drawing it in the terminal, returning the final score.
+/// Pressing q stops drawing, but the game is still played to the end.
+#[cfg(feature = "visualize")]
+pub fn visualize(input: &str) -> aoc::Result<i64> {
+    let mut visualizer = Some(Visualizer::new(60.0)?);
+    let mut result = Ok(());
+    let mut cabinet = ArcadeCabinet::new(input);
+    cabinet.play(|cabinet| {
+        if let Some(v) = visualizer.as_mut() {
+            let frame = format!("Score: {}\n{}", cabinet.score, cabinet.render());
+            match v.draw(&frame) {
+                Ok(Control::Continue) => (),
+                Ok(Control::Quit) => visualizer = None,
+                Err(e) => {
+                    result = Err(e);
+                    visualizer = None;
+                }
+            }
+        }
+    });
+    result.map(|_| cabinet.score())
+}
+
+pub const DAY13_INPUT: &str = include_str!("day13_input.txt");
+
+#[derive(Debug)]
+struct ArcadeCabinet {
+    machine: Machine,
+    screen: ExpandingGrid<Tile>,
+    score: i64,
+    ball_pos: Vector2D,
+    paddle_pos: Vector2D,
+}
+
+impl ArcadeCabinet {
+    fn new(input: &str) -> ArcadeCabinet {
+        ArcadeCabinet {
+            machine: Machine::new(&Program::from(input).optimize()),
+            screen: ExpandingGrid::new(),
+            score: 0,
+            ball_pos: Vector2D::zero(),
+            paddle_pos: Vector2D::zero(),
+        }
+    }
+
+    fn run(&mut self) {
+        while let Some((x, y, value)) = self.machine.run_as_iter().next_tuple() {
+            match (x, y) {
+                (-1, 0) => self.score = value,
+                _ => {
+                    // update canvas
+                    let tile = Tile::from(value);
+                    let pos = Vector2D { x, y };
+                    self.screen[pos] = tile;
+
+                    // update ball and paddle locations
+                    if let Tile::Ball = tile {
+                        self.ball_pos = Vector2D { x, y };
+                    } else if let Tile::Paddle = tile {
+                        self.paddle_pos = Vector2D { x, y };
+                    }
+                }
+            }
+        }
+    }
+
+    // Play until the game ends, calling on_frame every time the screen has
+    // been updated.
+    fn play<F: FnMut(&ArcadeCabinet)>(&mut self, mut on_frame: F) {
+        self.machine.write(0, 2);
+        loop {
+            self.run();
+            on_frame(self);
+
+            if self.machine.is_awaiting_input() {
+                let joystick = (self.ball_pos - self.paddle_pos).signum();
+                self.machine.input(joystick.x);
+            } else {
+                assert!(self.machine.is_halted());
+                break;
+            }
+        }
+    }
+
+    fn render(&self) -> String {
+        self.screen.grid().render_with(|&tile| char::from(tile))
+    }
+
+    fn score(&self) -> i64 {
+        self.score
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    #[default]
+    Empty,
+    Wall,
+    Block,
+    Paddle,
+    Ball,
+}
+
+impl From<i64> for Tile {
+    fn from(value: i64) -> Tile {
+        match value {
+            0 => Tile::Empty,
+            1 => Tile::Wall,
+            2 => Tile::Block,
+            3 => Tile::Paddle,
+            4 => Tile::Ball,
+            _ => panic!("Unknown tile value '{}'", value),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> char {
+        match tile {
+            Tile::Empty => ' ',
+            Tile::Wall => '#',
+            Tile::Block => '=',
+            Tile::Paddle => '_',
+            Tile::Ball => 'o',
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day13() {
+        assert_eq!(day13_part1(DAY13_INPUT), 173);
+        assert_eq!(day13_part2(DAY13_INPUT), 8942);
+    }
+
+    #[test]
+    fn test_replay_game() {
+        let mut cabinet = ArcadeCabinet::new(DAY13_INPUT);
+        cabinet.machine.record_transcript();
+        cabinet.play(|_| ());
+        let transcript = cabinet.machine.take_transcript().unwrap();
+
+        let mut replayed = ArcadeCabinet::new(DAY13_INPUT);
+        replayed.machine.write(0, 2);
+        assert_eq!(transcript.replay(&mut replayed.machine), Ok(()));
+        assert_eq!(replayed.machine.run(), None);
+        assert!(replayed.machine.is_halted());
+        assert_eq!(transcript.outputs().last(), Some(cabinet.score()));
+    }
+
+    #[test]
+    fn test_optimized_program_is_equivalent() {
+        let program = Program::from(DAY13_INPUT);
+        let optimized_program = program.optimize();
+        assert_ne!(optimized_program, program);
+
+        let mut original = Machine::new(&program);
+        let mut optimized = Machine::new(&optimized_program);
+        assert!(original.run_as_iter().eq(optimized.run_as_iter()));
+    }
+}
@@ -0,0 +1,213 @@
+//! Solution to Advent of Code 2019 [Day 14](https://adventofcode.com/2019/day/14).
+
+use aoc::interner::Interner;
+use aoc::search::binary_search_max;
+use aoc::solution::Solution;
+use itertools::Itertools;
+use std::cmp;
+use std::collections::HashMap;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn part1(&self, input: &str) -> String {
+        day14_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day14_part2(input).to_string()
+    }
+}
+
+fn day14_part1(input: &str) -> u64 {
+    minimum_ore_per_fuel(input)
+}
+
+fn day14_part2(input: &str) -> u64 {
+    max_fuel_per_trillion_ore(input)
+}
+
+fn minimum_ore_per_fuel(factory_spec: &str) -> u64 {
+    let mut factory = NanoFactory::from(factory_spec);
+    factory.make("FUEL", 1);
+    factory.ore_used
+}
+
+fn max_fuel_per_trillion_ore(factory_spec: &str) -> u64 {
+    let trillion = 1_000_000_000_000;
+    let ore_for_one_fuel = minimum_ore_per_fuel(factory_spec);
+    let mut factory = NanoFactory::from(factory_spec);
+    let lower = trillion / ore_for_one_fuel;
+    binary_search_max(lower, trillion, |fuel| {
+        factory.reset();
+        factory.make("FUEL", fuel);
+        factory.ore_used <= trillion
+    })
+    .unwrap()
+}
+
+pub const DAY14_INPUT: &str = include_str!("day14_input.txt");
+
+#[derive(Debug)]
+struct NanoFactory {
+    chemicals: Interner,
+    ore: u32,
+    reactions: HashMap<u32, Reaction>,
+    to_produce: Vec<ChemicalQuantity>,
+    stock: HashMap<u32, u64>,
+    ore_used: u64,
+}
+
+#[derive(Debug)]
+struct Reaction {
+    inputs: Vec<ChemicalQuantity>,
+    output: ChemicalQuantity,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ChemicalQuantity {
+    chemical: u32,
+    quantity: u64,
+}
+
+impl NanoFactory {
+    fn new(mut chemicals: Interner, reactions: HashMap<u32, Reaction>) -> NanoFactory {
+        NanoFactory {
+            ore: chemicals.intern("ORE"),
+            chemicals,
+            reactions,
+            to_produce: Vec::new(),
+            stock: HashMap::new(),
+            ore_used: 0,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.to_produce.clear();
+        self.stock.clear();
+        self.ore_used = 0;
+    }
+
+    fn make(&mut self, name: &str, quantity: u64) {
+        let chemical = self.chemicals.get(name).unwrap();
+        self.to_produce
+            .push(ChemicalQuantity { chemical, quantity });
+        while let Some(needed) = self.to_produce.pop() {
+            self.produce(needed);
+        }
+    }
+
+    fn produce(&mut self, chemical: ChemicalQuantity) {
+        let used = self.use_existing_stock(&chemical);
+        let quantity = chemical.quantity - used;
+        if quantity > 0 {
+            let produced = self.run_reaction(ChemicalQuantity {
+                chemical: chemical.chemical,
+                quantity,
+            });
+            if produced > quantity {
+                self.stock.insert(chemical.chemical, produced - quantity);
+            }
+        }
+    }
+
+    fn use_existing_stock(&mut self, chemical: &ChemicalQuantity) -> u64 {
+        if chemical.chemical == self.ore {
+            self.ore_used += chemical.quantity;
+            chemical.quantity
+        } else {
+            let available = *self.stock.entry(chemical.chemical).or_insert(0);
+            let used = cmp::min(available, chemical.quantity);
+            self.stock.insert(chemical.chemical, available - used);
+            used
+        }
+    }
+
+    fn run_reaction(&mut self, chemical: ChemicalQuantity) -> u64 {
+        let reaction = &self.reactions[&chemical.chemical];
+        let per_run = reaction.output.quantity;
+        let num_runs = (chemical.quantity as f64 / per_run as f64).ceil() as u64;
+        for &input in reaction.inputs.iter() {
+            let quantity = input.quantity * num_runs;
+            let required = ChemicalQuantity { quantity, ..input };
+            self.to_produce.push(required);
+        }
+        per_run * num_runs
+    }
+}
+
+impl From<&str> for NanoFactory {
+    fn from(string: &str) -> NanoFactory {
+        let mut chemicals = Interner::new();
+        let reactions = string
+            .lines()
+            .map(|line| Reaction::parse(line, &mut chemicals))
+            .map(|r| (r.output.chemical, r))
+            .collect();
+        NanoFactory::new(chemicals, reactions)
+    }
+}
+
+impl Reaction {
+    fn parse(string: &str, chemicals: &mut Interner) -> Reaction {
+        let (input, output) = string.trim().split("=>").next_tuple().unwrap();
+        let inputs = input
+            .split(',')
+            .map(|i| ChemicalQuantity::parse(i, chemicals))
+            .collect_vec();
+        let output = ChemicalQuantity::parse(output, chemicals);
+        Reaction { inputs, output }
+    }
+}
+
+impl ChemicalQuantity {
+    fn parse(string: &str, chemicals: &mut Interner) -> ChemicalQuantity {
+        let (quantity, name) = string.split_whitespace().next_tuple().unwrap();
+        let quantity = quantity.trim().parse::<u64>().unwrap();
+        let chemical = chemicals.intern(name);
+        ChemicalQuantity { chemical, quantity }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DAY14_EXAMPLES: [&str; 5] = [
+        include_str!("day14_example0.txt"),
+        include_str!("day14_example1.txt"),
+        include_str!("day14_example2.txt"),
+        include_str!("day14_example3.txt"),
+        include_str!("day14_example4.txt"),
+    ];
+
+    #[test]
+    fn test_make_fuel() {
+        check_make_fuel(DAY14_EXAMPLES[0], 31);
+        check_make_fuel(DAY14_EXAMPLES[1], 165);
+        check_make_fuel(DAY14_EXAMPLES[2], 13_312);
+        check_make_fuel(DAY14_EXAMPLES[3], 180_697);
+        check_make_fuel(DAY14_EXAMPLES[4], 2_210_736);
+    }
+
+    fn check_make_fuel(factory_spec: &str, expected_ore: u64) {
+        assert_eq!(minimum_ore_per_fuel(factory_spec), expected_ore);
+    }
+
+    #[test]
+    fn test_max_fuel_per_trillion_ore() {
+        check_max_fuel_per_trillion_ore(DAY14_EXAMPLES[2], 82_892_753);
+        check_max_fuel_per_trillion_ore(DAY14_EXAMPLES[3], 5_586_022);
+        check_max_fuel_per_trillion_ore(DAY14_EXAMPLES[4], 460_664);
+    }
+
+    fn check_max_fuel_per_trillion_ore(factory_spec: &str, expected_fuel: u64) {
+        assert_eq!(max_fuel_per_trillion_ore(factory_spec), expected_fuel);
+    }
+
+    #[test]
+    fn test_day14() {
+        assert_eq!(day14_part1(DAY14_INPUT), 1_920_219);
+        assert_eq!(day14_part2(DAY14_INPUT), 1_330_066);
+    }
+}
@@ -0,0 +1,312 @@
+//! Solution to Advent of Code 2019 [Day 15](https://adventofcode.com/2019/day/15).
+
+// Notes:
+// - Path appears to be one tile wide
+// - There are multiple paths with dead ends, so will need to backtrack
+
+use aoc::geom::{Direction, Grid, OffsetGrid, Vector2D};
+use aoc::graph::NodeGraph;
+use aoc::intcode::Machine;
+use aoc::solution::Solution;
+#[cfg(feature = "visualize")]
+use aoc::tui::{Control, Visualizer};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn part1(&self, input: &str) -> String {
+        day15(input).0.to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day15(input).1.to_string()
+    }
+}
+
+fn day15(input: &str) -> (usize, usize) {
+    let droid = explore(input, |_| ());
+    let part1 = droid.distance_of_oxygen_from_start().unwrap();
+    let part2 = droid.time_for_oxygen_to_percolate().unwrap();
+
+    (part1, part2)
+}
+
+// Explore the whole map, calling on_move after each newly explored tile.
+fn explore<F: FnMut(&RepairDroid)>(input: &str, mut on_move: F) -> RepairDroid {
+    let mut droid = RepairDroid::new(input);
+    while !droid.explored_everything() {
+        droid.explore_one_tile();
+        on_move(&droid);
+    }
+    droid
+}
+
+/// Explore the map while drawing it in the terminal. Pressing q stops
+/// drawing, but exploration still continues to the end.
+#[cfg(feature = "visualize")]
+pub fn visualize(input: &str) -> aoc::Result<()> {
+    let mut visualizer = Some(Visualizer::new(60.0)?);
+    let mut result = Ok(());
+    explore(input, |droid| {
+        if let Some(v) = visualizer.as_mut() {
+            match v.draw(&droid.render()) {
+                Ok(Control::Continue) => (),
+                Ok(Control::Quit) => visualizer = None,
+                Err(e) => {
+                    result = Err(e);
+                    visualizer = None;
+                }
+            }
+        }
+    });
+    result
+}
+
+pub const DAY15_INPUT: &str = include_str!("day15_input.txt");
+
+#[derive(Debug)]
+struct RepairDroid {
+    machine: Machine,
+    position: Vector2D,
+    world_map: WorldMap,
+}
+
+impl RepairDroid {
+    fn new(input: &str) -> RepairDroid {
+        let mut droid = RepairDroid {
+            machine: Machine::from_source(input),
+            position: Vector2D::zero(),
+            world_map: WorldMap::new(),
+        };
+        droid.record_location(droid.position, LocationType::Start);
+        droid.record_move(droid.position);
+        droid
+    }
+
+    fn explored_everything(&self) -> bool {
+        self.world_map.explored_everything()
+    }
+
+    fn distance_of_oxygen_from_start(&self) -> Option<usize> {
+        let oxygen_pos = self.oxygen_system_pos()?;
+        let distances = self.world_map.distances_from(oxygen_pos);
+        let start = -self.world_map.map.bounds().top_left;
+        *distances.get(start)?
+    }
+
+    fn oxygen_system_pos(&self) -> Option<Vector2D> {
+        self.world_map.oxygen_system_pos()
+    }
+
+    fn time_for_oxygen_to_percolate(&self) -> Option<usize> {
+        let oxygen_pos = self.oxygen_system_pos()?;
+        let distances = self.world_map.distances_from(oxygen_pos);
+        distances.iter().filter_map(|(_, &d)| d).max()
+    }
+
+    fn explore_one_tile(&mut self) {
+        if let Some(dest) = self.world_map.next_unexplored_tile() {
+            for c in self.find_path_to(self.position, dest) {
+                self.execute_command(c);
+            }
+        }
+    }
+
+    fn execute_command(&mut self, direction: Direction) {
+        self.machine.input(i64::from(direction));
+        let status = self.machine.run().unwrap();
+
+        let location = self.position + direction.offset();
+        let location_type = LocationType::from(status);
+        self.record_location(location, location_type);
+
+        match location_type {
+            LocationType::Wall => (),
+            LocationType::Empty => self.record_move(location),
+            LocationType::OxygenSystem => self.record_move(location),
+            _ => panic!("Err..."),
+        }
+    }
+
+    fn record_move(&mut self, location: Vector2D) {
+        self.position = location;
+        for n in self.position.neighbours() {
+            self.record_location(n, LocationType::Reachable);
+        }
+    }
+
+    fn record_location(&mut self, location: Vector2D, location_type: LocationType) {
+        self.world_map.record_location(location, location_type);
+    }
+
+    #[cfg(feature = "visualize")]
+    fn render(&self) -> String {
+        self.world_map.render(self.position)
+    }
+
+    fn find_path_to(&self, start: Vector2D, destination: Vector2D) -> Vec<Direction> {
+        self.world_map
+            .find_shortest_path(start, destination)
+            .into_iter()
+            .tuple_windows::<(_, _)>()
+            .map(|(pos, next)| next - pos)
+            .map(|diff| Direction::from_offset(diff).unwrap())
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct WorldMap {
+    map: OffsetGrid<LocationType>,
+    oxygen_system_pos: Option<Vector2D>,
+    unknown_locations: HashSet<Vector2D>,
+}
+
+impl WorldMap {
+    fn new() -> WorldMap {
+        WorldMap {
+            map: OffsetGrid::new(Vector2D::zero(), LocationType::Unknown),
+            oxygen_system_pos: None,
+            unknown_locations: HashSet::new(),
+        }
+    }
+
+    fn explored_everything(&self) -> bool {
+        self.unknown_locations.is_empty()
+    }
+
+    fn next_unexplored_tile(&self) -> Option<Vector2D> {
+        self.unknown_locations.iter().copied().next()
+    }
+
+    fn record_location(&mut self, location: Vector2D, location_type: LocationType) {
+        let is_known = location_type != LocationType::Reachable;
+        let is_recorded = self
+            .map
+            .get(location)
+            .is_some_and(|&lt| lt != LocationType::Unknown);
+        let should_record = is_known || !is_recorded;
+
+        if should_record {
+            self.map.expand_to_fit(location, LocationType::Unknown);
+            self.map[location] = location_type;
+
+            if is_known {
+                self.unknown_locations.remove(&location);
+            } else {
+                self.unknown_locations.insert(location);
+            }
+
+            if location_type == LocationType::OxygenSystem {
+                self.oxygen_system_pos = Some(location);
+            }
+        }
+    }
+
+    fn oxygen_system_pos(&self) -> Option<Vector2D> {
+        self.oxygen_system_pos
+    }
+
+    /// Steps from `location` to every explored position, indexed relative to
+    /// the top left of the map as in [OffsetGrid::grid].
+    fn distances_from(&self, location: Vector2D) -> Grid<Option<usize>> {
+        let seed = location - self.map.bounds().top_left;
+        self.map
+            .grid()
+            .distance_field(Some(seed), |lt| lt.is_traversible())
+    }
+
+    fn find_shortest_path(&self, start: Vector2D, destination: Vector2D) -> Vec<Vector2D> {
+        let heuristic = |&pos: &Vector2D| (pos - destination).manhattan_length();
+        self.astar_path(&start, &destination, heuristic).unwrap()
+    }
+
+    #[cfg(feature = "visualize")]
+    fn render(&self, droid_position: Vector2D) -> String {
+        let right = self.map.bounds().bottom_right.x;
+        let mut canvas = String::new();
+        for (pos, &loc) in self.map.iter() {
+            canvas.push(if pos == droid_position {
+                'D'
+            } else {
+                char::from(loc)
+            });
+            if pos.x == right {
+                canvas.push('\n');
+            }
+        }
+        canvas
+    }
+}
+
+impl NodeGraph for WorldMap {
+    type Node = Vector2D;
+
+    fn node_neighbours(&self, &pos: &Vector2D) -> Vec<(Vector2D, usize)> {
+        pos.neighbours()
+            .filter(|&n| self.map.get(n).is_some_and(|lt| lt.is_traversible()))
+            .map(|n| (n, 1))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LocationType {
+    Wall,
+    Empty,
+    OxygenSystem,
+    Start,
+    Reachable,
+    Unknown,
+}
+
+impl LocationType {
+    fn is_traversible(self) -> bool {
+        match self {
+            LocationType::Wall => false,
+            LocationType::Empty => true,
+            LocationType::OxygenSystem => true,
+            LocationType::Start => true,
+            LocationType::Reachable => true,
+            LocationType::Unknown => false,
+        }
+    }
+}
+
+impl From<i64> for LocationType {
+    fn from(value: i64) -> LocationType {
+        match value {
+            0 => LocationType::Wall,
+            1 => LocationType::Empty,
+            2 => LocationType::OxygenSystem,
+            _ => panic!("Unknown LocationType '{}'", value),
+        }
+    }
+}
+
+impl From<LocationType> for char {
+    fn from(loc_type: LocationType) -> char {
+        match loc_type {
+            LocationType::Wall => '#',
+            LocationType::Empty => '.',
+            LocationType::OxygenSystem => 'o',
+            LocationType::Start => 's',
+            LocationType::Reachable => '?',
+            LocationType::Unknown => ' ',
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day15() {
+        let (part1, part2) = day15(DAY15_INPUT);
+        assert_eq!(part1, 424);
+        assert_eq!(part2, 446);
+    }
+}
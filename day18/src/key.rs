@@ -2,45 +2,33 @@ use std::convert::TryFrom;
 use std::fmt;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Key(u32);
+pub struct Key(u8);
 
 impl Key {
-    pub fn from_mask(mask: u32) -> Key {
-        Key(mask)
+    pub fn from_index(index: usize) -> Key {
+        assert!(index < 32, "Unknown key index {}", index);
+        Key(index as u8)
     }
 
     pub fn as_char(self) -> char {
         char::from(self)
     }
 
-    pub fn as_mask(self) -> u32 {
-        self.0
+    pub fn index(self) -> usize {
+        usize::from(self.0)
     }
 
-    fn make_key_mask(c: char) -> Result<u32, String> {
+    fn char_to_index(c: char) -> Result<u8, String> {
         let index = match c {
             'a'..='z' => (c as u8) - b'a',
             '1'..='4' => 26 + (c.to_digit(10).unwrap() as u8),
             '@' => 26 + 5,
             _ => return Err(format!("Unknown key '{}'", c)),
         };
-        Ok(1 << index)
+        Ok(index)
     }
 
-    fn mask_to_index(mut mask: u32) -> u8 {
-        let mut index = 0;
-        loop {
-            mask >>= 1;
-            if mask == 0 {
-                break;
-            }
-            index += 1;
-        }
-        index
-    }
-
-    fn mask_to_char(mask: u32) -> char {
-        let index = Key::mask_to_index(mask);
+    fn index_to_char(index: u8) -> char {
         let ascii = match index {
             0..=25 => b'a' + index,
             26..=30 => b'0' + (index - 26),
@@ -56,14 +44,14 @@ impl TryFrom<char> for Key {
 
     fn try_from(c: char) -> Result<Self, Self::Error> {
         let c = c.to_ascii_lowercase();
-        let mask = Key::make_key_mask(c)?;
-        Ok(Key(mask))
+        let index = Key::char_to_index(c)?;
+        Ok(Key(index))
     }
 }
 
 impl From<Key> for char {
     fn from(k: Key) -> char {
-        Key::mask_to_char(k.0)
+        Key::index_to_char(k.0)
     }
 }
 
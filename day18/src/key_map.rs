@@ -1,11 +1,15 @@
 use crate::key::Key;
 use crate::key_set::KeySet;
 use crate::tunnel_map::{TunnelMap, TunnelPath};
-use fnv::{FnvHashMap, FnvHashSet};
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use aoc::graph::augmented_astar_search;
+use aoc::search::ida_star_with_table;
+use fnv::FnvHashMap;
 use std::convert::TryFrom;
 
+/// The number of states remembered by the low memory search, to avoid
+/// searching from the same keys again.
+const SEARCH_TABLE_SIZE: usize = 1 << 20;
+
 #[derive(Debug)]
 pub struct KeyMap {
     edges: FnvHashMap<Key, Vec<TunnelPath>>,
@@ -36,78 +40,100 @@ impl KeyMap {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct SearchState {
-    location: KeySet,
-    collected_keys: KeySet,
-    distance: usize,
-}
-
-impl Ord for SearchState {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.distance.cmp(&other.distance).reverse()
-    }
-}
-
-impl PartialOrd for SearchState {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 impl KeyMap {
     pub fn find_quickest_path_to_all_keys(&self) -> Option<usize> {
         let location = self.start_location();
 
-        let mut open = BinaryHeap::new();
-        open.push(SearchState {
-            location,
-            collected_keys: location,
-            distance: 0,
-        });
+        // The base node is the location of every robot, and the extra state
+        // is the keys collected so far.
+        let edges = |&location: &KeySet| {
+            let mut next = Vec::new();
+            for key in location.iter() {
+                for path in &self.edges[&key] {
+                    let mut location = location;
+                    location.remove(key);
+                    location.insert(path.dest);
+                    next.push((location, path.distance, path));
+                }
+            }
+            next
+        };
 
-        let mut seen = FnvHashSet::default();
+        let transition = |&collected_keys: &KeySet, path: &TunnelPath| {
+            if collected_keys.contains(path.dest) || !collected_keys.contains_all(path.doors) {
+                return None;
+            }
+            let mut collected_keys = collected_keys;
+            collected_keys.insert(path.dest);
+            Some(collected_keys)
+        };
+
+        // Every key still to collect is at least one step away.
+        let heuristic = |_: &KeySet, &collected_keys: &KeySet| {
+            self.all_keys
+                .iter()
+                .filter(|&k| !collected_keys.contains(k))
+                .count()
+        };
 
-        while let Some(state) = open.pop() {
-            let SearchState {
-                location,
-                collected_keys,
-                distance,
-            } = state;
+        let is_done = |_: &KeySet, &collected_keys: &KeySet| collected_keys == self.all_keys;
+        augmented_astar_search(location, location, edges, transition, is_done, heuristic)
+            .map(|(distance, _)| distance)
+    }
 
-            if !seen.insert((location, collected_keys)) {
-                continue;
-            }
+    /// The same as [find_quickest_path_to_all_keys], but using IDA* so that
+    /// only the current route is held in memory, at the cost of being much
+    /// slower.
+    pub fn find_quickest_path_to_all_keys_low_memory(&self) -> Option<usize> {
+        let location = self.start_location();
 
-            if collected_keys == self.all_keys {
-                return Some(state.distance);
+        // Each node is the location of every robot and the keys collected.
+        let edges = |&(location, collected_keys): &(KeySet, KeySet)| {
+            let mut next = Vec::new();
+            for key in location.iter() {
+                for path in &self.edges[&key] {
+                    if collected_keys.contains(path.dest)
+                        || !collected_keys.contains_all(path.doors)
+                    {
+                        continue;
+                    }
+                    let (mut location, mut collected_keys) = (location, collected_keys);
+                    location.remove(key);
+                    location.insert(path.dest);
+                    collected_keys.insert(path.dest);
+                    next.push(((location, collected_keys), path.distance));
+                }
             }
+            next
+        };
 
-            for key in location.iter() {
-                open.extend(
-                    self.edges[&key]
+        // Some robot has to reach the furthest uncollected key.
+        let heuristic = |&(location, collected_keys): &(KeySet, KeySet)| {
+            self.all_keys
+                .iter()
+                .filter(|&key| !collected_keys.contains(key))
+                .map(|key| {
+                    location
                         .iter()
-                        .filter(|path| !collected_keys.contains(path.dest))
-                        .filter(|path| collected_keys.contains_all(path.doors))
-                        .map(|path| {
-                            let mut location = location;
-                            location.remove(key);
-                            location.insert(path.dest);
-
-                            let mut collected_keys = collected_keys;
-                            collected_keys.insert(path.dest);
-
-                            SearchState {
-                                location,
-                                collected_keys,
-                                distance: distance + path.distance,
-                            }
-                        }),
-                );
-            }
-        }
+                        .filter_map(|robot| self.distance(robot, key))
+                        .min()
+                        .unwrap_or(0)
+                })
+                .max()
+                .unwrap_or(0)
+        };
+
+        let is_done = |&(_, collected_keys): &(KeySet, KeySet)| collected_keys == self.all_keys;
+        let start = (location, location);
+        ida_star_with_table(start, edges, is_done, heuristic, SEARCH_TABLE_SIZE)
+            .map(|(distance, _)| distance)
+    }
 
-        None
+    fn distance(&self, from: Key, to: Key) -> Option<usize> {
+        self.edges[&from]
+            .iter()
+            .find(|path| path.dest == to)
+            .map(|path| path.distance)
     }
 
     fn start_location(&self) -> KeySet {
@@ -1,43 +1,42 @@
 use crate::key::Key;
+use aoc::bitset::SmallSet;
 use std::fmt;
 use std::iter::FromIterator;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct KeySet(u32);
+pub struct KeySet(SmallSet);
 
 impl KeySet {
     pub fn new() -> KeySet {
-        KeySet(0)
+        KeySet(SmallSet::new())
     }
 
     pub fn insert(&mut self, key: Key) {
-        self.0 |= key.as_mask();
+        self.0.insert(key.index());
     }
 
     pub fn remove(&mut self, key: Key) {
-        self.0 &= !key.as_mask()
+        self.0.remove(key.index());
     }
 
     pub fn contains(self, key: Key) -> bool {
-        let result = self.0 & key.as_mask();
-        result > 0
+        self.0.contains(key.index())
     }
 
     pub fn contains_all(self, set: KeySet) -> bool {
-        let result = self.0 & set.0;
-        result == set.0
+        self.0.contains_all(set.0)
     }
 
     pub fn iter(self) -> impl Iterator<Item = Key> {
-        (0..32)
-            .map(|index| Key::from_mask(1 << index))
-            .filter(move |&key| self.contains(key))
+        self.0.iter().map(Key::from_index)
     }
 }
 
 impl From<Key> for KeySet {
     fn from(key: Key) -> Self {
-        KeySet(key.as_mask())
+        let mut set = KeySet::new();
+        set.insert(key);
+        set
     }
 }
 
@@ -54,14 +53,8 @@ impl FromIterator<Key> for KeySet {
 impl fmt::Debug for KeySet {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "KeySet(")?;
-        let mut mask = 1;
-        for _ in 0..26 {
-            let key = Key::from_mask(mask);
-            mask <<= 1;
-
-            if self.contains(key) {
-                write!(f, "{}", char::from(key))?;
-            }
+        for key in self.iter().filter(|key| key.index() < 26) {
+            write!(f, "{}", char::from(key))?;
         }
         write!(f, ")")
     }
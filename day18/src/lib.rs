@@ -0,0 +1,149 @@
+//! Solution to Advent of Code 2019 [Day 18](https://adventofcode.com/2019/day/18).
+
+mod key;
+mod key_map;
+mod key_set;
+mod tunnel_map;
+mod tunnel_tile;
+
+use aoc::solution::Solution;
+use aoc::Error;
+use key_map::KeyMap;
+use std::convert::TryFrom;
+
+pub const DAY18_INPUT: &str = include_str!("input/day18_input.txt");
+
+pub struct Day18 {
+    /// Search with IDA*, remembering a fixed number of states rather than
+    /// every one reached, which takes longer but bounds the memory used.
+    pub low_memory: bool,
+}
+
+impl Solution for Day18 {
+    fn part1(&self, input: &str) -> String {
+        day18_part1(input, self.low_memory).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day18_part2(input, self.low_memory).to_string()
+    }
+}
+
+fn day18_part1(input: &str, low_memory: bool) -> usize {
+    find_quickest_route(input, low_memory).unwrap()
+}
+
+fn day18_part2(input: &str, low_memory: bool) -> usize {
+    find_quickest_route_in_quadrants(input, low_memory).unwrap()
+}
+
+fn find_quickest_route(input: &str, low_memory: bool) -> aoc::Result<usize> {
+    let key_map = KeyMap::try_from(input).map_err(Error::Parse)?;
+    quickest_path(&key_map, low_memory)
+}
+
+fn find_quickest_route_in_quadrants(input: &str, low_memory: bool) -> aoc::Result<usize> {
+    let key_map = KeyMap::make_quadrants(input).map_err(Error::Parse)?;
+    quickest_path(&key_map, low_memory)
+}
+
+fn quickest_path(key_map: &KeyMap, low_memory: bool) -> aoc::Result<usize> {
+    let steps = if low_memory {
+        key_map.find_quickest_path_to_all_keys_low_memory()
+    } else {
+        key_map.find_quickest_path_to_all_keys()
+    };
+    steps.ok_or_else(|| Error::Search("Failed to find a route".into()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("input/example1.txt");
+    const EXAMPLE2: &str = include_str!("input/example2.txt");
+    const EXAMPLE3: &str = include_str!("input/example3.txt");
+    const EXAMPLE4: &str = include_str!("input/example4.txt");
+    const EXAMPLE5: &str = include_str!("input/example5.txt");
+
+    #[test]
+    fn test_quickest_route() {
+        check_quickest_route(EXAMPLE1, 8);
+        check_quickest_route(EXAMPLE2, 86);
+        check_quickest_route(EXAMPLE3, 132);
+        check_quickest_route(EXAMPLE4, 136);
+        check_quickest_route(EXAMPLE5, 81);
+    }
+
+    fn check_quickest_route(input: &str, expected_steps: usize) {
+        assert_eq!(find_quickest_route(input, false), Ok(expected_steps));
+    }
+
+    const QUADRANT_EXAMPLE1: &str = include_str!("input/quadrant_example1.txt");
+    const QUADRANT_EXAMPLE2: &str = include_str!("input/quadrant_example2.txt");
+    const QUADRANT_EXAMPLE3: &str = include_str!("input/quadrant_example3.txt");
+    const QUADRANT_EXAMPLE4: &str = include_str!("input/quadrant_example4.txt");
+
+    #[test]
+    fn test_quckest_route_in_quadrants() {
+        check_quickest_route_in_quadrants(QUADRANT_EXAMPLE1, 8);
+        check_quickest_route_in_quadrants(QUADRANT_EXAMPLE2, 24);
+        check_quickest_route_in_quadrants(QUADRANT_EXAMPLE3, 32);
+        check_quickest_route_in_quadrants(QUADRANT_EXAMPLE4, 72);
+    }
+
+    fn check_quickest_route_in_quadrants(input: &str, expected_steps: usize) {
+        assert_eq!(
+            find_quickest_route_in_quadrants(input, false),
+            Ok(expected_steps)
+        );
+    }
+
+    #[test]
+    fn test_no_route() {
+        // The key is behind its own door, so it can never be collected.
+        let input = "#######\n#@.A.a#\n#######";
+        for &low_memory in &[false, true] {
+            assert_eq!(
+                find_quickest_route(input, low_memory),
+                Err(Error::Search("Failed to find a route".into()))
+            );
+        }
+        assert!(matches!(
+            find_quickest_route("#@?#", false),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_low_memory_route() {
+        let examples = [
+            (KeyMap::try_from(EXAMPLE1), 8),
+            (KeyMap::try_from(EXAMPLE2), 86),
+            (KeyMap::try_from(EXAMPLE3), 132),
+            (KeyMap::try_from(EXAMPLE5), 81),
+            (KeyMap::make_quadrants(QUADRANT_EXAMPLE2), 24),
+            (KeyMap::make_quadrants(QUADRANT_EXAMPLE4), 72),
+        ];
+        for (key_map, expected_steps) in examples.iter() {
+            let key_map = key_map.as_ref().unwrap();
+            assert_eq!(quickest_path(key_map, true), Ok(*expected_steps));
+        }
+
+        let solution = Day18 { low_memory: true };
+        assert_eq!(solution.part1(EXAMPLE1), "8");
+        assert_eq!(solution.part2(QUADRANT_EXAMPLE2), "24");
+    }
+
+    #[test]
+    fn test_day18() {
+        assert_eq!(day18_part1(DAY18_INPUT, false), 3862);
+        assert_eq!(day18_part2(DAY18_INPUT, false), 1626);
+    }
+
+    #[test]
+    fn test_day18_low_memory() {
+        assert_eq!(day18_part1(DAY18_INPUT, true), 3862);
+        assert_eq!(day18_part2(DAY18_INPUT, true), 1626);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::key::Key;
 use crate::key_set::KeySet;
 use crate::tunnel_tile::TunnelTile;
-use aoc::geom::{self, Dimensions, Vector2D};
+use aoc::geom::{self, Grid, Vector2D};
 use fnv::{FnvHashMap, FnvHashSet};
 use std::convert::TryFrom;
 use std::fmt;
@@ -9,8 +9,7 @@ use std::ops::{Index, IndexMut};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TunnelMap {
-    dimensions: Dimensions,
-    tiles: Vec<TunnelTile>,
+    tiles: Grid<TunnelTile>,
     keys: FnvHashMap<Key, Vector2D>,
     doors: FnvHashMap<Vector2D, Key>,
 }
@@ -41,12 +40,11 @@ impl TunnelMap {
     }
 
     pub fn get(&self, pos: Vector2D) -> Option<&TunnelTile> {
-        self.tiles.get(self.index(pos))
+        self.tiles.get(pos)
     }
 
     pub fn get_mut(&mut self, pos: Vector2D) -> Option<&mut TunnelTile> {
-        let index = self.index(pos);
-        self.tiles.get_mut(index)
+        self.tiles.get_mut(pos)
     }
 
     pub fn all_keys(&self) -> KeySet {
@@ -57,11 +55,6 @@ impl TunnelMap {
         let key = Key::try_from(c).unwrap();
         self.keys.get(&key)
     }
-
-    fn index(&self, pos: Vector2D) -> usize {
-        let (x, y) = (pos.x as usize, pos.y as usize);
-        (y * self.dimensions.width) + x
-    }
 }
 
 impl Index<Vector2D> for TunnelMap {
@@ -82,14 +75,12 @@ impl TryFrom<&str> for TunnelMap {
     type Error = String;
 
     fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let chars = geom::cartograph_to_grid(input);
         let mut tiles = Vec::new();
-        let mut dimensions = Dimensions::new();
         let mut keys = FnvHashMap::default();
         let mut doors = FnvHashMap::default();
 
-        for (pos, c) in geom::cartograph(input) {
-            dimensions.expand_to_fit(pos);
-
+        for (pos, &c) in chars.iter() {
             let tile = TunnelTile::try_from(c).map_err(|e| format!("{}: {}", pos, e))?;
 
             if let TunnelTile::Player(key) = tile {
@@ -104,8 +95,7 @@ impl TryFrom<&str> for TunnelMap {
         }
 
         Ok(TunnelMap {
-            dimensions,
-            tiles,
+            tiles: Grid::from_vec(chars.dimensions(), tiles),
             keys,
             doors,
         })
@@ -156,7 +146,7 @@ impl TunnelMap {
 
             let next = pos
                 .neighbours()
-                .filter(|n| !seen.contains(&n))
+                .filter(|n| !seen.contains(n))
                 .map(|n| (n, self[n]))
                 .filter(|(_, t)| !t.is_wall());
 
@@ -173,11 +163,13 @@ impl TunnelMap {
 
 impl fmt::Display for TunnelMap {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for pos in self.dimensions.iter() {
-            if pos.x == 0 && pos.y > 0 {
+        for (y, row) in self.tiles.rows().enumerate() {
+            if y > 0 {
                 writeln!(f)?;
             }
-            write!(f, "{}", self[pos].as_char())?;
+            for tile in row {
+                write!(f, "{}", tile.as_char())?;
+            }
         }
         Ok(())
     }
@@ -16,11 +16,7 @@ impl TunnelTile {
     }
 
     pub fn is_wall(self) -> bool {
-        if let TunnelTile::Wall = self {
-            true
-        } else {
-            false
-        }
+        matches!(self, TunnelTile::Wall)
     }
 }
 
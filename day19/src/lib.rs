@@ -0,0 +1,94 @@
+//! Solution to Advent of Code 2019 [Day 19](https://adventofcode.com/2019/day/19).
+
+use aoc::intcode::{Machine, Program};
+use aoc::interval::Interval;
+use aoc::memo::Memo;
+use aoc::search::binary_search_min;
+use aoc::solution::Solution;
+
+pub const DAY19_INPUT: &str = include_str!("day19_input.txt");
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn part1(&self, input: &str) -> String {
+        day19_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day19_part2(input).to_string()
+    }
+}
+
+fn day19_part1(input: &str) -> usize {
+    let mut locator = TractorBeamLocator::new(input);
+    (0..50)
+        .flat_map(|x| (0..50).map(move |y| (x, y)))
+        .filter(|&(x, y)| locator.has_beam(x, y))
+        .count()
+}
+
+fn day19_part2(input: &str) -> usize {
+    const SIDE_LENGTH: usize = 100;
+
+    // Find the first row whose beam overlaps the beam in the row a square's
+    // height above it by at least the square's width. Lines before y=4 have
+    // gaps in, but the beam widens steadily after that so whether a square
+    // fits is monotonic.
+    let mut locator = TractorBeamLocator::new(input);
+    let mut overlap_above = |bottom: usize| {
+        let top = bottom + 1 - SIDE_LENGTH;
+        locator.beam_row(top).intersect(locator.beam_row(bottom))
+    };
+    let bottom = binary_search_min(SIDE_LENGTH + 3, 10_000, |bottom| {
+        overlap_above(bottom).len() >= SIDE_LENGTH as u64
+    })
+    .unwrap();
+
+    let left = overlap_above(bottom).start as usize;
+    let top = bottom + 1 - SIDE_LENGTH;
+    (left * 10_000) + top
+}
+
+#[derive(Debug)]
+struct TractorBeamLocator {
+    program: Program,
+    beam: Memo<(usize, usize), bool>,
+}
+
+impl TractorBeamLocator {
+    fn new(input: &str) -> TractorBeamLocator {
+        TractorBeamLocator {
+            program: Program::from(input),
+            beam: Memo::new(),
+        }
+    }
+
+    fn has_beam(&mut self, x: usize, y: usize) -> bool {
+        let program = &self.program;
+        self.beam.get_or_compute((x, y), |_, &(x, y)| {
+            let mut machine = Machine::new(program);
+            machine.input(x as i64);
+            machine.input(y as i64);
+            machine.run().unwrap() == 1
+        })
+    }
+
+    /// The span of the beam in row `y`, assuming that it has no gaps in.
+    fn beam_row(&mut self, y: usize) -> Interval {
+        let left = (0..).find(|&x| self.has_beam(x, y)).unwrap();
+        let right = (left..).find(|&x| !self.has_beam(x, y)).unwrap();
+        Interval::new(left as i64, right as i64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day19() {
+        assert_eq!(day19_part1(DAY19_INPUT), 181);
+        assert_eq!(day19_part2(DAY19_INPUT), 424_0964);
+    }
+}
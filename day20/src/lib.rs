@@ -0,0 +1,248 @@
+//! Solution to Advent of Code 2019 [Day 20](https://adventofcode.com/2019/day/20).
+
+use aoc::geom::{self, Vector2D};
+use aoc::graph::{bidirectional_dijkstra, Edge, Graph};
+use aoc::solution::Solution;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+pub const DAY20_INPUT: &str = include_str!("input/day20_input.txt");
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn part1(&self, input: &str) -> String {
+        day20_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day20_part2(input).to_string()
+    }
+}
+
+fn day20_part1(input: &str) -> usize {
+    Map::from(input).find_shortest_route()
+}
+
+fn day20_part2(input: &str) -> usize {
+    Map::from(input).find_shortest_route_recursive()
+}
+
+#[derive(Debug)]
+struct Map {
+    start: Vector2D,
+    end: Vector2D,
+    tiles: HashSet<Vector2D>,
+    outer_portals: HashMap<Vector2D, Vector2D>,
+    inner_portals: HashMap<Vector2D, Vector2D>,
+}
+
+impl Map {
+    fn find_shortest_route(&self) -> usize {
+        let tiles: Vec<Vector2D> = self.tiles.iter().copied().collect();
+        let indices: HashMap<Vector2D, usize> =
+            tiles.iter().enumerate().map(|(i, &pos)| (pos, i)).collect();
+        let edges = tiles
+            .iter()
+            .map(|&pos| {
+                let portals = self
+                    .inner_portals
+                    .get(&pos)
+                    .into_iter()
+                    .chain(self.outer_portals.get(&pos))
+                    .copied();
+                let steps = pos.neighbours().filter(|n| self.tiles.contains(n));
+                portals
+                    .chain(steps)
+                    .map(|n| Edge {
+                        dest_index: indices[&n],
+                        cost: 1,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Most of the maze is corridors, so only search between junctions.
+        let (start, end) = (indices[&self.start], indices[&self.end]);
+        let maze = TileGraph(edges).contract_corridors(|n| n == start || n == end);
+        let (start, end) = (maze.index_of(start).unwrap(), maze.index_of(end).unwrap());
+        maze.shortest_path_search(start, Some(end))
+            .cost_to(end)
+            .unwrap()
+    }
+
+    fn find_shortest_route_recursive(&self) -> usize {
+        let edges = |&(pos, level): &(Vector2D, usize)| {
+            let mut next = Vec::new();
+            if let Some(&endpoint) = self.inner_portals.get(&pos) {
+                next.push((endpoint, level + 1));
+            }
+            if level > 0 {
+                if let Some(&endpoint) = self.outer_portals.get(&pos) {
+                    next.push((endpoint, level - 1));
+                }
+            }
+            next.extend(
+                pos.neighbours()
+                    .filter(|n| self.tiles.contains(n))
+                    .map(|n| (n, level)),
+            );
+            next.into_iter().map(|state| (state, 1)).collect::<Vec<_>>()
+        };
+
+        // Every move can be made in reverse, so search from both ends at once.
+        let (start, end) = ((self.start, 0), (self.end, 0));
+        let (distance, _) = bidirectional_dijkstra(start, end, &edges, &edges).unwrap();
+        distance
+    }
+}
+
+/// The open tiles of the maze by index, where portals join their two ends
+/// as if they were neighbours.
+struct TileGraph(Vec<Vec<Edge>>);
+
+impl Graph for TileGraph {
+    fn num_nodes(&self) -> usize {
+        self.0.len()
+    }
+
+    fn node_edges(&self, node_index: usize) -> Vec<Edge> {
+        self.0[node_index].clone()
+    }
+}
+
+impl From<&str> for Map {
+    fn from(input: &str) -> Map {
+        let (tiles, portal_tiles, centre) = read_tiles(input);
+        let portal_halves = build_portal_endpoints(&tiles, portal_tiles, centre);
+        let (start, end, portals) = connect_portals(portal_halves);
+
+        let outer_portals = portals.iter().copied().map(|(a, b)| (b, a)).collect();
+        let inner_portals = portals.into_iter().collect();
+
+        Map {
+            start,
+            end,
+            tiles,
+            inner_portals,
+            outer_portals,
+        }
+    }
+}
+
+fn read_tiles(input: &str) -> (HashSet<Vector2D>, HashMap<Vector2D, char>, Vector2D) {
+    let grid = geom::cartograph_to_grid(input);
+    let mut tiles = HashSet::new();
+    let mut portal_tiles = HashMap::new();
+    for (pos, &c) in grid.iter() {
+        if c == '.' {
+            tiles.insert(pos);
+        } else if c.is_alphabetic() {
+            portal_tiles.insert(pos, c);
+        }
+    }
+
+    let centre = grid.dimensions().centre();
+
+    (tiles, portal_tiles, centre)
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum PortalType {
+    Inner,
+    Outer,
+}
+
+struct PortalHalf {
+    letters: (char, char),
+    entry_point: Vector2D,
+    portal_type: PortalType,
+}
+
+fn build_portal_endpoints(
+    tiles: &HashSet<Vector2D>,
+    portal_tiles: HashMap<Vector2D, char>,
+    centre: Vector2D,
+) -> Vec<PortalHalf> {
+    let mut portals: Vec<_> = portal_tiles
+        .iter()
+        .filter_map(|(&pos1, &c1)| {
+            let (&pos2, &c2) = pos1
+                .neighbours()
+                .find_map(|n| portal_tiles.get_key_value(&n))?;
+
+            let &entry_point = pos1.neighbours().find_map(|n| tiles.get(&n))?;
+
+            let mut letters = [c1, c2];
+            letters.sort();
+            let letters = (letters[0], letters[1]);
+
+            let c1dist = (centre - pos1).manhattan_length();
+            let c2dist = (centre - pos2).manhattan_length();
+            let portal_type = if c1dist < c2dist {
+                PortalType::Outer
+            } else {
+                PortalType::Inner
+            };
+
+            Some(PortalHalf {
+                letters,
+                entry_point,
+                portal_type,
+            })
+        })
+        .collect();
+
+    portals.sort_by_key(|a| a.letters);
+
+    portals
+}
+
+fn connect_portals(
+    mut portal_halves: Vec<PortalHalf>,
+) -> (Vector2D, Vector2D, Vec<(Vector2D, Vector2D)>) {
+    let end = portal_halves.pop().unwrap().entry_point;
+
+    let mut iter = portal_halves.into_iter();
+    let start = iter.next().unwrap().entry_point;
+    let portals: Vec<(Vector2D, Vector2D)> = iter
+        .tuples()
+        .map(|(a, b)| {
+            let (pos1, pos2) = (a.entry_point, b.entry_point);
+            if a.portal_type == PortalType::Inner {
+                (pos1, pos2)
+            } else {
+                (pos2, pos1)
+            }
+        })
+        .collect();
+
+    (start, end, portals)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("input/example1.txt");
+    const EXAMPLE2: &str = include_str!("input/example2.txt");
+    const EXAMPLE3: &str = include_str!("input/example3.txt");
+
+    #[test]
+    fn test_find_shortest_route() {
+        assert_eq!(Map::from(EXAMPLE1).find_shortest_route(), 23);
+        assert_eq!(Map::from(EXAMPLE2).find_shortest_route(), 58);
+    }
+
+    #[test]
+    fn test_find_shortest_route_recursive() {
+        assert_eq!(Map::from(EXAMPLE1).find_shortest_route_recursive(), 26);
+        assert_eq!(Map::from(EXAMPLE3).find_shortest_route_recursive(), 396);
+    }
+
+    #[test]
+    fn test_day20() {
+        assert_eq!(day20_part1(DAY20_INPUT), 522);
+        assert_eq!(day20_part2(DAY20_INPUT), 6300);
+    }
+}
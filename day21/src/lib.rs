@@ -0,0 +1,49 @@
+//! Solution to Advent of Code 2019 [Day 21](https://adventofcode.com/2019/day/21).
+
+use aoc::intcode::Machine;
+use aoc::solution::Solution;
+
+pub const DAY21_INPUT: &str = include_str!("day21_input.txt");
+const PART1_PROGRAM: &str = include_str!("day21_part1_program.txt");
+const PART2_PROGRAM: &str = include_str!("day21_part2_program.txt");
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn part1(&self, input: &str) -> String {
+        day21_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day21_part2(input).to_string()
+    }
+}
+
+fn day21_part1(input: &str) -> i64 {
+    run_program(input, PART1_PROGRAM)
+}
+
+fn day21_part2(input: &str) -> i64 {
+    run_program(input, PART2_PROGRAM)
+}
+
+fn run_program(input: &str, springscript: &str) -> i64 {
+    let mut machine = Machine::from_source(input);
+    let _prompt = machine.run_as_ascii();
+    springscript
+        .lines()
+        .filter(|line| !line.is_empty())
+        .for_each(|line| machine.input_ascii(line));
+    machine.run_as_iter().last().unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day21() {
+        assert_eq!(day21_part1(DAY21_INPUT), 19_362_259);
+        assert_eq!(day21_part2(DAY21_INPUT), 1_141_066_762);
+    }
+}
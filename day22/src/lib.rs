@@ -0,0 +1,302 @@
+//! Solution to Advent of Code 2019 [Day 22](https://adventofcode.com/2019/day/22).
+//!
+//! Based on the maths in [this comment on the subreddit](https://www.reddit.com/r/adventofcode/comments/ee0rqi/2019_day_22_solutions/fbnkaju/).
+
+use aoc::math::{is_prime, Matrix, ModNum, Modulo};
+use aoc::solution::Solution;
+use std::convert::{TryFrom, TryInto};
+use std::str::FromStr;
+
+pub const DAY22_INPUT: &str = include_str!("day22_input.txt");
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn part1(&self, input: &str) -> String {
+        day22_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day22_part2(input).to_string()
+    }
+}
+
+fn day22_part1(input: &str) -> usize {
+    let shuffled = Deck::with_shuffles(10_007, input).unwrap();
+    shuffled.find_card(2019).unwrap()
+}
+
+fn day22_part2(input: &str) -> u64 {
+    let size = 119_315_717_514_047;
+    let n = 101_741_582_076_661;
+    let shuffled = Deck::with_shuffles_n_times(size, input, n).unwrap();
+    shuffled.nth_card(2020).unwrap()
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Deck {
+    size: u64,
+    offset: ModNum,
+    increment: ModNum,
+}
+
+impl Deck {
+    fn new(size: u64) -> Deck {
+        Deck {
+            size,
+            offset: 0.modulo(size),
+            increment: 1.modulo(size),
+        }
+    }
+
+    fn nth_card(&self, n: u64) -> Option<u64> {
+        if n < self.size {
+            let n = n.modulo(self.size);
+            let result = self.offset.clone() + (self.increment.clone() * n);
+            result.value()
+        } else {
+            None
+        }
+    }
+
+    fn with_shuffles(size: u64, shuffles: &str) -> Result<Deck, String> {
+        let mut deck = Deck::new(size);
+        for t in parse_techniques(shuffles)?.into_iter() {
+            deck.shuffle(t)?;
+        }
+        Ok(deck)
+    }
+
+    fn with_shuffles_n_times(size: u64, shuffles: &str, n: u64) -> Result<Deck, String> {
+        let Deck {
+            increment: increment_mul,
+            offset: offset_diff,
+            ..
+        } = Deck::with_shuffles(size, shuffles)?;
+
+        // Each shuffle maps x -> increment_mul * x + offset_diff, which is
+        // the affine transform [[increment_mul, offset_diff], [0, 1]].
+        let shuffle = Matrix::from_rows(&[
+            &[
+                increment_mul.value().unwrap() as i64,
+                offset_diff.value().unwrap() as i64,
+            ],
+            &[0, 1],
+        ]);
+        let shuffled = shuffle.pow_mod(n, size);
+        let increment = shuffled[(0, 0)].modulo(size);
+        let offset = shuffled[(0, 1)].modulo(size);
+
+        Ok(Deck {
+            size,
+            increment,
+            offset,
+        })
+    }
+
+    fn shuffle(&mut self, technique: Technique) -> Result<(), String> {
+        match technique {
+            Technique::Reverse => {
+                self.increment *= (-1).modulo(self.size);
+                self.offset += self.increment.clone();
+            }
+            Technique::Cut(n) => {
+                self.offset += self.increment.clone() * n.modulo(self.size);
+            }
+            Technique::Deal(n) => {
+                let inverse = n.modulo(self.size).inv().ok_or_else(|| {
+                    format!("Can't deal with increment {} in a deck of {}", n, self.size)
+                })?;
+                self.increment *= inverse;
+            }
+        }
+        Ok(())
+    }
+
+    fn find_card(&self, value: u64) -> Option<usize> {
+        self.iter().position(|x| x == value)
+    }
+
+    fn iter(&self) -> DeckIter {
+        DeckIter {
+            deck: self.clone(),
+            n: 0,
+        }
+    }
+}
+
+struct DeckIter {
+    deck: Deck,
+    n: u64,
+}
+
+impl Iterator for DeckIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.deck.nth_card(self.n);
+        self.n += 1;
+
+        let iter_length = self.deck.size + 1;
+        self.n %= iter_length;
+
+        result
+    }
+}
+
+impl TryFrom<Vec<u64>> for Deck {
+    type Error = String;
+
+    fn try_from(cards: Vec<u64>) -> Result<Self, Self::Error> {
+        let size: u64 = cards.len().try_into().unwrap();
+        if is_prime(size) {
+            let card0 = cards[0].modulo(size);
+            let card1 = cards[1].modulo(size);
+            let deck = Deck {
+                size,
+                offset: card0.clone(),
+                increment: card1 - card0,
+            };
+            if deck.iter().eq(cards.iter().copied()) {
+                Ok(deck)
+            } else {
+                Err("Deck cannot be represented".into())
+            }
+        } else {
+            Err("Non-prime deck sizes are not allowed".into())
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Technique {
+    Reverse,   // deal into new stack
+    Cut(i64),  // cut N cards
+    Deal(u64), // deal with increment N
+}
+
+impl TryFrom<&str> for Technique {
+    type Error = String;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let line = line.trim();
+        if line.starts_with("deal into new stack") {
+            Ok(Technique::Reverse)
+        } else if line.starts_with("cut ") {
+            Ok(Technique::Cut(parse_number::<i64>(line)?))
+        } else if line.starts_with("deal with increment") {
+            Ok(Technique::Deal(parse_number::<u64>(line)?))
+        } else {
+            Err(format!("Unknown instruction '{}'", line))
+        }
+    }
+}
+
+fn parse_number<T: FromStr>(line: &str) -> Result<T, String> {
+    line.split_ascii_whitespace()
+        .last()
+        .map(|word| word.parse::<T>())
+        .unwrap()
+        .map(Ok)
+        .map_err(|_| "Missing N")?
+}
+
+fn parse_techniques(input: &str) -> Result<Vec<Technique>, String> {
+    let mut instructions = Vec::new();
+    for line in input.lines() {
+        instructions.push(Technique::try_from(line)?);
+    }
+    Ok(instructions)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::generate::{shuffle_instructions, Rng};
+
+    #[test]
+    fn test_deal_into_new_stack() {
+        let mut deck = Deck::new(11);
+        deck.shuffle(Technique::try_from("deal into new stack").unwrap())
+            .unwrap();
+        assert_eq!(
+            deck,
+            vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0].try_into().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_cut_n_cards() {
+        let mut deck = Deck::new(11);
+        deck.shuffle(Technique::try_from("cut 3").unwrap()).unwrap();
+        assert_eq!(
+            deck,
+            vec![3, 4, 5, 6, 7, 8, 9, 10, 0, 1, 2].try_into().unwrap()
+        );
+
+        let mut deck = Deck::new(11);
+        deck.shuffle(Technique::try_from("cut -4").unwrap())
+            .unwrap();
+        assert_eq!(
+            deck,
+            vec![7, 8, 9, 10, 0, 1, 2, 3, 4, 5, 6].try_into().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_deal_with_increment() {
+        let mut deck = Deck::new(11);
+        deck.shuffle(Technique::try_from("deal with increment 3").unwrap())
+            .unwrap();
+        assert_eq!(
+            deck,
+            vec![0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7].try_into().unwrap()
+        );
+
+        // Cards would collide if the increment shares a factor with the size.
+        assert!(Deck::with_shuffles(10, "deal with increment 4").is_err());
+    }
+
+    #[test]
+    fn test_random_shuffles() {
+        let mut rng = Rng::new(22);
+        for _ in 0..10 {
+            let size = 10_007;
+            let shuffles = shuffle_instructions(&mut rng, 50, size);
+            let deck = Deck::with_shuffles(size, &shuffles).unwrap();
+            let expected = shuffle_cards(size, &shuffles);
+            assert_eq!(deck, expected.try_into().unwrap(), "{}", shuffles);
+        }
+    }
+
+    fn shuffle_cards(size: u64, shuffles: &str) -> Vec<u64> {
+        let mut cards = (0..size).collect::<Vec<_>>();
+        for technique in parse_techniques(shuffles).unwrap() {
+            cards = match technique {
+                Technique::Reverse => cards.into_iter().rev().collect(),
+                Technique::Cut(n) => {
+                    let n = n.rem_euclid(size as i64) as usize;
+                    cards[n..]
+                        .iter()
+                        .chain(cards[..n].iter())
+                        .copied()
+                        .collect()
+                }
+                Technique::Deal(n) => {
+                    let mut dealt = vec![0; cards.len()];
+                    for (i, &card) in cards.iter().enumerate() {
+                        dealt[(i * n as usize) % cards.len()] = card;
+                    }
+                    dealt
+                }
+            };
+        }
+        cards
+    }
+
+    #[test]
+    fn test_day22() {
+        assert_eq!(day22_part1(DAY22_INPUT), 3939);
+        assert_eq!(day22_part2(DAY22_INPUT), 55_574_110_161_534);
+    }
+}
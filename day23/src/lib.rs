@@ -0,0 +1,85 @@
+//! Solution to Advent of Code 2019 [Day 23](https://adventofcode.com/2019/day/23).
+
+use aoc::intcode::{Machine, Network, NetworkStatus, Program, Route};
+use aoc::solution::Solution;
+
+pub const DAY23_INPUT: &str = include_str!("day23_input.txt");
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn part1(&self, input: &str) -> String {
+        day23_part1(input).to_string()
+    }
+
+    fn part2(&self, input: &str) -> String {
+        day23_part2(input).to_string()
+    }
+}
+
+fn day23_part1(input: &str) -> i64 {
+    run_network(input, NetworkMode::Part1)
+}
+
+fn day23_part2(input: &str) -> i64 {
+    run_network(input, NetworkMode::Part2)
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum NetworkMode {
+    Part1,
+    Part2,
+}
+
+const NAT_ADDRESS: i64 = 255;
+
+fn run_network(input: &str, mode: NetworkMode) -> i64 {
+    let num_machines = 50;
+
+    let program = Program::from(input);
+    let machines = (0..num_machines)
+        .map(|i| Machine::with_input(&program, i))
+        .collect();
+    let mut network = Network::new(machines, 3);
+
+    let mut nat = None;
+    let mut last_delivered_nat = None;
+
+    loop {
+        let status = network.run(Some(-1), |_, packet| {
+            let (address, x, y) = (packet[0], packet[1], packet[2]);
+            if address != NAT_ADDRESS {
+                return Route::To(address as usize, vec![x, y]);
+            }
+            nat = Some((x, y));
+            match mode {
+                NetworkMode::Part1 => Route::Stop,
+                NetworkMode::Part2 => Route::Discard,
+            }
+        });
+
+        match status {
+            NetworkStatus::Stopped => return nat.unwrap().1,
+            NetworkStatus::Halted => panic!("All machines halted"),
+            NetworkStatus::Idle => {
+                let (x, y) = nat.take().expect("Network idle with no NAT packet");
+                if last_delivered_nat == Some(y) {
+                    return y;
+                }
+                network.send(0, &[x, y]);
+                last_delivered_nat = Some(y);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_day23() {
+        assert_eq!(day23_part1(DAY23_INPUT), 24602);
+        assert_eq!(day23_part2(DAY23_INPUT), 19641);
+    }
+}
@@ -0,0 +1,194 @@
+//! Solution to Advent of Code 2019 [Day 25](https://adventofcode.com/2019/day/25).
+
+use aoc::intcode::{Machine, Program, Transcript};
+use aoc::solution::Solution;
+use itertools::Itertools;
+use regex::Regex;
+use std::io;
+
+pub const DAY25_INPUT: &str = include_str!("day25_input.txt");
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn part1(&self, input: &str) -> String {
+        day25_part1(input).to_string()
+    }
+
+    /// There is no second puzzle on the last day, the final star is awarded
+    /// for having collected all of the others.
+    fn part2(&self, _input: &str) -> String {
+        "Merry Christmas!".to_string()
+    }
+}
+
+/// Explore the ship by typing commands, with `--replay <path>` to first replay
+/// a saved transcript, `--record <path>` to save a transcript of the session
+/// and `--log <path>` to save a readable log of it.
+pub fn run_interactive(input: &str, args: &[String]) -> aoc::Result<()> {
+    let mut droid = Droid::new(input);
+    if let Some(path) = arg_value(args, "--replay") {
+        Transcript::load(path)?.replay(&mut droid.machine)?;
+    }
+
+    let record_path = arg_value(args, "--record");
+    if record_path.is_some() {
+        droid.machine.record_transcript();
+    }
+
+    let log_path = arg_value(args, "--log");
+    if log_path.is_some() {
+        droid.machine.echo_teletype();
+    }
+
+    droid.interactive_loop();
+
+    if let Some(path) = record_path {
+        droid.machine.take_transcript().unwrap().save(path)?;
+    }
+    if let Some(path) = log_path {
+        droid.machine.take_teletype().unwrap().save(path)?;
+    }
+    Ok(())
+}
+
+fn arg_value<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    let index = args.iter().position(|a| a == name)?;
+    args.get(index + 1).map(String::as_str)
+}
+
+fn day25_part1(input: &str) -> u64 {
+    let mut droid = Droid::new(input);
+    droid.pick_up_items();
+    let output = droid.find_correctly_weighted_items().unwrap();
+
+    let re = Regex::new(r"\d+").unwrap();
+    let caps = re.captures(&output).unwrap();
+    let password = caps.get(0).unwrap();
+    password.as_str().parse::<u64>().unwrap()
+}
+
+struct Droid {
+    machine: Machine,
+}
+
+impl Droid {
+    fn new(input: &str) -> Droid {
+        Droid {
+            machine: Machine::new(&Program::from(input).optimize()),
+        }
+    }
+
+    fn run_one_command(&mut self, input: &str) -> String {
+        self.machine.input_ascii(input.trim());
+        self.machine.run_as_ascii()
+    }
+
+    fn run_commands(&mut self, commands: &str) -> Option<String> {
+        let mut out = None;
+        for line in commands.trim().lines() {
+            out = Some(self.run_one_command(line));
+        }
+        out
+    }
+
+    fn pick_up_items(&mut self) {
+        const _PROGRAM: &str = include_str!("pick_up_all_items.txt");
+        self.run_commands(_PROGRAM);
+    }
+
+    fn find_correctly_weighted_items(&mut self) -> Option<String> {
+        let all_items = vec![
+            "asterisk",
+            "ornament",
+            "cake",
+            "space heater",
+            "festive hat",
+            "semiconductor",
+            "food ration",
+            "sand",
+        ];
+
+        for item in &all_items {
+            self.run_one_command(&format!("drop {}", item));
+        }
+
+        for n in 1..all_items.len() {
+            for items in all_items.iter().combinations(n) {
+                for item in &items {
+                    self.run_one_command(&format!("take {}", item));
+                }
+
+                let output = self.run_one_command("west");
+                if !(output.contains("lighter") || output.contains("heavier")) {
+                    return Some(output);
+                }
+
+                for item in items.into_iter() {
+                    self.run_one_command(&format!("drop {}", item));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn interactive_loop(&mut self) {
+        loop {
+            print!("{}", self.machine.run_as_ascii());
+            let mut buffer = String::new();
+            io::stdin().read_line(&mut buffer).unwrap();
+
+            if buffer.starts_with("exit") {
+                break;
+            }
+            self.machine.input_ascii(buffer.trim());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_replay_pick_up_items() {
+        let mut droid = Droid::new(DAY25_INPUT);
+        droid.machine.record_transcript();
+        droid.pick_up_items();
+        let transcript = droid.machine.take_transcript().unwrap();
+
+        let mut replayed = Droid::new(DAY25_INPUT);
+        assert_eq!(transcript.replay(&mut replayed.machine), Ok(()));
+        assert_eq!(
+            replayed.run_one_command("inv"),
+            droid.run_one_command("inv")
+        );
+    }
+
+    #[test]
+    fn test_optimized_program_is_equivalent() {
+        let mut droid = Droid {
+            machine: Machine::from_source(DAY25_INPUT),
+        };
+        droid.machine.record_transcript();
+        droid.pick_up_items();
+        let transcript = droid.machine.take_transcript().unwrap();
+
+        let program = Program::from(DAY25_INPUT);
+        let optimized_program = program.optimize();
+        assert_ne!(optimized_program, program);
+
+        let mut optimized = Machine::new(&optimized_program);
+        assert_eq!(transcript.replay(&mut optimized), Ok(()));
+        assert_eq!(
+            droid.run_one_command("inv"),
+            Droid { machine: optimized }.run_one_command("inv")
+        );
+    }
+
+    #[test]
+    fn test_day25() {
+        assert_eq!(day25_part1(DAY25_INPUT), 25_165_890);
+    }
+}